@@ -1,8 +1,8 @@
-use browser_use::{BrowserSession, LaunchOptions};
-use clap::{Parser, Subcommand};
+use browser_use::{BrowserSession, CrawlOptions, Crawler, Device, HumanEmulation, LaunchOptions};
+use clap::{Parser, Subcommand, ValueEnum};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
-use std::{fs, path::PathBuf, thread, time::Duration};
+use std::{fs, io::Write, path::PathBuf, thread, time::Duration};
 
 #[derive(Parser)]
 #[command(name = "fast-browser-use")]
@@ -21,10 +21,19 @@ enum Commands {
         #[arg(long)]
         url: String,
 
-        /// Simulate human behavior (random delays, mouse jitter)
+        /// Emulate human input (curved mouse paths, typing cadence, scroll momentum).
+        /// Once the page has loaded, the pointer moves onto it like a visitor's would.
         #[arg(long)]
         human_emulation: bool,
 
+        /// Seed for human emulation, for reproducible runs
+        #[arg(long, requires = "human_emulation")]
+        human_seed: Option<u64>,
+
+        /// Emulate a device (iphone_15, iphone_se, pixel_7, ipad, ipad_pro, desktop, desktop_hidpi)
+        #[arg(long)]
+        device: Option<String>,
+
         /// Wait for a specific CSS selector to appear
         #[arg(long)]
         wait_for_selector: Option<String>,
@@ -103,6 +112,120 @@ enum Commands {
         #[arg(long)]
         full_page: bool,
     },
+    /// Print a page to PDF
+    Pdf {
+        /// URL to print
+        #[arg(long)]
+        url: String,
+
+        /// Output file path (PDF)
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Paper size: letter, legal, tabloid, a3, a4 or a5
+        #[arg(long, default_value = "letter")]
+        paper: String,
+
+        /// Margin on all sides, in inches or with a unit (e.g. "1cm")
+        #[arg(long)]
+        margin: Option<String>,
+
+        /// Landscape orientation
+        #[arg(long)]
+        landscape: bool,
+
+        /// Rendering scale between 0.1 and 2
+        #[arg(long)]
+        scale: Option<f64>,
+
+        /// HTML template for the page header
+        #[arg(long)]
+        header_template: Option<String>,
+
+        /// HTML template for the page footer
+        #[arg(long)]
+        footer_template: Option<String>,
+
+        /// Pages to print, e.g. "1-5, 8"
+        #[arg(long)]
+        page_ranges: Option<String>,
+
+        /// Print background graphics
+        #[arg(long)]
+        print_background: bool,
+    },
+    /// Extract HTML tables as JSON records or CSV
+    Table {
+        /// URL to extract tables from
+        #[arg(long)]
+        url: String,
+
+        /// CSS selector of the table (default: all tables on the page)
+        #[arg(long)]
+        selector: Option<String>,
+
+        /// Output format: json or csv
+        #[arg(long, value_enum, ignore_case = true, default_value = "json")]
+        format: TableOutput,
+
+        /// Output file path
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Crawl a site and emit one JSON line per page
+    Crawl {
+        /// Start URL (repeat for several)
+        #[arg(long = "url", required = true)]
+        urls: Vec<String>,
+
+        /// Maximum link depth from the start URLs
+        #[arg(long, default_value = "2")]
+        max_depth: usize,
+
+        /// Maximum number of pages to crawl
+        #[arg(long, default_value = "100")]
+        max_pages: usize,
+
+        /// Only crawl URLs matching this regex (repeatable)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Skip URLs matching this regex (repeatable)
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Follow links to other origins
+        #[arg(long)]
+        allow_external: bool,
+
+        /// Do not fetch or obey robots.txt
+        #[arg(long)]
+        ignore_robots: bool,
+
+        /// Minimum delay between requests to the same host in ms
+        #[arg(long, default_value = "1000")]
+        delay: u64,
+
+        /// Number of tabs crawling in parallel
+        #[arg(long, default_value = "1")]
+        concurrency: usize,
+
+        /// State file to resume from and save progress to
+        #[arg(long)]
+        state: Option<PathBuf>,
+
+        /// Output file (JSONL, appended to); defaults to stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// Output format of the table command
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum TableOutput {
+    Json,
+    Csv,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -117,9 +240,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Navigate { url, human_emulation, wait_for_selector, load_session } => {
+        Commands::Navigate { url, human_emulation, human_seed, device, wait_for_selector, load_session } => {
             info!("Navigating to: {}", url);
-            let options = LaunchOptions::default().sandbox(false);
+            let mut options = LaunchOptions::default().sandbox(false);
+            if human_emulation {
+                info!("Engaging human emulation...");
+                let profile = HumanEmulation::new();
+                options = options.human_emulation(match human_seed {
+                    Some(seed) => profile.seed(seed),
+                    None => profile,
+                });
+            }
+            if let Some(name) = device {
+                let device = Device::preset(&name).ok_or_else(|| format!("Unknown device '{}'", name))?;
+                options = options.device(device);
+            }
             
             let session = BrowserSession::launch(options)?;
 
@@ -152,14 +287,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             session.navigate(&url)?;
 
-            if human_emulation {
-                info!("Engaging human emulation (random delays)...");
-                // Simple emulation: random sleep
-                // Real implementation would inject mouse movements
-                let delay = 1000 + (rand::random::<u64>() % 2000);
-                thread::sleep(Duration::from_millis(delay));
-            }
-
             if let Some(selector) = wait_for_selector {
                 info!("Waiting for selector: {}", selector);
                 let tab = session.get_active_tab()?;
@@ -168,6 +295,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 session.wait_for_navigation()?;
             }
 
+            // Navigating makes no input of its own, so give pages that watch for it some pointer movement
+            if human_emulation {
+                let tab = session.get_active_tab()?;
+                session.pointer_move(&tab, 200.0, 200.0, 1)?;
+            }
+
             info!("Navigation complete.");
         }
         Commands::Snapshot { url, include_styles, output } => {
@@ -179,11 +312,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 session.wait_for_navigation()?;
             }
 
-            if include_styles {
-                info!("Including styles (experimental)...");
-            }
-
-            let dom = session.extract_dom()?;
+            let dom = if include_styles {
+                info!("Including computed styles...");
+                session.extract_dom_with_styles()?
+            } else {
+                session.extract_dom()?
+            };
             // We use the 'Ai' render mode from the library via existing snapshot logic or direct call
             // Since we can't easily access render_aria_tree directly if it's not pub, we use the tool logic.
             // But we can import `render_aria_tree` if we made it pub (it is pub in `snapshot.rs` but `snapshot.rs` module is pub).
@@ -322,6 +456,127 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             fs::write(&output, &screenshot_data)?;
             info!("✅ Saved screenshot to {:?}", output);
         }
+        Commands::Pdf {
+            url,
+            output,
+            paper,
+            margin,
+            landscape,
+            scale,
+            header_template,
+            footer_template,
+            page_ranges,
+            print_background,
+        } => {
+            info!("🖨️ Printing {} to PDF", url);
+            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let result = session.execute_tool(
+                "save_pdf",
+                serde_json::json!({
+                    "path": output,
+                    "paper_size": paper.to_lowercase(),
+                    "margin": margin,
+                    "landscape": landscape,
+                    "scale": scale,
+                    "header_template": header_template,
+                    "footer_template": footer_template,
+                    "page_ranges": page_ranges,
+                    "print_background": print_background,
+                }),
+            )?;
+            if !result.success {
+                return Err(result.error.unwrap_or_else(|| "PDF export failed".to_string()).into());
+            }
+
+            info!("✅ Saved PDF to {:?}", output);
+        }
+        Commands::Table { url, selector, format, output } => {
+            info!("📊 Extracting tables from {}", url);
+            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+
+            session.navigate(&url)?;
+            session.wait_for_navigation()?;
+
+            let result = session.execute_tool(
+                "extract_table",
+                serde_json::json!({ "selector": selector, "format": format }),
+            )?;
+            if !result.success {
+                return Err(result.error.unwrap_or_else(|| "Table extraction failed".to_string()).into());
+            }
+
+            let data = result.data.unwrap_or_default();
+            let tables = data["tables"].as_array().cloned().unwrap_or_default();
+            info!("✅ Found {} table(s)", tables.len());
+
+            let content = if format == TableOutput::Csv {
+                // Separate multiple tables with a blank line
+                tables.iter().filter_map(|t| t["csv"].as_str()).collect::<Vec<_>>().join("\r\n")
+            } else if selector.is_some() {
+                serde_json::to_string_pretty(&tables.first().map(|t| &t["records"]))?
+            } else {
+                serde_json::to_string_pretty(&tables)?
+            };
+
+            if let Some(path) = output {
+                fs::write(&path, &content)?;
+                info!("Saved to {:?}", path);
+            } else {
+                println!("{}", content);
+            }
+        }
+        Commands::Crawl {
+            urls,
+            max_depth,
+            max_pages,
+            include,
+            exclude,
+            allow_external,
+            ignore_robots,
+            delay,
+            concurrency,
+            state,
+            output,
+        } => {
+            info!("🕷️ Crawling from {:?} (depth {}, max {} pages)", urls, max_depth, max_pages);
+            let session = BrowserSession::launch(LaunchOptions::default().sandbox(false))?;
+
+            let mut options = CrawlOptions::new(urls)
+                .max_depth(max_depth)
+                .max_pages(max_pages)
+                .same_origin(!allow_external)
+                .respect_robots(!ignore_robots)
+                .delay(Duration::from_millis(delay))
+                .concurrency(concurrency);
+            options.include = include;
+            options.exclude = exclude;
+            if let Some(path) = state {
+                options = options.state_file(path);
+            }
+
+            // Append so a resumed crawl adds to the earlier output
+            let mut writer: Box<dyn Write> = match &output {
+                Some(path) => Box::new(fs::OpenOptions::new().create(true).append(true).open(path)?),
+                None => Box::new(std::io::stdout()),
+            };
+
+            let crawler = Crawler::new(&session, options)?;
+            let summary = crawler.run(|page| {
+                let line = serde_json::to_string(page)?;
+                writeln!(writer, "{}", line)?;
+                writer.flush()?;
+                Ok(())
+            })?;
+
+            info!(
+                "✅ Crawled {} page(s) ({} in total, {} still queued)",
+                summary.pages, summary.total_pages, summary.remaining
+            );
+        }
     }
 
     Ok(())
@@ -1,3 +1,5 @@
+use crate::browser::{emulation::{Device, Emulation, NetworkConditions},
+                     human::HumanEmulation};
 use std::path::PathBuf;
 
 /// Options for launching a new browser instance
@@ -18,6 +20,12 @@ pub struct LaunchOptions {
     pub sandbox: bool,
 
     pub launch_timeout: u64,
+
+    /// Human-like mouse movement, typing and scrolling for the interaction tools
+    pub human_emulation: Option<HumanEmulation>,
+
+    /// Device, media, locale and geolocation overrides applied to every tab
+    pub emulation: Emulation,
 }
 
 impl Default for LaunchOptions {
@@ -30,6 +38,8 @@ impl Default for LaunchOptions {
             user_data_dir: None,
             sandbox: true,
             launch_timeout: 30000,
+            human_emulation: None,
+            emulation: Emulation::default(),
         }
     }
 }
@@ -76,6 +86,36 @@ impl LaunchOptions {
         self.launch_timeout = timeout_ms;
         self
     }
+
+    /// Builder method: emulate human input timing and mouse movement
+    pub fn human_emulation(mut self, profile: HumanEmulation) -> Self {
+        self.human_emulation = Some(profile);
+        self
+    }
+
+    /// Builder method: emulate a device (viewport, scale factor, touch and user agent)
+    pub fn device(mut self, device: Device) -> Self {
+        self.emulation.device = Some(device);
+        self
+    }
+
+    /// Builder method: throttle the network (or take it offline)
+    pub fn network(mut self, conditions: NetworkConditions) -> Self {
+        self.emulation.network = Some(conditions);
+        self
+    }
+
+    /// Builder method: slow the CPU down by `rate` (e.g. 4 for a mid-range phone)
+    pub fn cpu_throttling(mut self, rate: f64) -> Self {
+        self.emulation.cpu_throttling = Some(rate);
+        self
+    }
+
+    /// Builder method: set emulation overrides (replaces any set before, including the device)
+    pub fn emulation(mut self, emulation: Emulation) -> Self {
+        self.emulation = emulation;
+        self
+    }
 }
 
 /// Options for connecting to an existing browser instance
@@ -126,6 +166,20 @@ mod tests {
         assert_eq!(opts.launch_timeout, 60000);
     }
 
+    #[test]
+    fn test_launch_options_device() {
+        let opts = LaunchOptions::new()
+            .emulation(Emulation::new().locale("fr-FR"))
+            .device(Device::preset("iphone_15").unwrap());
+
+        assert_eq!(opts.emulation.device.unwrap().width, 393);
+        assert_eq!(opts.emulation.locale.as_deref(), Some("fr-FR"));
+
+        let opts = LaunchOptions::new().network(NetworkConditions::fast_3g()).cpu_throttling(4.0);
+        assert_eq!(opts.emulation.network, Some(NetworkConditions::fast_3g()));
+        assert_eq!(opts.emulation.cpu_throttling, Some(4.0));
+    }
+
     #[test]
     fn test_connection_options() {
         let opts = ConnectionOptions::new("ws://localhost:9222").timeout(5000);
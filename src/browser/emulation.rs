@@ -0,0 +1,502 @@
+//! Device, media, locale, geolocation, network and CPU emulation
+//!
+//! An [`Emulation`] holds a set of page-level overrides. Settings left as `None` are not touched
+//! when the emulation is applied, so a session can layer changes (a device first, a dark color
+//! scheme later) and re-apply the combined state to every new tab.
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab,
+                      protocol::cdp::{Browser::{GrantPermissions, PermissionType}, Emulation as Cdp, Network}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const IOS_USER_AGENT: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 \
+                              (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1";
+const IPADOS_USER_AGENT: &str = "Mozilla/5.0 (iPad; CPU OS 17_0 like Mac OS X) AppleWebKit/605.1.15 \
+                                 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1";
+const ANDROID_USER_AGENT: &str = "Mozilla/5.0 (Linux; Android 14; Pixel 7) AppleWebKit/537.36 \
+                                  (KHTML, like Gecko) Chrome/120.0.0.0 Mobile Safari/537.36";
+
+/// Names accepted by [`Device::preset`]
+pub const DEVICE_PRESETS: &[&str] =
+    &["iphone_15", "iphone_se", "pixel_7", "ipad", "ipad_pro", "desktop", "desktop_hidpi"];
+
+/// Screen metrics and browser identity of an emulated device
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Device {
+    pub name: String,
+
+    /// Viewport width in CSS pixels
+    pub width: u32,
+
+    /// Viewport height in CSS pixels
+    pub height: u32,
+
+    pub device_scale_factor: f64,
+
+    /// Mobile viewport: meta viewport tags are honored and scrollbars overlay the content
+    pub mobile: bool,
+
+    /// Touch events are enabled
+    pub touch: bool,
+
+    /// User agent to report (None: the browser's own)
+    pub user_agent: Option<String>,
+}
+
+impl Device {
+    /// A plain viewport of the given size with no other device traits
+    pub fn viewport(width: u32, height: u32) -> Self {
+        Self {
+            name: "custom".to_string(),
+            width,
+            height,
+            device_scale_factor: 1.0,
+            mobile: false,
+            touch: false,
+            user_agent: None,
+        }
+    }
+
+    /// A built-in device by name (see [`DEVICE_PRESETS`]); case, spaces and dashes are ignored
+    pub fn preset(name: &str) -> Option<Self> {
+        let key: String =
+            name.trim().to_ascii_lowercase().chars().map(|c| if c == ' ' || c == '-' { '_' } else { c }).collect();
+        let (width, height, device_scale_factor, mobile, user_agent) = match key.as_str() {
+            "iphone_15" | "iphone" => (393, 852, 3.0, true, Some(IOS_USER_AGENT)),
+            "iphone_se" => (375, 667, 2.0, true, Some(IOS_USER_AGENT)),
+            "pixel_7" | "pixel" | "android" => (412, 915, 2.625, true, Some(ANDROID_USER_AGENT)),
+            "ipad" => (820, 1180, 2.0, true, Some(IPADOS_USER_AGENT)),
+            "ipad_pro" => (1024, 1366, 2.0, true, Some(IPADOS_USER_AGENT)),
+            "desktop" => (1280, 720, 1.0, false, None),
+            "desktop_hidpi" | "retina" => (1440, 900, 2.0, false, None),
+            _ => return None,
+        };
+        Some(Self {
+            name: key,
+            width,
+            height,
+            device_scale_factor,
+            mobile,
+            touch: mobile,
+            user_agent: user_agent.map(str::to_string),
+        })
+    }
+
+    /// The same device rotated to landscape
+    pub fn landscape(mut self) -> Self {
+        if self.height > self.width {
+            std::mem::swap(&mut self.width, &mut self.height);
+        }
+        self
+    }
+}
+
+/// Value of the `prefers-color-scheme` media feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorScheme {
+    Light,
+    Dark,
+    NoPreference,
+}
+
+/// CSS media type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaType {
+    Screen,
+    Print,
+}
+
+/// Position reported by the Geolocation API
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Geolocation {
+    pub latitude: f64,
+
+    pub longitude: f64,
+
+    /// Accuracy in meters (default: 10)
+    #[serde(default = "default_accuracy")]
+    pub accuracy: f64,
+}
+
+fn default_accuracy() -> f64 {
+    10.0
+}
+
+impl Geolocation {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self { latitude, longitude, accuracy: default_accuracy() }
+    }
+}
+
+/// Network latency and bandwidth limits
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct NetworkConditions {
+    /// No requests succeed
+    pub offline: bool,
+
+    /// Minimum latency added to each request, in milliseconds
+    pub latency_ms: f64,
+
+    /// Download bandwidth in kilobits per second (None: unlimited)
+    pub download_kbps: Option<f64>,
+
+    /// Upload bandwidth in kilobits per second (None: unlimited)
+    pub upload_kbps: Option<f64>,
+}
+
+impl NetworkConditions {
+    /// No throttling
+    pub fn none() -> Self {
+        Self { offline: false, latency_ms: 0.0, download_kbps: None, upload_kbps: None }
+    }
+
+    pub fn offline() -> Self {
+        Self { offline: true, ..Self::none() }
+    }
+
+    /// The "Slow 3G" profile of Chrome DevTools
+    pub fn slow_3g() -> Self {
+        Self { offline: false, latency_ms: 2000.0, download_kbps: Some(400.0), upload_kbps: Some(400.0) }
+    }
+
+    /// The "Fast 3G" profile of Chrome DevTools
+    pub fn fast_3g() -> Self {
+        Self { offline: false, latency_ms: 562.5, download_kbps: Some(1474.6), upload_kbps: Some(675.0) }
+    }
+
+    /// Custom latency and bandwidth limits
+    pub fn custom(latency_ms: f64, download_kbps: Option<f64>, upload_kbps: Option<f64>) -> Self {
+        Self { offline: false, latency_ms, download_kbps, upload_kbps }
+    }
+
+    fn apply(&self, tab: &Tab) -> anyhow::Result<()> {
+        // Throughput is in bytes per second, -1 disables the limit
+        let bytes_per_second = |kbps: Option<f64>| kbps.map_or(-1.0, |kbps| kbps * 1000.0 / 8.0);
+        tab.call_method(Network::Enable {
+            max_total_buffer_size: None,
+            max_resource_buffer_size: None,
+            max_post_data_size: None,
+            report_direct_socket_traffic: None,
+            enable_durable_messages: None,
+        })?;
+        tab.call_method(Network::EmulateNetworkConditions {
+            offline: self.offline,
+            latency: self.latency_ms,
+            download_throughput: bytes_per_second(self.download_kbps),
+            upload_throughput: bytes_per_second(self.upload_kbps),
+            connection_Type: None,
+            packet_loss: None,
+            packet_queue_length: None,
+            packet_reordering: None,
+        })?;
+        Ok(())
+    }
+}
+
+/// Page-level emulation overrides; `None` leaves a setting as it is
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Emulation {
+    pub device: Option<Device>,
+    pub color_scheme: Option<ColorScheme>,
+    pub reduced_motion: Option<bool>,
+    pub media: Option<MediaType>,
+
+    /// IANA timezone, e.g. "Europe/Paris"
+    pub timezone: Option<String>,
+
+    /// BCP 47 locale, e.g. "fr-FR"; also sets the Accept-Language header
+    pub locale: Option<String>,
+
+    pub geolocation: Option<Geolocation>,
+
+    pub network: Option<NetworkConditions>,
+
+    /// CPU slowdown factor, e.g. 4 for a mid-range phone (1: no throttling)
+    pub cpu_throttling: Option<f64>,
+}
+
+impl Emulation {
+    /// Create an empty set of overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder method: emulate a device
+    pub fn device(mut self, device: Device) -> Self {
+        self.device = Some(device);
+        self
+    }
+
+    /// Builder method: set `prefers-color-scheme`
+    pub fn color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.color_scheme = Some(scheme);
+        self
+    }
+
+    /// Builder method: set `prefers-reduced-motion`
+    pub fn reduced_motion(mut self, reduce: bool) -> Self {
+        self.reduced_motion = Some(reduce);
+        self
+    }
+
+    /// Builder method: set the CSS media type
+    pub fn media(mut self, media: MediaType) -> Self {
+        self.media = Some(media);
+        self
+    }
+
+    /// Builder method: set the timezone
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Builder method: set the locale and Accept-Language
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Builder method: set the geolocation
+    pub fn geolocation(mut self, geolocation: Geolocation) -> Self {
+        self.geolocation = Some(geolocation);
+        self
+    }
+
+    /// Builder method: throttle the network
+    pub fn network(mut self, conditions: NetworkConditions) -> Self {
+        self.network = Some(conditions);
+        self
+    }
+
+    /// Builder method: slow the CPU down by `rate`
+    pub fn cpu_throttling(mut self, rate: f64) -> Self {
+        self.cpu_throttling = Some(rate);
+        self
+    }
+
+    /// Whether no override is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Take every override set in `other`, keeping the rest
+    pub fn merge(&mut self, other: Emulation) {
+        self.device = other.device.or(self.device.take());
+        self.color_scheme = other.color_scheme.or(self.color_scheme);
+        self.reduced_motion = other.reduced_motion.or(self.reduced_motion);
+        self.media = other.media.or(self.media);
+        self.timezone = other.timezone.or(self.timezone.take());
+        self.locale = other.locale.or(self.locale.take());
+        self.geolocation = other.geolocation.or(self.geolocation);
+        self.network = other.network.or(self.network);
+        self.cpu_throttling = other.cpu_throttling.or(self.cpu_throttling);
+    }
+
+    /// Apply the overrides that are set to a tab
+    pub fn apply(&self, tab: &Tab) -> Result<()> {
+        let failed = |what: &str, e: anyhow::Error| BrowserError::ChromeError(format!("Failed to {}: {}", what, e));
+
+        if let Some(device) = &self.device {
+            tab.call_method(Cdp::SetDeviceMetricsOverride {
+                width: device.width,
+                height: device.height,
+                device_scale_factor: device.device_scale_factor,
+                mobile: device.mobile,
+                scale: None,
+                screen_width: None,
+                screen_height: None,
+                position_x: None,
+                position_y: None,
+                dont_set_visible_size: None,
+                screen_orientation: None,
+                viewport: None,
+                display_feature: None,
+                device_posture: None,
+            })
+            .map_err(|e| failed("set the viewport", e))?;
+            tab.call_method(Cdp::SetTouchEmulationEnabled {
+                enabled: device.touch,
+                max_touch_points: device.touch.then_some(5),
+            })
+            .map_err(|e| failed("set touch emulation", e))?;
+            // An empty user agent removes a previous device's override
+            if self.locale.is_none() {
+                let user_agent = device.user_agent.as_deref().unwrap_or("");
+                tab.set_user_agent(user_agent, None, None).map_err(|e| failed("set the user agent", e))?;
+            }
+        }
+
+        if self.media.is_some() || self.color_scheme.is_some() || self.reduced_motion.is_some() {
+            tab.call_method(Cdp::SetEmulatedMedia { media: self.media_type(), features: Some(self.media_features()) })
+                .map_err(|e| failed("emulate media", e))?;
+        }
+
+        if let Some(timezone) = &self.timezone {
+            tab.call_method(Cdp::SetTimezoneOverride { timezone_id: timezone.clone() })
+                .map_err(|e| failed("set the timezone", e))?;
+        }
+
+        if let Some(locale) = &self.locale {
+            tab.call_method(Cdp::SetLocaleOverride { locale: Some(locale.clone()) })
+                .map_err(|e| failed("set the locale", e))?;
+            let user_agent = match self.device.as_ref().and_then(|d| d.user_agent.clone()) {
+                Some(user_agent) => user_agent,
+                None => {
+                    if self.device.is_some() {
+                        tab.set_user_agent("", None, None).map_err(|e| failed("set the user agent", e))?;
+                    }
+                    current_user_agent(tab)?
+                }
+            };
+            tab.set_user_agent(&user_agent, Some(&accept_language(locale)), None)
+                .map_err(|e| failed("set Accept-Language", e))?;
+        }
+
+        if let Some(geolocation) = &self.geolocation {
+            // Granting can fail on browsers we connected to; the override still applies to sites
+            // that already have the permission
+            let _ = tab.call_method(GrantPermissions {
+                permissions: vec![PermissionType::Geolocation],
+                origin: None,
+                browser_context_id: None,
+            });
+            tab.call_method(Cdp::SetGeolocationOverride {
+                latitude: Some(geolocation.latitude),
+                longitude: Some(geolocation.longitude),
+                accuracy: Some(geolocation.accuracy),
+                altitude: None,
+                altitude_accuracy: None,
+                heading: None,
+                speed: None,
+            })
+            .map_err(|e| failed("set the geolocation", e))?;
+        }
+
+        if let Some(network) = &self.network {
+            network.apply(tab).map_err(|e| failed("throttle the network", e))?;
+        }
+
+        if let Some(rate) = self.cpu_throttling {
+            tab.call_method(Cdp::SetCPUThrottlingRate { rate: rate.max(1.0) })
+                .map_err(|e| failed("throttle the CPU", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove every emulation override from a tab
+    pub fn clear(tab: &Tab) -> Result<()> {
+        let failed = |e: anyhow::Error| BrowserError::ChromeError(format!("Failed to clear emulation: {}", e));
+        tab.call_method(Cdp::ClearDeviceMetricsOverride(None)).map_err(failed)?;
+        tab.call_method(Cdp::SetTouchEmulationEnabled { enabled: false, max_touch_points: None }).map_err(failed)?;
+        tab.set_user_agent("", None, None).map_err(failed)?;
+        tab.call_method(Cdp::SetEmulatedMedia { media: Some(String::new()), features: Some(vec![]) })
+            .map_err(failed)?;
+        tab.call_method(Cdp::SetTimezoneOverride { timezone_id: String::new() }).map_err(failed)?;
+        tab.call_method(Cdp::SetLocaleOverride { locale: None }).map_err(failed)?;
+        tab.call_method(Cdp::ClearGeolocationOverride(None)).map_err(failed)?;
+        NetworkConditions::none().apply(tab).map_err(failed)?;
+        tab.call_method(Cdp::SetCPUThrottlingRate { rate: 1.0 }).map_err(failed)?;
+        Ok(())
+    }
+
+    fn media_type(&self) -> Option<String> {
+        Some(
+            match self.media {
+                Some(MediaType::Screen) => "screen",
+                Some(MediaType::Print) => "print",
+                None => "",
+            }
+            .to_string(),
+        )
+    }
+
+    fn media_features(&self) -> Vec<Cdp::MediaFeature> {
+        let color_scheme = match self.color_scheme {
+            Some(ColorScheme::Light) => "light",
+            Some(ColorScheme::Dark) => "dark",
+            Some(ColorScheme::NoPreference) => "no-preference",
+            None => "",
+        };
+        let reduced_motion = match self.reduced_motion {
+            Some(true) => "reduce",
+            Some(false) => "no-preference",
+            None => "",
+        };
+        vec![
+            Cdp::MediaFeature { name: "prefers-color-scheme".to_string(), value: color_scheme.to_string() },
+            Cdp::MediaFeature { name: "prefers-reduced-motion".to_string(), value: reduced_motion.to_string() },
+        ]
+    }
+}
+
+/// Accept-Language header for a locale, e.g. "fr-FR" -> "fr-FR,fr;q=0.9"
+pub fn accept_language(locale: &str) -> String {
+    match locale.split_once(['-', '_']) {
+        Some((language, _)) => format!("{},{};q=0.9", locale, language),
+        None => locale.to_string(),
+    }
+}
+
+fn current_user_agent(tab: &Tab) -> Result<String> {
+    tab.evaluate("navigator.userAgent", false)
+        .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?
+        .value
+        .and_then(|v| v.as_str().map(str::to_string))
+        .ok_or_else(|| BrowserError::EvaluationFailed("Failed to read the user agent".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_device_presets() {
+        for name in DEVICE_PRESETS {
+            assert!(Device::preset(name).is_some(), "missing preset {}", name);
+        }
+        let iphone = Device::preset("iPhone 15").unwrap();
+        assert_eq!((iphone.width, iphone.height, iphone.device_scale_factor), (393, 852, 3.0));
+        assert!(iphone.mobile && iphone.touch);
+        assert!(iphone.user_agent.unwrap().contains("iPhone"));
+
+        let landscape = Device::preset("pixel-7").unwrap().landscape();
+        assert_eq!((landscape.width, landscape.height), (915, 412));
+        assert!(Device::preset("desktop_hidpi").unwrap().user_agent.is_none());
+        assert!(Device::preset("toaster").is_none());
+    }
+
+    #[test]
+    fn test_merge_keeps_unset_overrides() {
+        let mut emulation = Emulation::new().device(Device::viewport(800, 600)).timezone("Asia/Tokyo");
+        emulation.merge(Emulation::new().color_scheme(ColorScheme::Dark).timezone("Europe/Paris"));
+        assert_eq!(emulation.device, Some(Device::viewport(800, 600)));
+        assert_eq!(emulation.color_scheme, Some(ColorScheme::Dark));
+        assert_eq!(emulation.timezone.as_deref(), Some("Europe/Paris"));
+        assert!(!emulation.is_empty());
+        assert!(Emulation::new().is_empty());
+    }
+
+    #[test]
+    fn test_network_presets() {
+        assert!(NetworkConditions::offline().offline);
+        assert_eq!(NetworkConditions::slow_3g().latency_ms, 2000.0);
+        assert!(NetworkConditions::fast_3g().download_kbps > NetworkConditions::slow_3g().download_kbps);
+        assert_eq!(NetworkConditions::none().download_kbps, None);
+
+        let mut emulation = Emulation::new().network(NetworkConditions::slow_3g());
+        emulation.merge(Emulation::new().cpu_throttling(4.0));
+        assert_eq!(emulation.network, Some(NetworkConditions::slow_3g()));
+        assert_eq!(emulation.cpu_throttling, Some(4.0));
+    }
+
+    #[test]
+    fn test_accept_language() {
+        assert_eq!(accept_language("fr-FR"), "fr-FR,fr;q=0.9");
+        assert_eq!(accept_language("de"), "de");
+    }
+}
@@ -0,0 +1,326 @@
+//! Human-like input timing and movement
+//!
+//! A [`HumanEmulation`] profile set on [`LaunchOptions`](super::LaunchOptions) makes the
+//! interaction tools move the mouse along curved paths, hover before clicking, type with
+//! per-key delays and occasional corrected typos, and scroll in decaying steps. All randomness
+//! comes from one seedable generator so a run can be reproduced.
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::{ops::RangeInclusive, time::Duration};
+
+/// Profile for human-like input
+#[derive(Debug, Clone, PartialEq)]
+pub struct HumanEmulation {
+    /// Seed for the random generator (None: seeded from the OS)
+    pub seed: Option<u64>,
+
+    /// Delay between key presses, in milliseconds
+    pub typing_delay_ms: RangeInclusive<u64>,
+
+    /// Chance per character of hitting a neighboring key first and correcting it
+    pub typo_rate: f64,
+
+    /// Time spent hovering over a target before pressing, in milliseconds
+    pub hover_ms: RangeInclusive<u64>,
+
+    /// Time a mouse button is held during a click, in milliseconds
+    pub press_ms: RangeInclusive<u64>,
+
+    /// Delay between mouse move events along a path, in milliseconds
+    pub move_delay_ms: RangeInclusive<u64>,
+
+    /// Delay between the wheel steps of one scroll, in milliseconds
+    pub scroll_delay_ms: RangeInclusive<u64>,
+}
+
+impl Default for HumanEmulation {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            typing_delay_ms: 60..=180,
+            typo_rate: 0.03,
+            hover_ms: 80..=250,
+            press_ms: 40..=120,
+            move_delay_ms: 6..=16,
+            scroll_delay_ms: 16..=40,
+        }
+    }
+}
+
+impl HumanEmulation {
+    /// Create a profile with default timings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder method: seed the random generator for reproducible runs
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Builder method: set the delay range between key presses
+    pub fn typing_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.typing_delay_ms = min_ms..=max_ms.max(min_ms);
+        self
+    }
+
+    /// Builder method: set the chance of a corrected typo per character (0 disables typos)
+    pub fn typo_rate(mut self, rate: f64) -> Self {
+        self.typo_rate = valid_rate(rate);
+        self
+    }
+
+    /// Builder method: set the hover time before clicks
+    pub fn hover_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.hover_ms = min_ms..=max_ms.max(min_ms);
+        self
+    }
+
+    /// Builder method: set how long a mouse button is held during a click
+    pub fn press_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.press_ms = min_ms..=max_ms.max(min_ms);
+        self
+    }
+
+    /// Builder method: set the delay between mouse move events along a path
+    pub fn move_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.move_delay_ms = min_ms..=max_ms.max(min_ms);
+        self
+    }
+
+    /// Builder method: set the delay between the wheel steps of one scroll
+    pub fn scroll_delay(mut self, min_ms: u64, max_ms: u64) -> Self {
+        self.scroll_delay_ms = min_ms..=max_ms.max(min_ms);
+        self
+    }
+
+    /// Repair values set directly on the fields that would make the random generator panic:
+    /// reversed ranges are swapped and the typo rate is kept within 0..=1
+    fn normalized(mut self) -> Self {
+        for range in [
+            &mut self.typing_delay_ms,
+            &mut self.hover_ms,
+            &mut self.press_ms,
+            &mut self.move_delay_ms,
+            &mut self.scroll_delay_ms,
+        ] {
+            if range.start() > range.end() {
+                *range = *range.end()..=*range.start();
+            }
+        }
+        self.typo_rate = valid_rate(self.typo_rate);
+        self
+    }
+}
+
+/// Clamp a probability to 0..=1, treating NaN as 0
+fn valid_rate(rate: f64) -> f64 {
+    if rate.is_nan() { 0.0 } else { rate.clamp(0.0, 1.0) }
+}
+
+/// One step of a humanized scroll: the wheel delta and the pause after it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollStep {
+    pub delta: f64,
+    pub pause: Duration,
+}
+
+/// Random source that turns a [`HumanEmulation`] profile into concrete paths and delays
+#[derive(Debug)]
+pub struct Humanizer {
+    profile: HumanEmulation,
+    rng: StdRng,
+}
+
+impl Humanizer {
+    pub fn new(profile: HumanEmulation) -> Self {
+        let profile = profile.normalized();
+        let rng = match profile.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Self { profile, rng }
+    }
+
+    pub fn profile(&self) -> &HumanEmulation {
+        &self.profile
+    }
+
+    fn millis(&mut self, range: &RangeInclusive<u64>) -> Duration {
+        Duration::from_millis(self.rng.gen_range(range.clone()))
+    }
+
+    /// Points along a curved path from `from` to `to`, ending exactly at `to`.
+    ///
+    /// The path is a cubic Bézier curve whose control points are pushed off the straight line
+    /// by a random amount, sampled with ease-in-out spacing (slow start, fast middle, slow end).
+    pub fn mouse_path(&mut self, from: (f64, f64), to: (f64, f64)) -> Vec<(f64, f64)> {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance < 1.0 {
+            return vec![to];
+        }
+
+        // Unit normal to the straight line, used to bend the curve
+        let (nx, ny) = (-dy / distance, dx / distance);
+        let spread = (distance * 0.3).min(120.0);
+        let mut control = |t: f64| {
+            let offset = self.rng.gen_range(-spread..=spread);
+            (from.0 + dx * t + nx * offset, from.1 + dy * t + ny * offset)
+        };
+        let (c1, c2) = (control(0.3), control(0.7));
+
+        let steps = ((distance / 12.0).round() as usize).clamp(8, 40);
+        let mut path = Vec::with_capacity(steps);
+        for step in 1..=steps {
+            let linear = step as f64 / steps as f64;
+            let t = linear * linear * (3.0 - 2.0 * linear);
+            let u = 1.0 - t;
+            let x = u * u * u * from.0 + 3.0 * u * u * t * c1.0 + 3.0 * u * t * t * c2.0 + t * t * t * to.0;
+            let y = u * u * u * from.1 + 3.0 * u * u * t * c1.1 + 3.0 * u * t * t * c2.1 + t * t * t * to.1;
+            path.push((x, y));
+        }
+        path
+    }
+
+    /// Pause between two mouse move events
+    pub fn move_delay(&mut self) -> Duration {
+        let range = self.profile.move_delay_ms.clone();
+        self.millis(&range)
+    }
+
+    /// Pause over a target before pressing it
+    pub fn hover_delay(&mut self) -> Duration {
+        let range = self.profile.hover_ms.clone();
+        self.millis(&range)
+    }
+
+    /// How long to hold a mouse button during a click
+    pub fn press_delay(&mut self) -> Duration {
+        let range = self.profile.press_ms.clone();
+        self.millis(&range)
+    }
+
+    /// Pause after a key press
+    pub fn key_delay(&mut self) -> Duration {
+        let range = self.profile.typing_delay_ms.clone();
+        self.millis(&range)
+    }
+
+    /// A neighboring key to hit by mistake before `ch`, if this character gets a typo
+    pub fn typo(&mut self, ch: char) -> Option<char> {
+        if self.profile.typo_rate <= 0.0 || !self.rng.gen_bool(self.profile.typo_rate) {
+            return None;
+        }
+        let neighbors = keyboard_neighbors(ch.to_ascii_lowercase())?;
+        let typo = neighbors.chars().nth(self.rng.gen_range(0..neighbors.len()))?;
+        Some(if ch.is_ascii_uppercase() { typo.to_ascii_uppercase() } else { typo })
+    }
+
+    /// Split a scroll into wheel steps that start large and decay, like a flick with momentum
+    pub fn scroll_steps(&mut self, amount: f64) -> Vec<ScrollStep> {
+        let count = self.rng.gen_range(5..=9);
+        let weights: Vec<f64> = (0..count).map(|i| 0.65f64.powi(i) * self.rng.gen_range(0.85..=1.15)).collect();
+        let total: f64 = weights.iter().sum();
+        let range = self.profile.scroll_delay_ms.clone();
+        weights
+            .into_iter()
+            .map(|w| ScrollStep { delta: amount * w / total, pause: self.millis(&range) })
+            .collect()
+    }
+}
+
+/// Keys next to `ch` on a QWERTY keyboard
+fn keyboard_neighbors(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        'q' => "wa",
+        'w' => "qes",
+        'e' => "wrd",
+        'r' => "etf",
+        't' => "ryg",
+        'y' => "tuh",
+        'u' => "yij",
+        'i' => "uok",
+        'o' => "ipl",
+        'p' => "ol",
+        'a' => "qsz",
+        's' => "awdz",
+        'd' => "sefx",
+        'f' => "drgc",
+        'g' => "fthv",
+        'h' => "gyjb",
+        'j' => "hukn",
+        'k' => "jilm",
+        'l' => "kop",
+        'z' => "asx",
+        'x' => "zsdc",
+        'c' => "xdfv",
+        'v' => "cfgb",
+        'b' => "vghn",
+        'n' => "bhjm",
+        'm' => "njk",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_runs_repeat() {
+        let profile = HumanEmulation::new().seed(7);
+        let mut a = Humanizer::new(profile.clone());
+        let mut b = Humanizer::new(profile);
+        assert_eq!(a.mouse_path((0.0, 0.0), (400.0, 300.0)), b.mouse_path((0.0, 0.0), (400.0, 300.0)));
+        assert_eq!(a.key_delay(), b.key_delay());
+        assert_eq!(a.scroll_steps(500.0), b.scroll_steps(500.0));
+    }
+
+    #[test]
+    fn test_mouse_path_is_curved_and_ends_at_target() {
+        let mut human = Humanizer::new(HumanEmulation::new().seed(1));
+        let path = human.mouse_path((10.0, 10.0), (510.0, 10.0));
+        assert!(path.len() >= 8);
+        let last = path.last().unwrap();
+        assert!((last.0 - 510.0).abs() < 1e-6 && (last.1 - 10.0).abs() < 1e-6);
+        assert!(path.iter().any(|p| (p.1 - 10.0).abs() > 1.0), "path should leave the straight line");
+    }
+
+    #[test]
+    fn test_scroll_steps_sum_and_decay() {
+        let mut human = Humanizer::new(HumanEmulation::new().seed(3));
+        let steps = human.scroll_steps(-600.0);
+        let total: f64 = steps.iter().map(|s| s.delta).sum();
+        assert!((total + 600.0).abs() < 1e-6);
+        assert!(steps.first().unwrap().delta.abs() > steps.last().unwrap().delta.abs());
+    }
+
+    #[test]
+    fn test_typos_use_neighboring_keys() {
+        let mut human = Humanizer::new(HumanEmulation::new().seed(5).typo_rate(1.0));
+        assert!("wrd".contains(human.typo('e').unwrap()));
+        assert!("WRD".contains(human.typo('E').unwrap()));
+        assert_eq!(human.typo('7'), None);
+
+        let mut careful = Humanizer::new(HumanEmulation::new().typo_rate(0.0));
+        assert_eq!(careful.typo('e'), None);
+    }
+
+    #[test]
+    fn test_hand_built_profile_is_repaired() {
+        let reversed = RangeInclusive::new(100, 50);
+        let profile = HumanEmulation { press_ms: reversed, typo_rate: f64::NAN, ..HumanEmulation::new().seed(3) };
+        let mut human = Humanizer::new(profile);
+        assert_eq!(human.profile().press_ms, 50..=100);
+        assert_eq!(human.profile().typo_rate, 0.0);
+        let press = human.press_delay();
+        assert!(press >= Duration::from_millis(50) && press <= Duration::from_millis(100));
+        assert_eq!(human.typo('e'), None);
+
+        let eager = Humanizer::new(HumanEmulation { typo_rate: 5.0, ..HumanEmulation::new() });
+        assert_eq!(eager.profile().typo_rate, 1.0);
+        assert_eq!(HumanEmulation::new().typo_rate(f64::NAN).typo_rate, 0.0);
+    }
+}
@@ -0,0 +1,360 @@
+//! Keyboard input: key chords and text typing
+//!
+//! Chords such as `Control+Shift+K` are parsed into modifiers and a key, then dispatched with
+//! `Input.dispatchKeyEvent`: each modifier is pressed and held, the key is pressed and released,
+//! and the modifiers are released in reverse order.
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab, protocol::cdp::Input};
+use std::{fmt, str::FromStr, time::Duration};
+
+/// Modifier key, with its bit in the `modifiers` mask of input events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    Alt = 1,
+    Control = 2,
+    Meta = 4,
+    Shift = 8,
+}
+
+impl Modifier {
+    /// Parse a modifier name or alias (Ctrl, Cmd, Option, ...), case-insensitively
+    pub fn parse(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "alt" | "option" | "opt" => Modifier::Alt,
+            "control" | "ctrl" => Modifier::Control,
+            "meta" | "cmd" | "command" | "super" | "win" | "windows" => Modifier::Meta,
+            "shift" => Modifier::Shift,
+            // The platform's primary shortcut modifier
+            "controlormeta" | "mod" => {
+                if cfg!(target_os = "macos") {
+                    Modifier::Meta
+                } else {
+                    Modifier::Control
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    fn key(self) -> &'static str {
+        match self {
+            Modifier::Alt => "Alt",
+            Modifier::Control => "Control",
+            Modifier::Meta => "Meta",
+            Modifier::Shift => "Shift",
+        }
+    }
+}
+
+/// A key with optional modifiers, e.g. `Control+Shift+K`, `Shift+Tab` or `Meta+Enter`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chord {
+    pub modifiers: Vec<Modifier>,
+    pub key: String,
+}
+
+impl Chord {
+    /// Parse a chord: modifiers and a key joined with `+` (a trailing `+` means the plus key)
+    pub fn parse(chord: &str) -> Result<Self> {
+        let chord = chord.trim();
+        let invalid =
+            |reason: &str| BrowserError::InvalidArgument(format!("Invalid key chord '{}': {}", chord, reason));
+
+        let (head, key) = match chord.strip_suffix("++") {
+            Some(head) => (head, "+"),
+            None if chord == "+" => ("", "+"),
+            None => match chord.rsplit_once('+') {
+                Some((head, key)) => (head, key),
+                None => ("", chord),
+            },
+        };
+        if key.trim().is_empty() {
+            return Err(invalid("missing key"));
+        }
+
+        let mut modifiers = Vec::new();
+        for name in head.split('+').map(str::trim).filter(|n| !n.is_empty()) {
+            let modifier = Modifier::parse(name).ok_or_else(|| invalid(&format!("unknown modifier '{}'", name)))?;
+            if !modifiers.contains(&modifier) {
+                modifiers.push(modifier);
+            }
+        }
+
+        let key = normalize_key(key.trim());
+        if key_definition(&key).is_none() {
+            return Err(invalid(&format!("unknown key '{}'", key)));
+        }
+        Ok(Self { modifiers, key })
+    }
+
+    /// The `modifiers` mask for events sent while the whole chord is held
+    pub fn modifiers_mask(&self) -> u32 {
+        self.modifiers.iter().fold(0, |mask, m| mask | *m as u32)
+    }
+}
+
+impl FromStr for Chord {
+    type Err = BrowserError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Chord::parse(s)
+    }
+}
+
+impl fmt::Display for Chord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{}+", modifier.key())?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
+
+/// Map common aliases to DOM key names
+fn normalize_key(key: &str) -> String {
+    match key.to_ascii_lowercase().as_str() {
+        "esc" => "Escape".to_string(),
+        "return" => "Enter".to_string(),
+        "space" | "spacebar" => " ".to_string(),
+        "del" => "Delete".to_string(),
+        "ins" => "Insert".to_string(),
+        "up" => "ArrowUp".to_string(),
+        "down" => "ArrowDown".to_string(),
+        "left" => "ArrowLeft".to_string(),
+        "right" => "ArrowRight".to_string(),
+        "pgup" => "PageUp".to_string(),
+        "pgdn" => "PageDown".to_string(),
+        _ => key.to_string(),
+    }
+}
+
+/// DOM `key`, `code`, Windows virtual key code and typed text of a key
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyDefinition {
+    key: String,
+    code: String,
+    key_code: u32,
+    text: Option<String>,
+}
+
+/// Look up a key by its DOM key name or printable character
+fn key_definition(key: &str) -> Option<KeyDefinition> {
+    let def = |key: &str, code: &str, key_code: u32, text: Option<&str>| KeyDefinition {
+        key: key.to_string(),
+        code: code.to_string(),
+        key_code,
+        text: text.map(str::to_string),
+    };
+
+    let mut chars = key.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        let text = ch.to_string();
+        if ch.is_ascii_alphabetic() {
+            let upper = ch.to_ascii_uppercase();
+            return Some(def(key, &format!("Key{}", upper), upper as u32, Some(&text)));
+        }
+        if ch.is_ascii_digit() {
+            return Some(def(key, &format!("Digit{}", ch), ch as u32, Some(&text)));
+        }
+        let (code, key_code) = match ch {
+            ' ' => ("Space", 32),
+            ';' | ':' => ("Semicolon", 186),
+            '=' | '+' => ("Equal", 187),
+            ',' | '<' => ("Comma", 188),
+            '-' | '_' => ("Minus", 189),
+            '.' | '>' => ("Period", 190),
+            '/' | '?' => ("Slash", 191),
+            '`' | '~' => ("Backquote", 192),
+            '[' | '{' => ("BracketLeft", 219),
+            '\\' | '|' => ("Backslash", 220),
+            ']' | '}' => ("BracketRight", 221),
+            '\'' | '"' => ("Quote", 222),
+            '!' => ("Digit1", 49),
+            '@' => ("Digit2", 50),
+            '#' => ("Digit3", 51),
+            '$' => ("Digit4", 52),
+            '%' => ("Digit5", 53),
+            '^' => ("Digit6", 54),
+            '&' => ("Digit7", 55),
+            '*' => ("Digit8", 56),
+            '(' => ("Digit9", 57),
+            ')' => ("Digit0", 48),
+            '\n' | '\r' => return Some(def("Enter", "Enter", 13, Some("\r"))),
+            '\t' => return Some(def("Tab", "Tab", 9, None)),
+            _ => return None,
+        };
+        return Some(def(key, code, key_code, Some(&text)));
+    }
+
+    if let Some(n) = key.strip_prefix('F').and_then(|n| n.parse::<u32>().ok())
+        && (1..=24).contains(&n)
+    {
+        return Some(def(key, key, 111 + n, None));
+    }
+
+    let (code, key_code, text) = match key {
+        "Enter" => ("Enter", 13, Some("\r")),
+        "Tab" => ("Tab", 9, None),
+        "Backspace" => ("Backspace", 8, None),
+        "Escape" => ("Escape", 27, None),
+        "Delete" => ("Delete", 46, None),
+        "Insert" => ("Insert", 45, None),
+        "Home" => ("Home", 36, None),
+        "End" => ("End", 35, None),
+        "PageUp" => ("PageUp", 33, None),
+        "PageDown" => ("PageDown", 34, None),
+        "ArrowLeft" => ("ArrowLeft", 37, None),
+        "ArrowUp" => ("ArrowUp", 38, None),
+        "ArrowRight" => ("ArrowRight", 39, None),
+        "ArrowDown" => ("ArrowDown", 40, None),
+        "Shift" => ("ShiftLeft", 16, None),
+        "Control" => ("ControlLeft", 17, None),
+        "Alt" => ("AltLeft", 18, None),
+        "Meta" => ("MetaLeft", 91, None),
+        "CapsLock" => ("CapsLock", 20, None),
+        "ContextMenu" => ("ContextMenu", 93, None),
+        "Pause" => ("Pause", 19, None),
+        "PrintScreen" => ("PrintScreen", 44, None),
+        _ => return None,
+    };
+    Some(def(key, code, key_code, text))
+}
+
+/// Send one key event
+fn dispatch_key(
+    tab: &Tab,
+    event_type: Input::DispatchKeyEventTypeOption,
+    definition: &KeyDefinition,
+    text: Option<String>,
+    modifiers: u32,
+) -> Result<()> {
+    tab.call_method(Input::DispatchKeyEvent {
+        Type: event_type,
+        modifiers: Some(modifiers),
+        timestamp: None,
+        text: text.clone(),
+        unmodified_text: text,
+        key_identifier: None,
+        code: Some(definition.code.clone()),
+        key: Some(definition.key.clone()),
+        windows_virtual_key_code: Some(definition.key_code),
+        native_virtual_key_code: Some(definition.key_code),
+        auto_repeat: None,
+        is_keypad: None,
+        is_system_key: None,
+        location: None,
+        commands: None,
+    })
+    .map_err(|e| BrowserError::ChromeError(format!("Failed to dispatch key event: {}", e)))?;
+    Ok(())
+}
+
+/// Press a chord: hold the modifiers, press and release the key, then release the modifiers
+pub fn press_chord(tab: &Tab, chord: &Chord) -> Result<()> {
+    let mut mask = 0;
+    for modifier in &chord.modifiers {
+        mask |= *modifier as u32;
+        let definition = key_definition(modifier.key()).expect("modifier keys are defined");
+        dispatch_key(tab, Input::DispatchKeyEventTypeOption::RawKeyDown, &definition, None, mask)?;
+    }
+
+    let mut definition = key_definition(&chord.key)
+        .ok_or_else(|| BrowserError::InvalidArgument(format!("Unknown key '{}'", chord.key)))?;
+    if mask & Modifier::Shift as u32 != 0 && definition.key.len() == 1 {
+        definition.key = definition.key.to_uppercase();
+        definition.text = definition.text.map(|t| t.to_uppercase());
+    }
+    // Shortcuts with Control, Alt or Meta do not insert text
+    let shortcut = mask & (Modifier::Control as u32 | Modifier::Alt as u32 | Modifier::Meta as u32) != 0;
+    let text = if shortcut { None } else { definition.text.clone() };
+    let down_type = if text.is_some() {
+        Input::DispatchKeyEventTypeOption::KeyDown
+    } else {
+        Input::DispatchKeyEventTypeOption::RawKeyDown
+    };
+    dispatch_key(tab, down_type, &definition, text, mask)?;
+    dispatch_key(tab, Input::DispatchKeyEventTypeOption::KeyUp, &definition, None, mask)?;
+
+    for modifier in chord.modifiers.iter().rev() {
+        mask &= !(*modifier as u32);
+        let definition = key_definition(modifier.key()).expect("modifier keys are defined");
+        dispatch_key(tab, Input::DispatchKeyEventTypeOption::KeyUp, &definition, None, mask)?;
+    }
+    Ok(())
+}
+
+/// Type text into the focused element key by key, pausing `delay` after each character.
+///
+/// Characters without a key on a US keyboard (accents, emoji, CJK) are inserted as text.
+pub fn type_text(tab: &Tab, text: &str, delay: Duration) -> Result<()> {
+    for ch in text.chars() {
+        match key_definition(&ch.to_string()) {
+            Some(definition) => {
+                let text = definition.text.clone();
+                let down_type = if text.is_some() {
+                    Input::DispatchKeyEventTypeOption::KeyDown
+                } else {
+                    Input::DispatchKeyEventTypeOption::RawKeyDown
+                };
+                dispatch_key(tab, down_type, &definition, text, 0)?;
+                dispatch_key(tab, Input::DispatchKeyEventTypeOption::KeyUp, &definition, None, 0)?;
+            }
+            None => insert_text(tab, &ch.to_string())?,
+        }
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+    }
+    Ok(())
+}
+
+/// Insert text into the focused element in one go, like an IME commit (no key events)
+pub fn insert_text(tab: &Tab, text: &str) -> Result<()> {
+    tab.call_method(Input::InsertText { text: text.to_string() })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to insert text: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chords() {
+        let chord = Chord::parse("Control+Shift+K").unwrap();
+        assert_eq!(chord.modifiers, vec![Modifier::Control, Modifier::Shift]);
+        assert_eq!(chord.key, "K");
+        assert_eq!(chord.modifiers_mask(), 10);
+
+        let chord: Chord = "ctrl+a".parse().unwrap();
+        assert_eq!((chord.modifiers, chord.key.as_str()), (vec![Modifier::Control], "a"));
+
+        let chord = Chord::parse("Cmd+Enter").unwrap();
+        assert_eq!((chord.modifiers, chord.key.as_str()), (vec![Modifier::Meta], "Enter"));
+
+        assert_eq!(Chord::parse("Shift+Tab").unwrap().to_string(), "Shift+Tab");
+        assert_eq!(Chord::parse("Escape").unwrap().modifiers, vec![]);
+        assert_eq!(Chord::parse("Esc").unwrap().key, "Escape");
+        assert_eq!(Chord::parse("Control++").unwrap().key, "+");
+        assert_eq!(Chord::parse("Space").unwrap().key, " ");
+        assert_eq!(Chord::parse("F12").unwrap().key, "F12");
+    }
+
+    #[test]
+    fn test_parse_invalid_chords() {
+        assert!(Chord::parse("Hyper+K").is_err());
+        assert!(Chord::parse("Control+").is_err());
+        assert!(Chord::parse("Control+NoSuchKey").is_err());
+        assert!(Chord::parse("").is_err());
+    }
+
+    #[test]
+    fn test_key_definitions() {
+        let a = key_definition("a").unwrap();
+        assert_eq!((a.code.as_str(), a.key_code, a.text.as_deref()), ("KeyA", 65, Some("a")));
+        assert_eq!(key_definition("F5").unwrap().key_code, 116);
+        assert_eq!(key_definition("?").unwrap().code, "Slash");
+        assert!(key_definition("é").is_none());
+    }
+}
@@ -5,9 +5,20 @@
 
 pub mod config;
 pub mod debug;
+pub mod emulation;
+pub mod human;
+pub mod keyboard;
+pub mod mouse;
+pub mod recording;
 pub mod session;
+pub mod touch;
 
 pub use config::{ConnectionOptions, LaunchOptions};
+pub use emulation::{ColorScheme, Device, Emulation, Geolocation, MediaType, NetworkConditions};
+pub use human::{HumanEmulation, Humanizer};
+pub use keyboard::{Chord, Modifier};
+pub use mouse::{Mouse, MouseButton};
+pub use recording::{Recording, RecordingFormat, RecordingOptions};
 pub use session::BrowserSession;
 
 use crate::error::Result;
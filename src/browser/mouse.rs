@@ -0,0 +1,177 @@
+//! Raw mouse input
+//!
+//! Mouse events are dispatched with `Input.dispatchMouseEvent` at viewport coordinates (CSS
+//! pixels). The pointer position and held buttons are kept between calls, so a `mouse_down`
+//! followed by `mouse_move` calls is seen by the page as a drag.
+
+use crate::{browser::human::Humanizer,
+            error::{BrowserError, Result}};
+use headless_chrome::{Tab, protocol::cdp::Input};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Mouse button for raw mouse events
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MouseButton {
+    #[default]
+    Left,
+    Right,
+    Middle,
+    Back,
+    Forward,
+}
+
+impl MouseButton {
+    fn cdp(self) -> Input::MouseButton {
+        match self {
+            MouseButton::Left => Input::MouseButton::Left,
+            MouseButton::Right => Input::MouseButton::Right,
+            MouseButton::Middle => Input::MouseButton::Middle,
+            MouseButton::Back => Input::MouseButton::Back,
+            MouseButton::Forward => Input::MouseButton::Forward,
+        }
+    }
+
+    /// Bit of this button in the `buttons` mask of mouse events
+    fn mask(self) -> u32 {
+        match self {
+            MouseButton::Left => 1,
+            MouseButton::Right => 2,
+            MouseButton::Middle => 4,
+            MouseButton::Back => 8,
+            MouseButton::Forward => 16,
+        }
+    }
+}
+
+/// Pointer position and held buttons
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Mouse {
+    pub x: f64,
+    pub y: f64,
+    buttons: u32,
+}
+
+impl Mouse {
+    /// Buttons currently held down, as a `buttons` mask
+    pub fn buttons(&self) -> u32 {
+        self.buttons
+    }
+
+    /// The held button reported with move events (the lowest one when several are held)
+    fn held_button(&self) -> Option<MouseButton> {
+        [MouseButton::Left, MouseButton::Right, MouseButton::Middle, MouseButton::Back, MouseButton::Forward]
+            .into_iter()
+            .find(|b| self.buttons & b.mask() != 0)
+    }
+
+    /// Move the pointer to (x, y) in `steps` evenly spaced moves
+    pub fn move_to(&mut self, tab: &Tab, x: f64, y: f64, steps: u32) -> Result<()> {
+        let steps = steps.max(1);
+        let (from_x, from_y) = (self.x, self.y);
+        for step in 1..=steps {
+            let t = step as f64 / steps as f64;
+            let (px, py) = (from_x + (x - from_x) * t, from_y + (y - from_y) * t);
+            let button = self.held_button();
+            self.dispatch(tab, Input::DispatchMouseEventTypeOption::MouseMoved, px, py, button, None, None)?;
+            self.x = px;
+            self.y = py;
+        }
+        Ok(())
+    }
+
+    /// Move the pointer to (x, y) along a curved path with human-like pauses between moves
+    pub fn move_human(&mut self, tab: &Tab, x: f64, y: f64, human: &mut Humanizer) -> Result<()> {
+        for (px, py) in human.mouse_path((self.x, self.y), (x, y)) {
+            self.move_to(tab, px, py, 1)?;
+            std::thread::sleep(human.move_delay());
+        }
+        Ok(())
+    }
+
+    /// Press a button at the current position
+    pub fn down(&mut self, tab: &Tab, button: MouseButton, click_count: u32) -> Result<()> {
+        self.buttons |= button.mask();
+        let (x, y) = (self.x, self.y);
+        let event_type = Input::DispatchMouseEventTypeOption::MousePressed;
+        self.dispatch(tab, event_type, x, y, Some(button), Some(click_count), None)
+    }
+
+    /// Release a button at the current position
+    pub fn up(&mut self, tab: &Tab, button: MouseButton, click_count: u32) -> Result<()> {
+        self.buttons &= !button.mask();
+        let (x, y) = (self.x, self.y);
+        let event_type = Input::DispatchMouseEventTypeOption::MouseReleased;
+        self.dispatch(tab, event_type, x, y, Some(button), Some(click_count), None)
+    }
+
+    /// Move to (x, y) and click `click_count` times (2 for a double click)
+    pub fn click(&mut self, tab: &Tab, x: f64, y: f64, button: MouseButton, click_count: u32) -> Result<()> {
+        self.move_to(tab, x, y, 1)?;
+        for count in 1..=click_count.max(1) {
+            self.down(tab, button, count)?;
+            self.up(tab, button, count)?;
+        }
+        Ok(())
+    }
+
+    /// Scroll the wheel at the current position
+    pub fn wheel(&mut self, tab: &Tab, delta_x: f64, delta_y: f64) -> Result<()> {
+        let (x, y) = (self.x, self.y);
+        self.dispatch(tab, Input::DispatchMouseEventTypeOption::MouseWheel, x, y, None, None, Some((delta_x, delta_y)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        &self,
+        tab: &Tab,
+        event_type: Input::DispatchMouseEventTypeOption,
+        x: f64,
+        y: f64,
+        button: Option<MouseButton>,
+        click_count: Option<u32>,
+        delta: Option<(f64, f64)>,
+    ) -> Result<()> {
+        tab.call_method(Input::DispatchMouseEvent {
+            Type: event_type,
+            x,
+            y,
+            modifiers: None,
+            timestamp: None,
+            button: Some(button.map_or(Input::MouseButton::None, MouseButton::cdp)),
+            buttons: Some(self.buttons),
+            click_count,
+            force: None,
+            tangential_pressure: None,
+            tilt_x: None,
+            tilt_y: None,
+            twist: None,
+            delta_x: delta.map(|d| d.0),
+            delta_y: delta.map(|d| d.1),
+            pointer_Type: None,
+        })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to dispatch mouse event: {}", e)))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_button() {
+        let mut mouse = Mouse::default();
+        assert_eq!(mouse.held_button(), None);
+        mouse.buttons = MouseButton::Right.mask() | MouseButton::Middle.mask();
+        assert_eq!(mouse.held_button(), Some(MouseButton::Right));
+        assert_eq!(mouse.buttons(), 6);
+    }
+
+    #[test]
+    fn test_button_deserialize() {
+        let button: MouseButton = serde_json::from_value(serde_json::json!("middle")).unwrap();
+        assert_eq!(button, MouseButton::Middle);
+    }
+}
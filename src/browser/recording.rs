@@ -0,0 +1,387 @@
+//! Screencast recording of a tab
+//!
+//! Frames come from `Page.startScreencast` and are kept in memory with their timestamps until the
+//! recording is stopped, then encoded as an animated GIF or a numbered PNG sequence. While a
+//! recording runs, tool calls are noted as captions and drawn onto the frames that follow them.
+
+use crate::{error::{BrowserError, Result},
+            tools::annotate::label_font};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::{Tab, browser::tab::EventListener, protocol::cdp::{Page, types::Event}};
+use image::{Delay, Rgba, RgbaImage, codecs::gif::{GifEncoder, Repeat}};
+use imageproc::{drawing::{draw_filled_rect_mut, draw_text_mut}, rect::Rect};
+use rusttype::{Font, Scale};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{fs::File,
+          io::BufWriter,
+          path::{Path, PathBuf},
+          sync::{Arc, Mutex, Weak, mpsc},
+          thread,
+          time::Instant};
+
+/// Shortest frame delay most GIF viewers honor, in milliseconds
+const MIN_GIF_DELAY_MS: u32 = 20;
+
+/// Height of the caption bar drawn at the bottom of captioned frames
+const CAPTION_HEIGHT: u32 = 24;
+
+/// Screencast settings
+#[derive(Debug, Clone)]
+pub struct RecordingOptions {
+    /// Maximum frame width in pixels
+    pub max_width: Option<u32>,
+
+    /// Maximum frame height in pixels
+    pub max_height: Option<u32>,
+
+    /// Capture only every n-th frame Chrome produces
+    pub every_nth_frame: u32,
+
+    /// JPEG quality of the captured frames (0-100)
+    pub quality: u32,
+
+    /// Draw the most recent action onto each frame
+    pub captions: bool,
+
+    /// Stop buffering frames once this many have been captured
+    pub max_frames: usize,
+}
+
+impl Default for RecordingOptions {
+    fn default() -> Self {
+        Self {
+            max_width: Some(1280),
+            max_height: Some(800),
+            every_nth_frame: 1,
+            quality: 80,
+            captions: true,
+            max_frames: 3000,
+        }
+    }
+}
+
+/// A captured frame
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Encoded image as delivered by Chrome (JPEG)
+    pub data: Vec<u8>,
+
+    /// Seconds since the recording started
+    pub timestamp: f64,
+
+    /// Action in progress when the frame was captured
+    pub caption: Option<String>,
+}
+
+/// Output format for [`Recording::save`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingFormat {
+    /// Single animated GIF file
+    #[default]
+    Gif,
+    /// Directory of numbered PNG files (frame_00001.png, ...)
+    Png,
+}
+
+/// A running screencast
+pub struct Recording {
+    tab: Arc<Tab>,
+    listener: Weak<dyn EventListener<Event> + Send + Sync>,
+    frames: Arc<Mutex<Vec<Frame>>>,
+    caption: Arc<Mutex<Option<String>>>,
+    options: RecordingOptions,
+    started: Instant,
+}
+
+impl Recording {
+    /// Start recording the given tab
+    pub fn start(tab: Arc<Tab>, options: RecordingOptions) -> Result<Self> {
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let caption: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let started = Instant::now();
+
+        // Frames must be acknowledged before Chrome sends more. The listener runs on the tab's
+        // event thread, so acknowledgements are sent from a separate thread.
+        let (ack_tx, ack_rx) = mpsc::channel::<u32>();
+        let ack_tab = tab.clone();
+        thread::spawn(move || {
+            for session_id in ack_rx {
+                if ack_tab.ack_screencast(session_id).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let listener_frames = frames.clone();
+        let listener_caption = caption.clone();
+        let max_frames = options.max_frames;
+        let ack_tx = Mutex::new(ack_tx);
+        let listener = tab
+            .add_event_listener(Arc::new(move |event: &Event| {
+                if let Event::PageScreencastFrame(frame) = event {
+                    if let Ok(data) = BASE64.decode(&frame.params.data)
+                        && let Ok(mut frames) = listener_frames.lock()
+                        && frames.len() < max_frames
+                    {
+                        frames.push(Frame {
+                            data,
+                            timestamp: started.elapsed().as_secs_f64(),
+                            caption: listener_caption.lock().ok().and_then(|c| c.clone()),
+                        });
+                    }
+                    if let Ok(tx) = ack_tx.lock() {
+                        let _ = tx.send(frame.params.session_id);
+                    }
+                }
+            }))
+            .map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+
+        let started_screencast = tab.start_screencast(
+            Some(Page::StartScreencastFormatOption::Jpeg),
+            Some(options.quality.min(100)),
+            options.max_width,
+            options.max_height,
+            Some(options.every_nth_frame.max(1)),
+        );
+        if let Err(e) = started_screencast {
+            let _ = tab.remove_event_listener(&listener);
+            return Err(BrowserError::ChromeError(format!("Failed to start screencast: {}", e)));
+        }
+
+        Ok(Self { tab, listener, frames, caption, options, started })
+    }
+
+    /// Set the caption drawn onto frames captured from now on (ignored if captions are disabled)
+    pub fn set_caption(&self, text: impl Into<String>) {
+        if self.options.captions
+            && let Ok(mut caption) = self.caption.lock()
+        {
+            *caption = Some(text.into());
+        }
+    }
+
+    /// Seconds since the recording started
+    pub fn elapsed(&self) -> f64 {
+        self.started.elapsed().as_secs_f64()
+    }
+
+    /// Number of frames captured so far
+    pub fn frame_count(&self) -> usize {
+        self.frames.lock().map(|f| f.len()).unwrap_or(0)
+    }
+
+    /// Stop the screencast and return the captured frames
+    pub fn stop(self) -> Result<Vec<Frame>> {
+        let stopped = self.tab.stop_screencast();
+        // Dropping the listener also drops the acknowledgement sender, ending that thread
+        let _ = self.tab.remove_event_listener(&self.listener);
+        stopped.map_err(|e| BrowserError::ChromeError(format!("Failed to stop screencast: {}", e)))?;
+
+        let frames = self.frames.lock().map(|mut f| std::mem::take(&mut *f)).unwrap_or_default();
+        Ok(frames)
+    }
+
+    /// Create the output location for [`Recording::save`]: the GIF file and its parent directory,
+    /// or the PNG directory.
+    ///
+    /// Call this before [`Recording::stop`] so a bad path is reported while the frames are still
+    /// being recorded rather than after they have been taken out of the session.
+    pub fn prepare_output(format: RecordingFormat, path: &Path) -> Result<()> {
+        match format {
+            RecordingFormat::Gif => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent)?;
+                }
+                File::create(path)?;
+            }
+            RecordingFormat::Png => std::fs::create_dir_all(path)?,
+        }
+        Ok(())
+    }
+
+    /// Encode frames as an animated GIF (at `path`) or numbered PNGs (in the `path` directory).
+    ///
+    /// Frames are decoded and written one at a time, so long recordings are never held in memory
+    /// as full images. Returns the files written.
+    pub fn save(frames: &[Frame], format: RecordingFormat, path: &Path) -> Result<Vec<PathBuf>> {
+        let font = label_font();
+        let render = |frame: &Frame| -> Result<RgbaImage> {
+            let mut image = image::load_from_memory(&frame.data)
+                .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to decode frame: {}", e)))?
+                .to_rgba8();
+            if let Some(text) = &frame.caption {
+                draw_caption(&mut image, text, &font);
+            }
+            Ok(image)
+        };
+
+        match format {
+            RecordingFormat::Gif => {
+                if frames.is_empty() {
+                    return Err(BrowserError::ScreenshotFailed("No frames were recorded".to_string()));
+                }
+
+                let file = BufWriter::new(File::create(path)?);
+                let mut encoder = GifEncoder::new_with_speed(file, 10);
+                encoder
+                    .set_repeat(Repeat::Infinite)
+                    .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to encode GIF: {}", e)))?;
+
+                let mut size = None;
+                for (i, frame) in frames.iter().enumerate() {
+                    let image = render(frame)?;
+                    let (width, height) = *size.get_or_insert(image.dimensions());
+
+                    // Each frame is shown until the next one was captured
+                    let delay_ms = frames
+                        .get(i + 1)
+                        .map(|next| ((next.timestamp - frame.timestamp) * 1000.0).round() as u32)
+                        .unwrap_or(1000)
+                        .max(MIN_GIF_DELAY_MS);
+
+                    // The viewport may have been resized mid-recording; GIF frames share one size
+                    let image = if image.dimensions() == (width, height) {
+                        image
+                    } else {
+                        image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle)
+                    };
+
+                    encoder
+                        .encode_frame(image::Frame::from_parts(image, 0, 0, Delay::from_numer_denom_ms(delay_ms, 1)))
+                        .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to encode GIF: {}", e)))?;
+                }
+
+                Ok(vec![path.to_path_buf()])
+            }
+            RecordingFormat::Png => {
+                std::fs::create_dir_all(path)?;
+                frames
+                    .iter()
+                    .enumerate()
+                    .map(|(i, frame)| {
+                        let file = path.join(format!("frame_{:05}.png", i + 1));
+                        render(frame)?
+                            .save(&file)
+                            .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to save frame: {}", e)))?;
+                        Ok(file)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Draw a caption bar along the bottom of the frame
+fn draw_caption(image: &mut RgbaImage, text: &str, font: &Font) {
+    let (width, height) = image.dimensions();
+    if height <= CAPTION_HEIGHT {
+        return;
+    }
+    let top = (height - CAPTION_HEIGHT) as i32;
+    draw_filled_rect_mut(image, Rect::at(0, top).of_size(width, CAPTION_HEIGHT), Rgba([0, 0, 0, 200]));
+    draw_text_mut(image, Rgba([255, 255, 255, 255]), 6, top + 4, Scale::uniform(16.0), font, text);
+}
+
+/// Parameters whose values may be secrets (typed text, form and storage values, cookies)
+const REDACTED_PARAMS: &[&str] = &["text", "value", "values", "cookies", "password"];
+
+/// Short caption for a tool call, e.g. `click {"index":5}`
+///
+/// Values of [`REDACTED_PARAMS`] are masked, since captions end up in the saved recording.
+pub fn action_caption(tool: &str, params: &serde_json::Value) -> String {
+    let mut caption = tool.trim_start_matches("browser_").to_string();
+    let mut params = params.clone();
+    if let Some(object) = params.as_object_mut() {
+        for (key, value) in object.iter_mut() {
+            if REDACTED_PARAMS.contains(&key.as_str()) {
+                *value = serde_json::Value::from("•••");
+            }
+        }
+    }
+    let params = params.to_string();
+    if params != "{}" && params != "null" {
+        caption.push(' ');
+        caption.push_str(&params);
+    }
+
+    const MAX_CHARS: usize = 100;
+    if caption.chars().count() > MAX_CHARS {
+        caption = caption.chars().take(MAX_CHARS - 1).collect::<String>() + "…";
+    }
+    caption
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jpeg(color: [u8; 3]) -> Vec<u8> {
+        let image = image::RgbImage::from_pixel(40, 30, image::Rgb(color));
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageRgb8(image)
+            .write_to(&mut std::io::Cursor::new(&mut bytes), image::ImageOutputFormat::Jpeg(90))
+            .unwrap();
+        bytes
+    }
+
+    fn frames() -> Vec<Frame> {
+        vec![
+            Frame { data: jpeg([255, 0, 0]), timestamp: 0.0, caption: None },
+            Frame { data: jpeg([0, 0, 255]), timestamp: 0.5, caption: Some("click".to_string()) },
+        ]
+    }
+
+    #[test]
+    fn test_action_caption() {
+        assert_eq!(action_caption("browser_click", &serde_json::json!({ "index": 5 })), r#"click {"index":5}"#);
+        assert_eq!(action_caption("go_back", &serde_json::json!({})), "go_back");
+
+        assert_eq!(
+            action_caption("input", &serde_json::json!({ "index": 2, "text": "hunter2" })),
+            r#"input {"index":2,"text":"•••"}"#
+        );
+        let form = action_caption("fill_form", &serde_json::json!({ "values": { "password": "hunter2" } }));
+        assert!(!form.contains("hunter2"));
+
+        let long = action_caption("navigate", &serde_json::json!({ "url": "x".repeat(200) }));
+        assert_eq!(long.chars().count(), 100);
+        assert!(long.ends_with('…'));
+    }
+
+    #[test]
+    fn test_save_gif() {
+        let path = std::env::temp_dir().join(format!("browser_use_recording_{}.gif", std::process::id()));
+        let written = Recording::save(&frames(), RecordingFormat::Gif, &path).unwrap();
+        assert_eq!(written, vec![path.clone()]);
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        let _ = std::fs::remove_file(&path);
+
+        assert!(Recording::save(&[], RecordingFormat::Gif, &path).is_err());
+    }
+
+    #[test]
+    fn test_save_png_sequence() {
+        let dir = std::env::temp_dir().join(format!("browser_use_recording_{}", std::process::id()));
+        let written = Recording::save(&frames(), RecordingFormat::Png, &dir).unwrap();
+        assert_eq!(written.len(), 2);
+        assert!(written[1].ends_with("frame_00002.png"));
+        assert_eq!(image::open(&written[0]).unwrap().width(), 40);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_prepare_output() {
+        let dir = std::env::temp_dir().join(format!("browser_use_recording_out_{}", std::process::id()));
+        let path = dir.join("nested").join("run.gif");
+        Recording::prepare_output(RecordingFormat::Gif, &path).unwrap();
+        assert!(Recording::save(&frames(), RecordingFormat::Gif, &path).is_ok());
+
+        // A GIF path that is an existing directory cannot be written
+        assert!(Recording::prepare_output(RecordingFormat::Gif, &dir).is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{browser::{config::{ConnectionOptions, LaunchOptions}, debug::{ConsoleLog, NetworkError}},
+use crate::{browser::{config::{ConnectionOptions, LaunchOptions},
+                      debug::{ConsoleLog, NetworkError},
+                      emulation::Emulation,
+                      human::{HumanEmulation, Humanizer},
+                      mouse::{Mouse, MouseButton},
+                      recording::{Frame, Recording, RecordingOptions, action_caption}},
             dom::DomTree,
             error::{BrowserError, Result},
             tools::{ToolContext, ToolRegistry, cookies::CookieParam}};
 use headless_chrome::{Browser, Tab, protocol::cdp::{Network::CookieParam as CdpCookieParam, types::Event}};
-use std::{ffi::OsStr, sync::{Arc, Mutex}, time::Duration};
+use std::{ffi::OsStr, sync::{Arc, Mutex, MutexGuard}, time::Duration};
 
 /// Wrapper for Tab and Element to maintain proper lifetime relationships
 pub struct TabElement<'a> {
@@ -24,6 +29,18 @@ pub struct BrowserSession {
 
     /// Captured network errors
     network_errors: Arc<Mutex<Vec<NetworkError>>>,
+
+    /// Screencast recording in progress, if any
+    recording: Mutex<Option<Recording>>,
+
+    /// Pointer position and held buttons for raw mouse input
+    mouse: Mutex<Mouse>,
+
+    /// Random source for human-like input, when human emulation is on
+    human: Mutex<Option<Humanizer>>,
+
+    /// Emulation overrides applied to every tab of the session
+    emulation: Mutex<Emulation>,
 }
 
 impl BrowserSession {
@@ -131,15 +148,22 @@ impl BrowserSession {
             tabs = browser.get_tabs().lock().map_err(|e| BrowserError::TabOperationFailed(e.to_string()))?.clone();
         }
         
-        for tab in tabs {
-            Self::setup_tab_listeners(&tab, console_logs.clone(), network_errors.clone())?;
+        for tab in &tabs {
+            Self::setup_tab_listeners(tab, console_logs.clone(), network_errors.clone())?;
+            if !options.emulation.is_empty() {
+                options.emulation.apply(tab)?;
+            }
         }
 
         Ok(Self { 
             browser, 
             tool_registry: ToolRegistry::with_defaults(),
             console_logs,
-            network_errors
+            network_errors,
+            recording: Mutex::new(None),
+            mouse: Mutex::new(Mouse::default()),
+            human: Mutex::new(options.human_emulation.map(Humanizer::new)),
+            emulation: Mutex::new(options.emulation),
         })
     }
 
@@ -159,7 +183,11 @@ impl BrowserSession {
             browser, 
             tool_registry: ToolRegistry::with_defaults(),
             console_logs,
-            network_errors
+            network_errors,
+            recording: Mutex::new(None),
+            mouse: Mutex::new(Mouse::default()),
+            human: Mutex::new(None),
+            emulation: Mutex::new(Emulation::default()),
         })
     }
 
@@ -174,13 +202,17 @@ impl BrowserSession {
     }
 
     /// Create a new tab and set it as active
-    pub fn new_tab(&mut self) -> Result<Arc<Tab>> {
+    pub fn new_tab(&self) -> Result<Arc<Tab>> {
         let tab = self
             .browser
             .new_tab()
             .map_err(|e| BrowserError::TabOperationFailed(format!("Failed to create tab: {}", e)))?;
             
         Self::setup_tab_listeners(&tab, self.console_logs.clone(), self.network_errors.clone())?;
+        let emulation = self.emulation()?;
+        if !emulation.is_empty() {
+            emulation.apply(&tab)?;
+        }
             
         Ok(tab)
     }
@@ -277,6 +309,11 @@ impl BrowserSession {
         DomTree::from_tab(&self.tab()?)
     }
 
+    /// Extract the DOM tree from the active tab, including computed styles for indexed elements
+    pub fn extract_dom_with_styles(&self) -> Result<DomTree> {
+        DomTree::from_tab_with_styles(&self.tab()?)
+    }
+
     /// Extract the DOM tree with a custom ref prefix (for iframe handling)
     pub fn extract_dom_with_prefix(&self, prefix: &str) -> Result<DomTree> {
         DomTree::from_tab_with_prefix(&self.tab()?, prefix)
@@ -300,6 +337,7 @@ impl BrowserSession {
 
     /// Execute a tool by name
     pub fn execute_tool(&self, name: &str, params: serde_json::Value) -> Result<crate::tools::ToolResult> {
+        self.note_action(name, &params);
         let mut context = ToolContext::new(self);
         self.tool_registry.execute(name, params, &mut context)
     }
@@ -397,6 +435,144 @@ impl BrowserSession {
         Ok(errors.clone())
     }
 
+    /// Start a screencast recording of `tab`
+    pub fn start_recording(&self, tab: Arc<Tab>, options: RecordingOptions) -> Result<()> {
+        let mut recording = self.recording.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        if recording.is_some() {
+            return Err(BrowserError::InvalidArgument("A recording is already in progress".to_string()));
+        }
+        *recording = Some(Recording::start(tab, options)?);
+        Ok(())
+    }
+
+    /// Stop the current recording and return its frames
+    pub fn stop_recording(&self) -> Result<Vec<Frame>> {
+        let recording = self.recording.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?.take();
+        match recording {
+            Some(recording) => recording.stop(),
+            None => Err(BrowserError::InvalidArgument("No recording in progress".to_string())),
+        }
+    }
+
+    /// Note a tool call as the caption of subsequent recording frames (no-op when not recording)
+    pub fn note_action(&self, tool: &str, params: &serde_json::Value) {
+        if let Ok(recording) = self.recording.lock()
+            && let Some(recording) = recording.as_ref()
+        {
+            recording.set_caption(action_caption(tool, params));
+        }
+    }
+
+    /// Lock the mouse state used by the raw mouse tools
+    pub fn mouse(&self) -> Result<MutexGuard<'_, Mouse>> {
+        self.mouse.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))
+    }
+
+    /// Turn human emulation on with the given profile, or off with None
+    pub fn set_human_emulation(&self, profile: Option<HumanEmulation>) -> Result<()> {
+        *self.human.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))? = profile.map(Humanizer::new);
+        Ok(())
+    }
+
+    /// Current emulation overrides
+    pub fn emulation(&self) -> Result<Emulation> {
+        Ok(self.emulation.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?.clone())
+    }
+
+    /// Add emulation overrides to the session and apply the combined overrides to every tab.
+    ///
+    /// The session keeps its previous overrides unless every tab accepts the new ones; on failure
+    /// the tabs are put back to the previous overrides as far as possible.
+    pub fn emulate(&self, emulation: Emulation) -> Result<Emulation> {
+        let mut current = self.emulation.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        let mut merged = current.clone();
+        merged.merge(emulation);
+
+        let tabs = self.get_tabs()?;
+        if let Err(e) = tabs.iter().try_for_each(|tab| merged.apply(tab)) {
+            for tab in &tabs {
+                let _ = Emulation::clear(tab).and_then(|_| current.apply(tab));
+            }
+            return Err(e);
+        }
+
+        *current = merged.clone();
+        Ok(merged)
+    }
+
+    /// Remove all emulation overrides from every tab
+    pub fn clear_emulation(&self) -> Result<()> {
+        let mut current = self.emulation.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        *current = Emulation::default();
+        for tab in self.get_tabs()? {
+            Emulation::clear(&tab)?;
+        }
+        Ok(())
+    }
+
+    /// Whether human emulation is on
+    pub fn is_human(&self) -> bool {
+        self.human.lock().map(|human| human.is_some()).unwrap_or(false)
+    }
+
+    /// Run `f` with the human emulation random source, or return None when emulation is off
+    pub fn with_human<R>(&self, f: impl FnOnce(&mut Humanizer) -> R) -> Result<Option<R>> {
+        let mut human = self.human.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        Ok(human.as_mut().map(f))
+    }
+
+    /// Move the mouse pointer to a viewport point, along a curved path when human emulation is on
+    pub fn pointer_move(&self, tab: &Tab, x: f64, y: f64, steps: u32) -> Result<()> {
+        let mut mouse = self.mouse()?;
+        let mut human = self.human.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        match human.as_mut() {
+            Some(human) => mouse.move_human(tab, x, y, human),
+            None => mouse.move_to(tab, x, y, steps),
+        }
+    }
+
+    /// Click at a viewport point. With human emulation the pointer travels there, hovers for a
+    /// moment and holds the button briefly.
+    pub fn pointer_click(&self, tab: &Tab, x: f64, y: f64, button: MouseButton, click_count: u32) -> Result<()> {
+        let mut mouse = self.mouse()?;
+        let mut human = self.human.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        let Some(human) = human.as_mut() else {
+            return mouse.click(tab, x, y, button, click_count);
+        };
+
+        mouse.move_human(tab, x, y, human)?;
+        std::thread::sleep(human.hover_delay());
+        for count in 1..=click_count.max(1) {
+            mouse.down(tab, button, count)?;
+            std::thread::sleep(human.press_delay());
+            mouse.up(tab, button, count)?;
+        }
+        Ok(())
+    }
+
+    /// Type text into the focused element. With human emulation keys are typed one at a time
+    /// with random delays, occasionally hitting a neighboring key and correcting it.
+    pub fn type_text(&self, tab: &Tab, text: &str) -> Result<()> {
+        let failed = |e: anyhow::Error| BrowserError::ChromeError(format!("Failed to type text: {}", e));
+        let mut human = self.human.lock().map_err(|e| BrowserError::ChromeError(e.to_string()))?;
+        let Some(human) = human.as_mut() else {
+            tab.type_str(text).map_err(failed)?;
+            return Ok(());
+        };
+
+        for ch in text.chars() {
+            if let Some(typo) = human.typo(ch) {
+                tab.type_str(&typo.to_string()).map_err(failed)?;
+                std::thread::sleep(human.key_delay());
+                tab.press_key("Backspace").map_err(failed)?;
+                std::thread::sleep(human.key_delay());
+            }
+            tab.type_str(&ch.to_string()).map_err(failed)?;
+            std::thread::sleep(human.key_delay());
+        }
+        Ok(())
+    }
+
     /// Close the browser
     pub fn close(&self) -> Result<()> {
         // Note: The Browser struct doesn't have a public close method in headless_chrome
@@ -466,8 +642,7 @@ mod tests {
     #[test]
     #[ignore]
     fn test_new_tab() {
-        let mut session =
-            BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+        let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
 
         let result = session.new_tab();
         assert!(result.is_ok());
@@ -0,0 +1,142 @@
+//! Touch input
+//!
+//! Gestures are built from `Input.dispatchTouchEvent` calls, so pages receive real `touchstart`,
+//! `touchmove` and `touchend` events and the browser derives taps, scrolls and flings from them
+//! as it would on a phone.
+
+use crate::error::{BrowserError, Result};
+use headless_chrome::{Tab, protocol::cdp::Input};
+use std::time::Duration;
+
+/// Interval between touch move events, about one per frame
+const FRAME: Duration = Duration::from_millis(16);
+
+fn dispatch(tab: &Tab, event_type: Input::DispatchTouchEventTypeOption, points: &[(f64, f64)]) -> Result<()> {
+    let touch_points = points
+        .iter()
+        .enumerate()
+        .map(|(id, &(x, y))| Input::TouchPoint {
+            x,
+            y,
+            radius_x: None,
+            radius_y: None,
+            rotation_angle: None,
+            force: Some(1.0),
+            tangential_pressure: None,
+            tilt_x: None,
+            tilt_y: None,
+            twist: None,
+            id: Some(id as f64),
+        })
+        .collect();
+    tab.call_method(Input::DispatchTouchEvent { Type: event_type, touch_points, modifiers: None, timestamp: None })
+        .map_err(|e| BrowserError::ChromeError(format!("Failed to dispatch touch event: {}", e)))?;
+    Ok(())
+}
+
+/// Put fingers down at `points`, hold them for `hold`, and lift them
+pub fn press(tab: &Tab, points: &[(f64, f64)], hold: Duration) -> Result<()> {
+    touch_down(tab, points)?;
+    std::thread::sleep(hold);
+    lift(tab)
+}
+
+/// Tap at (x, y) `count` times (2 for a double tap)
+pub fn tap(tab: &Tab, x: f64, y: f64, count: u32) -> Result<()> {
+    for i in 0..count.max(1) {
+        if i > 0 {
+            std::thread::sleep(Duration::from_millis(80));
+        }
+        press(tab, &[(x, y)], Duration::from_millis(40))?;
+    }
+    Ok(())
+}
+
+/// Move fingers from their `from` to their `to` positions over `duration`, then lift them.
+///
+/// One finger makes a swipe; two fingers moving apart or together make a pinch.
+pub fn gesture(tab: &Tab, from: &[(f64, f64)], to: &[(f64, f64)], duration: Duration) -> Result<()> {
+    check_points(from, to)?;
+    touch_down(tab, from)?;
+    glide(tab, from, to, duration)
+}
+
+/// Put fingers down at `points` without lifting them; finish the gesture with [`glide`]
+pub fn touch_down(tab: &Tab, points: &[(f64, f64)]) -> Result<()> {
+    dispatch(tab, Input::DispatchTouchEventTypeOption::TouchStart, points)
+}
+
+/// Move fingers that are down at `from` to `to` over `duration`, then lift them
+pub fn glide(tab: &Tab, from: &[(f64, f64)], to: &[(f64, f64)], duration: Duration) -> Result<()> {
+    if let Err(e) = check_points(from, to) {
+        lift(tab)?;
+        return Err(e);
+    }
+
+    let steps = glide_steps(duration);
+    let pause = duration / steps;
+    for step in 1..=steps {
+        std::thread::sleep(pause);
+        let t = step as f64 / steps as f64;
+        let points: Vec<(f64, f64)> =
+            from.iter().zip(to).map(|(a, b)| (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)).collect();
+        dispatch(tab, Input::DispatchTouchEventTypeOption::TouchMove, &points)?;
+    }
+    lift(tab)
+}
+
+/// Number of touch move events for a glide lasting `duration`: one per frame, at least five
+fn glide_steps(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis() / FRAME.as_millis()).unwrap_or(u32::MAX).max(5)
+}
+
+/// Lift all fingers
+pub fn lift(tab: &Tab) -> Result<()> {
+    dispatch(tab, Input::DispatchTouchEventTypeOption::TouchEnd, &[])
+}
+
+fn check_points(from: &[(f64, f64)], to: &[(f64, f64)]) -> Result<()> {
+    if from.len() != to.len() || from.is_empty() {
+        return Err(BrowserError::InvalidArgument("A gesture needs the same number of start and end points".into()));
+    }
+    Ok(())
+}
+
+/// Pinch around `center` to zoom by `scale`, moving two fingers horizontally over `duration`
+pub fn pinch(tab: &Tab, center: (f64, f64), scale: f64, duration: Duration) -> Result<()> {
+    let (start, end) = pinch_spread(scale);
+    gesture(tab, &fingers(center, start), &fingers(center, end), duration)
+}
+
+/// Half the distance between the fingers at the start and end of a pinch by `scale`
+fn pinch_spread(scale: f64) -> (f64, f64) {
+    // Start close together to zoom in and far apart to zoom out, so both stay on screen
+    let start = if scale >= 1.0 { 50.0 } else { 150.0 };
+    (start, start * scale)
+}
+
+/// Two fingers `half` pixels to the left and right of `center`
+fn fingers(center: (f64, f64), half: f64) -> [(f64, f64); 2] {
+    [(center.0 - half, center.1), (center.0 + half, center.1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinch_fingers() {
+        let (start, end) = pinch_spread(2.0);
+        assert_eq!(fingers((200.0, 300.0), start), [(150.0, 300.0), (250.0, 300.0)]);
+        assert_eq!(fingers((200.0, 300.0), end), [(100.0, 300.0), (300.0, 300.0)]);
+
+        assert_eq!(pinch_spread(0.5), (150.0, 75.0));
+    }
+
+    #[test]
+    fn test_glide_steps() {
+        assert_eq!(glide_steps(Duration::from_millis(320)), 20);
+        assert_eq!(glide_steps(Duration::ZERO), 5);
+        assert_eq!(glide_steps(Duration::from_millis(16 << 32)), u32::MAX);
+    }
+}
@@ -0,0 +1,645 @@
+//! Same-site crawler
+//!
+//! Crawls outward from a set of start URLs using one or more tabs of a [`BrowserSession`],
+//! collecting each page's title, markdown and links. Progress can be persisted to a state
+//! file so an interrupted crawl picks up where it stopped.
+
+pub mod robots;
+
+pub use robots::Robots;
+
+use crate::{browser::BrowserSession,
+            error::{BrowserError, Result},
+            tools::{GetMarkdownParams, ReadLinksParams, Tool, ToolContext, markdown::GetMarkdownTool,
+                    read_links::{Link, ReadLinksTool}}};
+use headless_chrome::Tab;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{collections::{HashMap, HashSet, VecDeque},
+          path::{Path, PathBuf},
+          sync::{Arc, Mutex, mpsc},
+          thread,
+          time::{Duration, Instant}};
+use url::Url;
+
+/// User agent token matched against robots.txt groups
+const ROBOTS_USER_AGENT: &str = "fast-browser-use";
+
+/// Options for a crawl
+#[derive(Debug, Clone)]
+pub struct CrawlOptions {
+    /// URLs the crawl starts from (depth 0)
+    pub start_urls: Vec<String>,
+
+    /// Maximum link depth from the start URLs
+    pub max_depth: usize,
+
+    /// Maximum number of pages to crawl
+    pub max_pages: usize,
+
+    /// Regex patterns; when non-empty a URL must match at least one to be crawled
+    pub include: Vec<String>,
+
+    /// Regex patterns; URLs matching any of them are skipped
+    pub exclude: Vec<String>,
+
+    /// Only follow links to the origins of the start URLs
+    pub same_origin: bool,
+
+    /// Fetch and obey robots.txt for each origin
+    pub respect_robots: bool,
+
+    /// Minimum delay between two requests to the same host (raised by robots.txt Crawl-delay)
+    pub delay: Duration,
+
+    /// Number of tabs crawling in parallel
+    pub concurrency: usize,
+
+    /// File the crawl state is saved to after every page, and resumed from if it exists
+    pub state_file: Option<PathBuf>,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            start_urls: Vec::new(),
+            max_depth: 2,
+            max_pages: 100,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            same_origin: true,
+            respect_robots: true,
+            delay: Duration::from_millis(1000),
+            concurrency: 1,
+            state_file: None,
+        }
+    }
+}
+
+impl CrawlOptions {
+    /// Create CrawlOptions for the given start URLs with default values
+    pub fn new<S: Into<String>>(start_urls: impl IntoIterator<Item = S>) -> Self {
+        Self { start_urls: start_urls.into_iter().map(Into::into).collect(), ..Self::default() }
+    }
+
+    /// Builder method: set maximum link depth
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Builder method: set maximum number of pages
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = pages;
+        self
+    }
+
+    /// Builder method: add an include pattern
+    pub fn include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Builder method: add an exclude pattern
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    /// Builder method: restrict the crawl to the start URLs' origins
+    pub fn same_origin(mut self, same_origin: bool) -> Self {
+        self.same_origin = same_origin;
+        self
+    }
+
+    /// Builder method: enable/disable robots.txt handling
+    pub fn respect_robots(mut self, respect: bool) -> Self {
+        self.respect_robots = respect;
+        self
+    }
+
+    /// Builder method: set per-host politeness delay
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Builder method: set number of concurrent tabs
+    pub fn concurrency(mut self, tabs: usize) -> Self {
+        self.concurrency = tabs;
+        self
+    }
+
+    /// Builder method: set the resumable state file
+    pub fn state_file(mut self, path: PathBuf) -> Self {
+        self.state_file = Some(path);
+        self
+    }
+}
+
+/// A crawled page, emitted as one JSONL record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawledPage {
+    pub url: String,
+    pub depth: usize,
+    /// HTTP status of the main document (None if unknown)
+    pub status: Option<u16>,
+    pub title: String,
+    pub markdown: String,
+    /// Absolute URLs of the links on the page, without fragments
+    pub links: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Crawl progress, persisted to the state file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrawlState {
+    /// URLs waiting to be crawled, with their depth
+    pub queue: VecDeque<(String, usize)>,
+    /// URLs already queued or crawled
+    pub seen: HashSet<String>,
+    /// Number of pages crawled so far
+    pub crawled: usize,
+}
+
+impl CrawlState {
+    /// Load state from a file
+    pub fn load(path: &Path) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Save state to a file (written to a temporary file first so a crash never leaves it truncated)
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_string(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    fn enqueue(&mut self, url: String, depth: usize) {
+        if self.seen.insert(url.clone()) {
+            self.queue.push_back((url, depth));
+        }
+    }
+}
+
+/// Totals for a finished crawl
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrawlSummary {
+    /// Pages crawled by this run
+    pub pages: usize,
+    /// Pages crawled in total, including earlier runs resumed from the state file
+    pub total_pages: usize,
+    /// URLs still queued when the crawl stopped
+    pub remaining: usize,
+}
+
+/// Remove the fragment and reject non-HTTP(S) URLs
+pub fn normalize_url(url: &str) -> Option<String> {
+    let mut parsed = Url::parse(url).ok()?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return None;
+    }
+    parsed.set_fragment(None);
+    Some(parsed.to_string())
+}
+
+/// Resolve link hrefs against the page URL, dropping duplicates and non-HTTP(S) links
+pub fn resolve_links(base: &str, links: &[Link]) -> Vec<String> {
+    let Ok(base) = Url::parse(base) else {
+        return Vec::new();
+    };
+
+    let mut seen = HashSet::new();
+    links
+        .iter()
+        .filter_map(|link| base.join(link.href.trim()).ok())
+        .filter_map(|url| normalize_url(url.as_str()))
+        .filter(|url| seen.insert(url.clone()))
+        .collect()
+}
+
+/// Origin and include/exclude filter applied to discovered links
+#[derive(Debug, Clone)]
+pub struct UrlFilter {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+    /// Allowed origins, or None to follow links anywhere
+    origins: Option<HashSet<String>>,
+}
+
+impl UrlFilter {
+    /// Build the filter from crawl options, validating start URLs and patterns
+    pub fn new(options: &CrawlOptions) -> Result<Self> {
+        let compile = |patterns: &[String]| -> Result<Vec<Regex>> {
+            patterns
+                .iter()
+                .map(|p| {
+                    Regex::new(p).map_err(|e| BrowserError::InvalidArgument(format!("Invalid pattern '{}': {}", p, e)))
+                })
+                .collect()
+        };
+
+        let mut origins = HashSet::new();
+        for url in &options.start_urls {
+            let parsed = Url::parse(url)
+                .map_err(|e| BrowserError::InvalidArgument(format!("Invalid start URL '{}': {}", url, e)))?;
+            origins.insert(parsed.origin().ascii_serialization());
+        }
+
+        Ok(Self {
+            include: compile(&options.include)?,
+            exclude: compile(&options.exclude)?,
+            origins: options.same_origin.then_some(origins),
+        })
+    }
+
+    /// Whether a URL should be crawled
+    pub fn allows(&self, url: &str) -> bool {
+        let Ok(parsed) = Url::parse(url) else {
+            return false;
+        };
+        if let Some(origins) = &self.origins
+            && !origins.contains(&parsed.origin().ascii_serialization())
+        {
+            return false;
+        }
+        if !self.include.is_empty() && !self.include.iter().any(|re| re.is_match(url)) {
+            return false;
+        }
+        !self.exclude.iter().any(|re| re.is_match(url))
+    }
+}
+
+/// State shared between crawl workers
+struct Shared {
+    state: CrawlState,
+    /// URLs taken from the queue whose pages have not been handed to the caller yet
+    in_flight: Vec<(String, usize)>,
+    /// Set when the caller's callback fails: no new URLs are handed out
+    stopped: bool,
+    /// Earliest time the next request to each host may start
+    next_request: HashMap<String, Instant>,
+    robots: HashMap<String, Arc<Robots>>,
+}
+
+impl Shared {
+    fn new(state: CrawlState) -> Self {
+        Self { state, in_flight: Vec::new(), stopped: false, next_request: HashMap::new(), robots: HashMap::new() }
+    }
+
+    /// Drop a URL from the in-flight list
+    fn finish(&mut self, url: &str) {
+        self.in_flight.retain(|(in_flight, _)| in_flight != url);
+    }
+
+    /// The state to persist: in-flight URLs go back to the front of the queue, so an
+    /// interrupted crawl crawls them again on resume
+    fn resumable(&self) -> CrawlState {
+        let mut state = self.state.clone();
+        for entry in self.in_flight.iter().rev() {
+            state.queue.push_front(entry.clone());
+        }
+        state
+    }
+}
+
+/// Crawler driving the tabs of a [`BrowserSession`]
+pub struct Crawler<'a> {
+    session: &'a BrowserSession,
+    options: CrawlOptions,
+    filter: UrlFilter,
+}
+
+impl<'a> Crawler<'a> {
+    /// Create a crawler, validating the start URLs and filter patterns
+    pub fn new(session: &'a BrowserSession, options: CrawlOptions) -> Result<Self> {
+        let filter = UrlFilter::new(&options)?;
+        Ok(Self { session, options, filter })
+    }
+
+    /// Run the crawl, calling `on_page` for every page as it completes
+    pub fn run<F>(&self, mut on_page: F) -> Result<CrawlSummary>
+    where
+        F: FnMut(&CrawledPage) -> Result<()>,
+    {
+        let state = match &self.options.state_file {
+            Some(path) if path.exists() => {
+                log::info!("Resuming crawl from {:?}", path);
+                CrawlState::load(path)?
+            }
+            _ => {
+                let mut state = CrawlState::default();
+                for url in &self.options.start_urls {
+                    if let Some(url) = normalize_url(url) {
+                        state.enqueue(url, 0);
+                    }
+                }
+                state
+            }
+        };
+        let shared = Mutex::new(Shared::new(state));
+        let (sender, receiver) = mpsc::channel::<CrawledPage>();
+
+        // Session tabs get its console and network listeners and its device and throttling emulation
+        let tabs = (0..self.options.concurrency.max(1)).map(|_| self.session.new_tab()).collect::<Result<Vec<_>>>()?;
+
+        let mut callback_result = Ok(());
+        let mut pages = 0;
+
+        thread::scope(|scope| {
+            for tab in &tabs {
+                let sender = sender.clone();
+                let shared = &shared;
+                scope.spawn(move || self.worker(tab.clone(), shared, sender));
+            }
+            drop(sender);
+
+            // Pages arrive here once their links are queued; a page only counts as crawled, and
+            // progress is only saved, once it has been handed out
+            for page in receiver {
+                if callback_result.is_err() {
+                    continue;
+                }
+                callback_result = on_page(&page);
+                let mut guard = shared.lock().unwrap();
+                if callback_result.is_err() {
+                    // Stop handing out new URLs; pages still in flight stay queued in the saved state
+                    guard.stopped = true;
+                    continue;
+                }
+
+                pages += 1;
+                guard.finish(&page.url);
+                guard.state.crawled += 1;
+                if let Some(path) = &self.options.state_file
+                    && let Err(e) = guard.resumable().save(path)
+                {
+                    log::warn!("Failed to save crawl state: {}", e);
+                }
+            }
+        });
+
+        for tab in tabs {
+            let _ = tab.close(true);
+        }
+        callback_result?;
+
+        let shared = shared.into_inner().unwrap();
+        Ok(CrawlSummary { pages, total_pages: shared.state.crawled, remaining: shared.resumable().queue.len() })
+    }
+
+    fn worker(&self, tab: Arc<Tab>, shared: &Mutex<Shared>, sender: mpsc::Sender<CrawledPage>) {
+        while let Some((url, depth)) = self.next_url(&tab, shared) {
+            let page = self.crawl_page(&tab, &url, depth);
+
+            let mut guard = shared.lock().unwrap();
+            if depth < self.options.max_depth {
+                for link in &page.links {
+                    if self.filter.allows(link) {
+                        guard.state.enqueue(link.clone(), depth + 1);
+                    }
+                }
+            }
+            drop(guard);
+
+            if sender.send(page).is_err() {
+                break;
+            }
+        }
+    }
+
+    /// Take the next URL whose host is ready, waiting for politeness delays; None when the crawl is done
+    fn next_url(&self, tab: &Arc<Tab>, shared: &Mutex<Shared>) -> Option<(String, usize)> {
+        loop {
+            let mut guard = shared.lock().unwrap();
+            if guard.stopped || guard.state.crawled + guard.in_flight.len() >= self.options.max_pages {
+                return None;
+            }
+            if guard.state.queue.is_empty() {
+                if guard.in_flight.is_empty() {
+                    return None;
+                }
+                // Another worker may still find links
+                drop(guard);
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            }
+
+            let now = Instant::now();
+            let ready = guard.state.queue.iter().position(|(url, _)| {
+                host_of(url).and_then(|host| guard.next_request.get(&host)).is_none_or(|at| *at <= now)
+            });
+            let Some(position) = ready else {
+                drop(guard);
+                thread::sleep(Duration::from_millis(100));
+                continue;
+            };
+
+            let (url, depth) = guard.state.queue.remove(position).unwrap();
+            let origin = Url::parse(&url).map(|u| u.origin().ascii_serialization()).unwrap_or_default();
+            let cached_robots = guard.robots.get(&origin).cloned();
+            guard.in_flight.push((url.clone(), depth));
+            drop(guard);
+
+            let robots = match cached_robots {
+                Some(robots) => robots,
+                None if self.options.respect_robots => {
+                    let robots = Arc::new(self.fetch_robots(tab, &origin));
+                    shared.lock().unwrap().robots.insert(origin, robots.clone());
+                    robots
+                }
+                None => Arc::new(Robots::allow_all()),
+            };
+
+            let path = Url::parse(&url)
+                .map(|u| match u.query() {
+                    Some(query) => format!("{}?{}", u.path(), query),
+                    None => u.path().to_string(),
+                })
+                .unwrap_or_default();
+            if !robots.is_allowed(&path) {
+                log::info!("Skipping {} (disallowed by robots.txt)", url);
+                shared.lock().unwrap().finish(&url);
+                continue;
+            }
+
+            let delay = robots.crawl_delay.map_or(self.options.delay, |d| d.max(self.options.delay));
+            if let Some(host) = host_of(&url) {
+                shared.lock().unwrap().next_request.insert(host, Instant::now() + delay);
+            }
+            return Some((url, depth));
+        }
+    }
+
+    /// Load `<origin>/robots.txt` in the tab; anything but a 200 allows everything
+    fn fetch_robots(&self, tab: &Arc<Tab>, origin: &str) -> Robots {
+        let robots_url = format!("{}/robots.txt", origin);
+        let loaded = tab.navigate_to(&robots_url).and_then(|tab| tab.wait_until_navigated()).is_ok();
+        if !loaded || document_status(tab) != Some(200) {
+            return Robots::allow_all();
+        }
+
+        match tab.evaluate("document.body ? document.body.innerText : ''", false) {
+            Ok(result) => {
+                let content = result.value.and_then(|v| v.as_str().map(String::from)).unwrap_or_default();
+                Robots::parse(&content, ROBOTS_USER_AGENT)
+            }
+            Err(_) => Robots::allow_all(),
+        }
+    }
+
+    fn crawl_page(&self, tab: &Arc<Tab>, url: &str, depth: usize) -> CrawledPage {
+        log::info!("Crawling {} (depth {})", url, depth);
+        let mut page = CrawledPage {
+            url: url.to_string(),
+            depth,
+            status: None,
+            title: String::new(),
+            markdown: String::new(),
+            links: Vec::new(),
+            error: None,
+        };
+
+        if let Err(e) = tab.navigate_to(url).and_then(|tab| tab.wait_until_navigated()) {
+            page.error = Some(format!("Navigation failed: {}", e));
+            return page;
+        }
+
+        page.status = document_status(tab);
+        page.title = tab.get_title().unwrap_or_default();
+        // Redirects change the URL that links resolve against
+        let final_url = tab.get_url();
+
+        let mut context = ToolContext::with_tab(self.session, tab.clone());
+        match ReadLinksTool.execute_typed(ReadLinksParams {}, &mut context) {
+            Ok(result) => {
+                let links: Vec<Link> = result
+                    .data
+                    .and_then(|data| serde_json::from_value(data["links"].clone()).ok())
+                    .unwrap_or_default();
+                page.links = resolve_links(&final_url, &links);
+            }
+            Err(e) => page.error = Some(e.to_string()),
+        }
+
+        let markdown_params = GetMarkdownParams { single_page: true, ..Default::default() };
+        match GetMarkdownTool.execute_typed(markdown_params, &mut context) {
+            Ok(result) => {
+                page.markdown = result
+                    .data
+                    .and_then(|data| data["markdown"].as_str().map(String::from))
+                    .unwrap_or_default();
+            }
+            Err(e) => {
+                page.error.get_or_insert(e.to_string());
+            }
+        }
+
+        page
+    }
+}
+
+fn host_of(url: &str) -> Option<String> {
+    Url::parse(url).ok().and_then(|u| u.host_str().map(String::from))
+}
+
+/// HTTP status of the tab's main document, from the Navigation Timing API
+fn document_status(tab: &Arc<Tab>) -> Option<u16> {
+    tab.evaluate(
+        "(performance.getEntriesByType('navigation')[0] || {}).responseStatus || 0",
+        false,
+    )
+    .ok()
+    .and_then(|r| r.value)
+    .and_then(|v| v.as_u64())
+    .filter(|status| *status > 0)
+    .map(|status| status as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(href: &str) -> Link {
+        Link { text: String::new(), href: href.to_string() }
+    }
+
+    #[test]
+    fn test_resolve_links() {
+        let links = [
+            link("/docs/intro#setup"),
+            link("guide"),
+            link("https://other.example/x"),
+            link("mailto:team@example.com"),
+            link("javascript:void(0)"),
+            link("/docs/intro"),
+        ];
+
+        assert_eq!(
+            resolve_links("https://example.com/docs/", &links),
+            vec!["https://example.com/docs/intro", "https://example.com/docs/guide", "https://other.example/x"]
+        );
+    }
+
+    #[test]
+    fn test_state_enqueue_deduplicates() {
+        let mut state = CrawlState::default();
+        state.enqueue("https://example.com/".to_string(), 0);
+        state.enqueue("https://example.com/".to_string(), 1);
+        assert_eq!(state.queue.len(), 1);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: CrawlState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.queue[0], ("https://example.com/".to_string(), 0));
+        assert!(restored.seen.contains("https://example.com/"));
+    }
+
+    #[test]
+    fn test_resumable_state_requeues_in_flight() {
+        let mut state = CrawlState::default();
+        state.enqueue("https://example.com/c".to_string(), 2);
+        let mut shared = Shared::new(state);
+        shared.in_flight.push(("https://example.com/a".to_string(), 1));
+        shared.in_flight.push(("https://example.com/b".to_string(), 1));
+
+        shared.finish("https://example.com/b");
+        let queue: Vec<String> = shared.resumable().queue.into_iter().map(|(url, _)| url).collect();
+        assert_eq!(queue, vec!["https://example.com/a", "https://example.com/c"]);
+    }
+
+    #[test]
+    fn test_url_filter() {
+        let options = CrawlOptions::new(["https://example.com/blog/"]).include("/blog/").exclude(r"\.pdf$");
+        let filter = UrlFilter::new(&options).unwrap();
+
+        assert!(filter.allows("https://example.com/blog/post-1"));
+        assert!(!filter.allows("https://example.com/about"));
+        assert!(!filter.allows("https://example.com/blog/report.pdf"));
+        assert!(!filter.allows("https://other.example/blog/post-1"));
+
+        let filter = UrlFilter::new(&options.clone().same_origin(false)).unwrap();
+        assert!(filter.allows("https://other.example/blog/post-1"));
+
+        assert!(UrlFilter::new(&CrawlOptions::new(["https://example.com"]).include("(")).is_err());
+        assert!(UrlFilter::new(&CrawlOptions::new(["not a url"])).is_err());
+    }
+
+    #[test]
+    fn test_crawl_options_builder() {
+        let options = CrawlOptions::new(["https://example.com"])
+            .max_depth(1)
+            .include("/blog/")
+            .exclude(r"\.pdf$")
+            .concurrency(4);
+
+        assert_eq!(options.start_urls, vec!["https://example.com"]);
+        assert_eq!(options.max_depth, 1);
+        assert_eq!(options.max_pages, 100);
+        assert_eq!(options.include, vec!["/blog/"]);
+        assert_eq!(options.concurrency, 4);
+        assert!(options.same_origin);
+    }
+}
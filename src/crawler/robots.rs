@@ -0,0 +1,221 @@
+//! Minimal robots.txt parser
+//!
+//! Supports `User-agent` groups, `Allow`/`Disallow` rules with `*` wildcards and `$` anchors
+//! (longest match wins, `Allow` wins ties) and `Crawl-delay`.
+
+use std::time::Duration;
+
+/// Longest Crawl-delay obeyed; larger values would stall the crawl
+const MAX_CRAWL_DELAY: Duration = Duration::from_secs(60);
+
+/// A single Allow/Disallow rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+/// Rules from a robots.txt file that apply to one user agent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Robots {
+    rules: Vec<Rule>,
+
+    /// Crawl-delay requested for this user agent
+    pub crawl_delay: Option<Duration>,
+}
+
+impl Robots {
+    /// Rules that allow everything (used when robots.txt is missing or unreadable)
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Parse robots.txt content, keeping the group that best matches `user_agent`.
+    ///
+    /// A group naming the agent (case-insensitive substring match) takes precedence over `*`.
+    pub fn parse(content: &str, user_agent: &str) -> Self {
+        let agent = user_agent.to_lowercase();
+
+        let mut specific: Option<Robots> = None;
+        let mut wildcard: Option<Robots> = None;
+
+        // Agents of the group being read, and whether its rule lines have started
+        let mut group_agents: Vec<String> = Vec::new();
+        let mut in_rules = false;
+        let mut current = Robots::default();
+
+        let mut finish = |agents: &[String], group: Robots| {
+            if agents.iter().any(|a| a != "*" && !a.is_empty() && agent.contains(a.as_str())) {
+                specific.get_or_insert_with(Robots::default).merge(group);
+            } else if agents.iter().any(|a| a == "*") {
+                wildcard.get_or_insert_with(Robots::default).merge(group);
+            }
+        };
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            match key.as_str() {
+                "user-agent" => {
+                    if in_rules {
+                        finish(&group_agents, std::mem::take(&mut current));
+                        group_agents.clear();
+                        in_rules = false;
+                    }
+                    group_agents.push(value.to_lowercase());
+                }
+                "allow" | "disallow" => {
+                    in_rules = true;
+                    // An empty Disallow means "allow everything"
+                    if !value.is_empty() {
+                        current.rules.push(Rule { allow: key == "allow", pattern: value.to_string() });
+                    }
+                }
+                "crawl-delay" => {
+                    in_rules = true;
+                    current.crawl_delay = parse_crawl_delay(value);
+                }
+                _ => {}
+            }
+        }
+        finish(&group_agents, current);
+
+        specific.or(wildcard).unwrap_or_default()
+    }
+
+    fn merge(&mut self, other: Robots) {
+        self.rules.extend(other.rules);
+        self.crawl_delay = self.crawl_delay.or(other.crawl_delay);
+    }
+
+    /// Whether the given path (with query string) may be crawled
+    pub fn is_allowed(&self, path: &str) -> bool {
+        let mut best: Option<(usize, bool)> = None;
+
+        for rule in &self.rules {
+            if pattern_matches(&rule.pattern, path) {
+                let len = rule.pattern.len();
+                best = match best {
+                    Some((best_len, best_allow)) if best_len > len || (best_len == len && best_allow) => {
+                        Some((best_len, best_allow))
+                    }
+                    _ => Some((len, rule.allow)),
+                };
+            }
+        }
+
+        best.map(|(_, allow)| allow).unwrap_or(true)
+    }
+}
+
+/// Parse a Crawl-delay in seconds, ignoring negative and non-finite values and capping long delays
+fn parse_crawl_delay(value: &str) -> Option<Duration> {
+    let seconds = value.parse::<f64>().ok().filter(|s| s.is_finite() && *s >= 0.0)?;
+    Duration::try_from_secs_f64(seconds.min(MAX_CRAWL_DELAY.as_secs_f64())).ok()
+}
+
+/// Match a robots.txt path pattern (`*` matches any run of characters, a trailing `$` anchors the end)
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let Some(rest) = path.strip_prefix(parts[0]) else {
+        return false;
+    };
+
+    if parts.len() == 1 {
+        return !anchored || rest.is_empty();
+    }
+
+    let mut rest = rest;
+    for (i, part) in parts.iter().enumerate().skip(1) {
+        let last = i == parts.len() - 1;
+        if last && anchored {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROBOTS: &str = "
+        # Example
+        User-agent: *
+        Disallow: /private/
+        Allow: /private/public-page
+        Disallow: /*.pdf$
+        Crawl-delay: 2
+
+        User-agent: FastBrowserUse
+        User-agent: otherbot
+        Disallow: /search
+    ";
+
+    #[test]
+    fn test_wildcard_group() {
+        let robots = Robots::parse(ROBOTS, "SomeCrawler/1.0");
+
+        assert!(robots.is_allowed("/"));
+        assert!(!robots.is_allowed("/private/secret"));
+        assert!(robots.is_allowed("/private/public-page"));
+        assert!(!robots.is_allowed("/files/report.pdf"));
+        assert!(robots.is_allowed("/files/report.pdf?download=1"));
+        assert!(robots.is_allowed("/search?q=rust"));
+        assert_eq!(robots.crawl_delay, Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_specific_group_takes_precedence() {
+        let robots = Robots::parse(ROBOTS, "Mozilla/5.0 fastbrowseruse/0.1");
+
+        assert!(!robots.is_allowed("/search?q=rust"));
+        assert!(robots.is_allowed("/private/secret"));
+        assert_eq!(robots.crawl_delay, None);
+    }
+
+    #[test]
+    fn test_empty_and_missing_rules() {
+        let robots = Robots::parse("User-agent: *\nDisallow:\n", "bot");
+        assert!(robots.is_allowed("/anything"));
+        assert!(Robots::allow_all().is_allowed("/anything"));
+    }
+
+    #[test]
+    fn test_parse_crawl_delay() {
+        assert_eq!(parse_crawl_delay("0.5"), Some(Duration::from_millis(500)));
+        assert_eq!(parse_crawl_delay("86400"), Some(MAX_CRAWL_DELAY));
+        assert_eq!(parse_crawl_delay("1e300"), Some(MAX_CRAWL_DELAY));
+        assert_eq!(parse_crawl_delay("-1"), None);
+        assert_eq!(parse_crawl_delay("inf"), None);
+        assert_eq!(parse_crawl_delay("NaN"), None);
+        assert_eq!(parse_crawl_delay("soon"), None);
+
+        let robots = Robots::parse("User-agent: *\nCrawl-delay: -1\n", "bot");
+        assert_eq!(robots.crawl_delay, None);
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        assert!(pattern_matches("/a*/c", "/abc/c/d"));
+        assert!(pattern_matches("/", "/x"));
+        assert!(pattern_matches("/x$", "/x"));
+        assert!(!pattern_matches("/x$", "/xy"));
+        assert!(!pattern_matches("/a*z", "/abc"));
+    }
+}
@@ -55,6 +55,10 @@ pub struct AriaNode {
     /// Whether element is currently active/focused
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
+
+    /// Curated computed styles (only captured when styles are requested)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub styles: Option<ComputedStyles>,
 }
 
 /// Child of an AriaNode - either another AriaNode or a text string
@@ -106,6 +110,57 @@ pub struct Rect {
     pub height: f64,
 }
 
+/// Curated subset of an element's computed CSS styles
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ComputedStyles {
+    pub color: String,
+    pub background_color: String,
+    pub font_size: String,
+    pub font_weight: String,
+    pub display: String,
+    pub position: String,
+    pub z_index: String,
+    pub opacity: String,
+}
+
+impl ComputedStyles {
+    /// Render the styles as a compact `key:value;...` string, skipping browser defaults
+    pub fn to_compact(&self) -> String {
+        let mut parts = Vec::new();
+
+        if !self.color.is_empty() {
+            parts.push(format!("color:{}", self.color));
+        }
+        if !self.background_color.is_empty()
+            && self.background_color != "transparent"
+            && self.background_color != "rgba(0, 0, 0, 0)"
+        {
+            parts.push(format!("bg:{}", self.background_color));
+        }
+        if !self.font_size.is_empty() {
+            parts.push(format!("font-size:{}", self.font_size));
+        }
+        if !self.font_weight.is_empty() && self.font_weight != "400" && self.font_weight != "normal" {
+            parts.push(format!("font-weight:{}", self.font_weight));
+        }
+        if !self.display.is_empty() {
+            parts.push(format!("display:{}", self.display));
+        }
+        if !self.position.is_empty() && self.position != "static" {
+            parts.push(format!("position:{}", self.position));
+        }
+        if !self.z_index.is_empty() && self.z_index != "auto" {
+            parts.push(format!("z-index:{}", self.z_index));
+        }
+        if !self.opacity.is_empty() && self.opacity != "1" {
+            parts.push(format!("opacity:{}", self.opacity));
+        }
+
+        parts.join(";")
+    }
+}
+
 impl Default for BoxInfo {
     fn default() -> Self {
         Self { visible: false, cursor: None, rect: None }
@@ -129,6 +184,7 @@ impl AriaNode {
             pressed: None,
             selected: None,
             active: None,
+            styles: None,
         }
     }
 
@@ -191,6 +247,12 @@ impl AriaNode {
         self
     }
 
+    /// Builder: set computed styles
+    pub fn with_styles(mut self, styles: ComputedStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
     /// Check if this node is interactive (has an index and is visible)
     pub fn is_interactive(&self) -> bool {
         self.index.is_some() && self.box_info.visible
@@ -427,6 +489,28 @@ mod tests {
         assert!(!node1.aria_equals(&node3));
     }
 
+    #[test]
+    fn test_computed_styles_compact() {
+        let styles = ComputedStyles {
+            color: "rgb(255, 255, 255)".to_string(),
+            background_color: "rgb(0, 102, 204)".to_string(),
+            font_size: "16px".to_string(),
+            font_weight: "700".to_string(),
+            display: "inline-block".to_string(),
+            position: "static".to_string(),
+            z_index: "auto".to_string(),
+            opacity: "1".to_string(),
+        };
+
+        assert_eq!(
+            styles.to_compact(),
+            "color:rgb(255, 255, 255);bg:rgb(0, 102, 204);font-size:16px;font-weight:700;display:inline-block"
+        );
+
+        let faded = ComputedStyles { opacity: "0.5".to_string(), position: "fixed".to_string(), ..Default::default() };
+        assert_eq!(faded.to_compact(), "position:fixed;opacity:0.5");
+    }
+
     #[test]
     fn test_count_nodes() {
         let mut root = AriaNode::fragment();
@@ -4,6 +4,7 @@ JSON.stringify(
   (function () {
     "use strict";
 
+    const config = __EXTRACT_CONFIG__;
     let currentIndex = 0;
 
     // Helper: normalize whitespace
@@ -74,6 +75,21 @@ JSON.stringify(
       return { visible, inline, cursor, rect };
     }
 
+    // Helper: capture a curated set of computed styles
+    function computeStyles(element) {
+      const style = window.getComputedStyle(element);
+      return {
+        color: style.color,
+        background_color: style.backgroundColor,
+        font_size: style.fontSize,
+        font_weight: style.fontWeight,
+        display: style.display,
+        position: style.position,
+        z_index: style.zIndex,
+        opacity: style.opacity,
+      };
+    }
+
     // Helper: check if element receives pointer events
     function receivesPointerEvents(element) {
       const box = computeBox(element);
@@ -418,6 +434,11 @@ JSON.stringify(
 
       computeAriaIndex(result);
 
+      // Only capture styles for indexed elements to keep the payload small
+      if (config.includeStyles && result.index !== undefined) {
+        result.styles = computeStyles(element);
+      }
+
       // Add ARIA properties based on role
       const checkedRoles = [
         "checkbox",
@@ -711,6 +732,7 @@ JSON.stringify(
       if (ariaNode.level !== undefined) result.level = ariaNode.level;
       if (ariaNode.pressed !== undefined) result.pressed = ariaNode.pressed;
       if (ariaNode.selected !== undefined) result.selected = ariaNode.selected;
+      if (ariaNode.styles) result.styles = ariaNode.styles;
 
       // Serialize box info
       result.box_info = {
@@ -754,43 +776,7 @@ JSON.stringify(
       }
     }
 
-    // Build CSS selector for element
-    function buildSelector(element) {
-      if (element.id) {
-        return "#" + element.id;
-      }
-
-      const path = [];
-      let current = element;
-
-      while (current && current !== document.body) {
-        let selector = current.tagName.toLowerCase();
-
-        if (current.className && typeof current.className === "string") {
-          const classes = current.className.trim().split(/\s+/);
-          if (classes.length > 0 && classes[0]) {
-            selector += "." + classes[0];
-          }
-        }
-
-        // Add nth-child if needed for uniqueness
-        const parent = current.parentElement;
-        if (parent) {
-          const siblings = Array.from(parent.children);
-          const index = siblings.indexOf(current);
-          if (
-            siblings.filter((s) => s.tagName === current.tagName).length > 1
-          ) {
-            selector += ":nth-child(" + (index + 1) + ")";
-          }
-        }
-
-        path.unshift(selector);
-        current = current.parentElement;
-      }
-
-      return path.join(" > ");
-    }
+    __HELPERS__
 
     // Main execution
     try {
@@ -0,0 +1,56 @@
+// Helpers shared by the injected scripts
+// Substituted for the __HELPERS__ placeholder inside each script's function scope
+
+// Build CSS selector for element
+// Tools map elements back to snapshot indices by this selector, so every script must use it
+function buildSelector(element) {
+  if (element.id) {
+    return "#" + element.id;
+  }
+
+  const path = [];
+  let current = element;
+
+  while (current && current !== document.body) {
+    let selector = current.tagName.toLowerCase();
+
+    if (current.className && typeof current.className === "string") {
+      const classes = current.className.trim().split(/\s+/);
+      if (classes.length > 0 && classes[0]) {
+        selector += "." + classes[0];
+      }
+    }
+
+    // Add nth-child if needed for uniqueness
+    const parent = current.parentElement;
+    if (parent) {
+      const siblings = Array.from(parent.children);
+      const index = siblings.indexOf(current);
+      if (siblings.filter((s) => s.tagName === current.tagName).length > 1) {
+        selector += ":nth-child(" + (index + 1) + ")";
+      }
+    }
+
+    path.unshift(selector);
+    current = current.parentElement;
+  }
+
+  return path.join(" > ");
+}
+
+// Set a value through the prototype's native setter: React and Vue wrap the
+// instance property, so a plain `element.value = ...` is swallowed by their value tracking
+function setNativeValue(element, value) {
+  const proto =
+    element.tagName === "TEXTAREA"
+      ? HTMLTextAreaElement.prototype
+      : element.tagName === "SELECT"
+        ? HTMLSelectElement.prototype
+        : HTMLInputElement.prototype;
+  const descriptor = Object.getOwnPropertyDescriptor(proto, "value");
+  if (descriptor && descriptor.set) {
+    descriptor.set.call(element, value);
+  } else {
+    element.value = value;
+  }
+}
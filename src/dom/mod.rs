@@ -9,6 +9,13 @@ pub mod element;
 pub mod tree;
 pub mod yaml;
 
-pub use element::{AriaChild, AriaNode, BoundingBox, ElementNode};
+const HELPERS_JS: &str = include_str!("helpers.js");
+
+/// Fill a script's `__HELPERS__` placeholder with the helper functions shared by the injected scripts
+pub(crate) fn with_helpers(script: &str) -> String {
+    script.replace("__HELPERS__", HELPERS_JS)
+}
+
+pub use element::{AriaChild, AriaNode, BoundingBox, ComputedStyles, ElementNode};
 pub use tree::DomTree;
 pub use yaml::{yaml_escape_key_if_needed, yaml_escape_value_if_needed};
@@ -1,8 +1,11 @@
-use crate::{dom::element::{AriaChild, AriaNode},
+use crate::{dom::{element::{AriaChild, AriaNode},
+                 with_helpers},
             error::{BrowserError, Result}};
 use headless_chrome::Tab;
 use std::sync::Arc;
 
+const EXTRACT_DOM_JS: &str = include_str!("extract_dom.js");
+
 /// Represents the ARIA snapshot of a web page
 /// Based on Playwright's AriaSnapshot structure
 #[derive(Debug, Clone)]
@@ -42,12 +45,22 @@ impl DomTree {
     /// Build DOM tree from a browser tab with a ref prefix (for iframe handling)
     pub fn from_tab_with_prefix(tab: &Arc<Tab>, _ref_prefix: &str) -> Result<Self> {
         // Note: ref_prefix is deprecated but kept for API compatibility
+        Self::extract(tab, false)
+    }
+
+    /// Build DOM tree from a browser tab, capturing computed styles for indexed elements
+    pub fn from_tab_with_styles(tab: &Arc<Tab>) -> Result<Self> {
+        Self::extract(tab, true)
+    }
+
+    fn extract(tab: &Arc<Tab>, include_styles: bool) -> Result<Self> {
         // JavaScript code to extract ARIA snapshot
-        let js_code = include_str!("extract_dom.js");
+        let config = serde_json::json!({ "includeStyles": include_styles });
+        let js_code = with_helpers(EXTRACT_DOM_JS).replace("__EXTRACT_CONFIG__", &config.to_string());
 
         // Execute JavaScript to extract DOM
         let result = tab
-            .evaluate(js_code, false)
+            .evaluate(&js_code, false)
             .map_err(|e| BrowserError::DomParseFailed(format!("Failed to execute DOM extraction script: {}", e)))?;
 
         // Get the JSON string value
@@ -1,4 +1,5 @@
 pub mod browser;
+pub mod crawler;
 pub mod dom;
 pub mod error;
 pub mod tools;
@@ -6,7 +7,8 @@ pub mod tools;
 #[cfg(feature = "mcp-handler")]
 pub mod mcp;
 
-pub use browser::{BrowserSession, ConnectionOptions, LaunchOptions};
+pub use browser::{BrowserSession, Chord, ConnectionOptions, Device, Emulation, HumanEmulation, LaunchOptions};
+pub use crawler::{CrawlOptions, CrawledPage, Crawler};
 pub use dom::{BoundingBox, DomTree, ElementNode};
 pub use error::{BrowserError, Result};
 pub use tools::{Tool, ToolContext, ToolRegistry, ToolResult};
@@ -38,6 +38,7 @@ macro_rules! register_mcp_tools {
                     params: Parameters<<$tool_type as Tool>::Params>,
                 ) -> Result<CallToolResult, McpError> {
                     let session = self.session();
+                    session.note_action(stringify!($mcp_name), &serde_json::to_value(&params.0).unwrap_or_default());
                     let mut context = ToolContext::new(&*session);
                     let tool = <$tool_type>::default();
                     let result = tool.execute_typed(params.0, &mut context)
@@ -58,19 +59,47 @@ register_mcp_tools! {
     browser_close => tools::close::CloseTool, "Close the browser when the task is complete";
 
     // ---- Page Content and Extraction ----
-    browser_get_markdown => tools::markdown::GetMarkdownTool, "Get the markdown content of the current page (use this tool only for information extraction; for interaction use the snapshot tool instead)";
+    browser_get_markdown => tools::markdown::GetMarkdownTool, "Get the markdown content of the current page, split into pages at heading/paragraph/code boundaries with a table of contents of page titles; falls back to <main>/<body> when Readability finds no article, selector/index convert one region only; PDF documents are read as text with one page per PDF page; set with_indices to annotate links, buttons and inputs with snapshot indices for clicking (use this tool only for information extraction; for interaction use the snapshot tool instead)";
     browser_snapshot => tools::snapshot::SnapshotTool, "Get a snapshot of the current page with indexed interactive elements for interaction";
+    browser_get_metadata => tools::metadata::GetMetadataTool, "Get the page's structured metadata: JSON-LD blocks, OpenGraph and Twitter card tags, microdata/RDFa items, canonical URL, hreflang alternates, feeds and robots meta";
+    browser_extract_table => tools::table::ExtractTableTool, "Extract HTML tables (by CSS selector, index, or all tables) as JSON records or CSV, with colspan/rowspan expanded";
+    browser_extract_structured => tools::structured::ExtractStructuredTool, "Extract typed records from repeated elements: give a container selector and named fields (selector, attr, type string/number/url/date), optionally paging with a next-page selector or scrolling";
     browser_screenshot => tools::screenshot::ScreenshotTool, "Capture a screenshot of the current page";
+    browser_compare_screenshot => tools::compare_screenshot::CompareScreenshotTool, "Compare the viewport, full page or an element against a baseline PNG (created on first run), with a color threshold, anti-aliasing tolerance and masked selectors; returns the mismatch percentage and writes a highlighted diff image";
+    browser_start_recording => tools::recording::StartRecordingTool, "Start recording the active tab as a screencast; subsequent tool calls are drawn as captions on the frames";
+    browser_stop_recording => tools::recording::StopRecordingTool, "Stop the screencast recording and save it as an animated GIF or a directory of numbered PNG frames";
+    browser_save_pdf => tools::pdf::SavePdfTool, "Print the current page to PDF with paper size, margins, landscape, scale, header/footer templates, page ranges and background options; saves to a path or returns base64";
+    browser_save_page => tools::save_page::SavePageTool, "Archive the current page exactly as rendered, as MHTML or as a self-contained HTML file with inlined CSS/images and current form values; saves to a path or returns the content";
     // browser_get_text => tools::extract::ExtractContentTool, "Extract text or HTML content from the page or an element";
+    browser_set_viewport => tools::emulate::SetViewportTool, "Resize the viewport or emulate a device preset (iphone_15, iphone_se, pixel_7, ipad, ipad_pro, desktop, desktop_hidpi) with its scale factor, touch support, mobile mode and user agent; landscape rotates it";
+    browser_emulate => tools::emulate::EmulateTool, "Emulate prefers-color-scheme, prefers-reduced-motion, print media, a timezone, a locale (with Accept-Language) or a geolocation for all tabs; reset removes all emulation";
+    browser_emulate_network => tools::emulate::EmulateNetworkTool, "Throttle the network for all tabs with a preset (none, offline, slow-3g, fast-3g) or custom latency_ms/download_kbps/upload_kbps, to test slow-loading and offline behavior";
+    browser_emulate_cpu => tools::emulate::EmulateCpuTool, "Slow the CPU down by a factor (e.g. 4 for a mid-range phone; 1 disables throttling) for all tabs";
     browser_evaluate => tools::evaluate::EvaluateTool, "Execute JavaScript code in the browser context";
 
     // ---- Interaction ----
     browser_click => tools::click::ClickTool, "Click on an element specified by CSS selector or index (index obtained from browser_snapshot tool)";
     browser_hover => tools::hover::HoverTool, "Hover over an element specified by CSS selector or index (index obtained from browser_snapshot tool)";
-    browser_select => tools::select::SelectTool, "Select an option in a dropdown element by CSS selector or index (index obtained from browser_snapshot tool)";
-    browser_input_fill => tools::input::InputTool, "Type text into an input element specified by CSS selector or index (index obtained from browser_snapshot tool)";
+    browser_select => tools::select::SelectTool, "Select options in a dropdown element by CSS selector or index (index obtained from browser_snapshot tool), choosing by value, visible label, option index, or several values for multi-selects";
+    browser_set_checked => tools::set_checked::SetCheckedTool, "Check or uncheck a checkbox, radio or switch by CSS selector or index (index obtained from browser_snapshot tool); does nothing if it is already in the requested state";
+    browser_element_at_point => tools::element_at_point::ElementAtPointTool, "Find the element at a pixel coordinate or grid cell (e.g. \"C4\") of an annotated screenshot and return its snapshot index, role, name and selector";
+    browser_click_at_mark => tools::element_at_point::ClickAtMarkTool, "Click at a pixel coordinate or grid cell (e.g. \"C4\") of an annotated screenshot, reporting the snapshot index of the element that was hit";
+    browser_mouse_move => tools::mouse::MouseMoveTool, "Move the mouse pointer to viewport coordinates or an element's center with raw mouse events, optionally in several steps; held buttons make it a drag";
+    browser_mouse_down => tools::mouse::MouseDownTool, "Press a mouse button (left/right/middle/back/forward) at the current pointer position or at given coordinates/element, without releasing it";
+    browser_mouse_up => tools::mouse::MouseUpTool, "Release a mouse button at the current pointer position or at given coordinates/element";
+    browser_click_at => tools::mouse::ClickAtTool, "Click at viewport coordinates (or an element's center) with raw mouse events, choosing the button and click count; use for canvases, maps, sliders and games";
+    browser_wheel => tools::mouse::WheelTool, "Scroll with the mouse wheel by dx/dy pixels at the pointer position or at given coordinates/element";
+    browser_drag => tools::mouse::DragTool, "Drag from one point or element to another; HTML5 draggable elements get emulated drag-and-drop events, anything else a mouse press, moves and release";
+    browser_tap => tools::touch::TapTool, "Tap with a finger (touch events) at viewport coordinates or an element by CSS selector or index (index obtained from browser_snapshot tool); tap_count 2 double-taps";
+    browser_long_press => tools::touch::LongPressTool, "Press and hold a finger at viewport coordinates or an element by CSS selector or index, for context menus and hold-to-act controls";
+    browser_swipe => tools::touch::SwipeTool, "Swipe a finger from a point or element (default: viewport center) in a direction (up/down/left/right) or to another point, at a given velocity; drives carousels, feeds and pull-to-refresh";
+    browser_pinch => tools::touch::PinchTool, "Pinch with two fingers around a point or element (default: viewport center); scale above 1 zooms in, below 1 zooms out";
+    browser_input_fill => tools::input::InputTool, "Type text into an input element specified by CSS selector or index (index obtained from browser_snapshot tool). Use mode \"set_value\" for React/Vue controlled inputs, sliders, date/time/color inputs and rich-text editors";
+    browser_get_forms => tools::forms::GetFormsTool, "List the forms on the page with each field's label, name, type, required flag, current value, options and index";
+    browser_fill_form => tools::forms::FillFormTool, "Fill several form fields at once from a map of field label/name to value, optionally submitting the form";
     browser_press_key => tools::press_key::PressKeyTool, "Press a key on the keyboard";
-    browser_scroll => tools::scroll::ScrollTool, "Scroll the page by a specified amount or to the bottom";
+    browser_keyboard => tools::keyboard::KeyboardTool, "Press key chords such as \"Control+A\", \"Shift+Tab\" or \"Meta+Enter\" in sequence, or type text into the focused element with an optional per-character delay; insert_text commits emoji and IME text directly";
+    browser_scroll => tools::scroll::ScrollTool, "Scroll the page or a scrollable container vertically/horizontally, scroll an element (CSS selector or index) into view, or keep scrolling until a selector or text appears; reports each container's position and whether it reached the end";
     browser_wait => tools::wait::WaitTool, "Wait for an element to appear on the page";
 
     // ---- Tab Management ----
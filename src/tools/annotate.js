@@ -0,0 +1,16 @@
+// Measure the viewport, scroll position and page size for annotate
+// Returns JSON string with the geometry, in CSS pixels
+
+(function () {
+  const root = document.documentElement;
+  const body = document.body || root;
+  return JSON.stringify({
+    dpr: window.devicePixelRatio || 1,
+    scrollX: window.scrollX,
+    scrollY: window.scrollY,
+    viewportWidth: window.innerWidth,
+    viewportHeight: window.innerHeight,
+    pageWidth: Math.max(root.scrollWidth, body.scrollWidth),
+    pageHeight: Math.max(root.scrollHeight, body.scrollHeight),
+  });
+})();
@@ -1,17 +1,53 @@
-use crate::{
-    error::{BrowserError, Result},
-    tools::{Tool, ToolContext, ToolResult},
-    dom::element::AriaChild,
-};
+use crate::{dom::element::{AriaChild, AriaNode, Rect},
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::protocol::cdp::Page;
+use image::{Rgba, RgbaImage};
+use imageproc::{drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_text_mut, text_size},
+                rect::Rect as ImageRect};
+use rusttype::{Font, Scale};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use image::Rgba;
-use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut};
-use imageproc::rect::Rect as ImageRect;
-use rusttype::{Font, Scale};
-use std::io::Cursor;
-use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde_json::Value;
+use std::{collections::HashMap, io::Cursor};
+
+/// DejaVu Sans Mono Bold, used when no system font can be loaded (license in fonts/LICENSE-DejaVu.txt)
+const EMBEDDED_FONT: &[u8] = include_bytes!("fonts/DejaVuSansMono-Bold.ttf");
+
+const PAGE_GEOMETRY_JS: &str = include_str!("annotate.js");
+
+const BADGE_HEIGHT: u32 = 18;
+const LABEL_SCALE: f32 = 14.0;
+
+/// Default grid cell size, in CSS pixels
+pub const DEFAULT_GRID_SIZE: u32 = 100;
+
+/// Load the font used for labels, preferring a system font and falling back to the embedded one
+pub fn label_font() -> Font<'static> {
+    let font_path = if cfg!(target_os = "macos") {
+        "/System/Library/Fonts/Helvetica.ttc"
+    } else {
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf"
+    };
+    std::fs::read(font_path)
+        .ok()
+        .and_then(Font::try_from_vec)
+        .or_else(|| Font::try_from_bytes(EMBEDDED_FONT))
+        .expect("embedded font is valid")
+}
+
+/// Outline and badge color for an element role
+pub fn role_color(role: &str) -> Rgba<u8> {
+    match role {
+        "button" | "menuitem" | "menuitemcheckbox" | "menuitemradio" => Rgba([220, 38, 38, 255]),
+        "link" => Rgba([37, 99, 235, 255]),
+        "textbox" | "searchbox" | "combobox" | "spinbutton" | "slider" => Rgba([22, 163, 74, 255]),
+        "checkbox" | "radio" | "switch" => Rgba([147, 51, 234, 255]),
+        "tab" | "option" | "listbox" | "treeitem" => Rgba([234, 88, 12, 255]),
+        _ => Rgba([219, 39, 119, 255]),
+    }
+}
 
 /// Parameters for the annotate tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -22,6 +58,75 @@ pub struct AnnotateParams {
 
     /// Path to save the annotated screenshot (if not returning base64)
     pub path: Option<String>,
+
+    /// Annotate the whole scrollable page instead of the viewport (default: false)
+    #[serde(default)]
+    pub full_page: bool,
+
+    /// Only label elements with these roles, e.g. ["button", "link"] (default: all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+
+    /// Only label elements with these indices (default: all)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indices: Vec<usize>,
+
+    /// Draw a coordinate grid with lettered columns and numbered rows (default: false)
+    #[serde(default)]
+    pub grid: bool,
+
+    /// Grid cell size in CSS pixels (default: 100)
+    #[serde(default = "default_grid_size")]
+    pub grid_size: u32,
+}
+
+fn default_grid_size() -> u32 {
+    DEFAULT_GRID_SIZE
+}
+
+impl Default for AnnotateParams {
+    fn default() -> Self {
+        Self {
+            return_base64: false,
+            path: None,
+            full_page: false,
+            roles: Vec::new(),
+            indices: Vec::new(),
+            grid: false,
+            grid_size: DEFAULT_GRID_SIZE,
+        }
+    }
+}
+
+impl AnnotateParams {
+    /// Whether an indexed element passes the role and index filters
+    fn wants(&self, index: usize, role: &str) -> bool {
+        (self.roles.is_empty() || self.roles.iter().any(|r| r.eq_ignore_ascii_case(role)))
+            && (self.indices.is_empty() || self.indices.contains(&index))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageGeometry {
+    dpr: f64,
+    scroll_x: f64,
+    scroll_y: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+    page_width: f64,
+    page_height: f64,
+}
+
+/// An element labeled on the annotated screenshot
+#[derive(Debug, Clone, Serialize)]
+struct AnnotatedElement {
+    index: usize,
+    role: String,
+    name: String,
+    /// Position in the image, in CSS pixels
+    rect: Rect,
+    selector: Option<String>,
 }
 
 /// Tool for capturing a screenshot with annotated interactive elements
@@ -36,130 +141,351 @@ impl Tool for AnnotateTool {
     }
 
     fn execute_typed(&self, params: AnnotateParams, context: &mut ToolContext) -> Result<ToolResult> {
-        // 1. Capture screenshot
-        let screenshot_data = context
-            .session
-            .tab()?
-            .capture_screenshot(
-                headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
-                None,
-                None,
-                false, // Viewport only for annotation usually makes more sense to match coordinates
-            )
-            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+        if params.grid && params.grid_size < 10 {
+            return Err(BrowserError::InvalidArgument(format!(
+                "grid_size must be at least 10 pixels, got {}",
+                params.grid_size
+            )));
+        }
 
-        // 2. Extract DOM with bounding boxes
+        // Extract the DOM first: element rects are relative to the viewport at this scroll position
+        let tab = context.tab()?;
         let dom = context.get_dom()?;
 
-        // 3. Load image
-        let mut img = image::load_from_memory(&screenshot_data)
+        let geometry = tab
+            .evaluate(PAGE_GEOMETRY_JS, false)
+            .ok()
+            .and_then(|r| r.value)
+            .and_then(|v| v.as_str().and_then(|s| serde_json::from_str::<PageGeometry>(s).ok()))
+            .ok_or_else(|| BrowserError::ScreenshotFailed("Failed to measure page".to_string()))?;
+
+        let clip = params.full_page.then_some(Page::Viewport {
+            x: 0.0,
+            y: 0.0,
+            width: geometry.page_width,
+            height: geometry.page_height,
+            scale: 1.0,
+        });
+        let screenshot = tab
+            .call_method(Page::CaptureScreenshot {
+                format: Some(Page::CaptureScreenshotFormatOption::Png),
+                quality: None,
+                clip,
+                from_surface: Some(true),
+                capture_beyond_viewport: Some(params.full_page),
+                optimize_for_speed: None,
+            })
+            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+        let png = BASE64
+            .decode(&screenshot.data)
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Invalid screenshot data: {}", e)))?;
+        let mut img = image::load_from_memory(&png)
             .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to load screenshot image: {}", e)))?
             .to_rgba8();
 
-        let (width, height) = img.dimensions();
-
-        // 4. Load font (using a built-in font or loading from bytes if possible, otherwise we might fail)
-        // Since we can't easily rely on system fonts in a portable way, we'll try to use a bundled font or fallback.
-        // For this environment, let's assume we can't bundle a font easily without adding it to the repo.
-        // Actually, we can use `ab_glyph` with a font file.
-        // A better approach for a self-contained binary is to include a font as bytes.
-        // Let's use a very simple fallback or a known system font path if we can't embed.
-        // Wait, `imageproc` examples often use `DejaVuSans`.
-        // I'll try to look for a system font, or if that fails, just draw boxes without text? 
-        // No, numbers are crucial.
-        // I will embed a font. `DejaVuSans.ttf` is open.
-        // Since I can't download files easily right now, I'll assume a system path or try to find one.
-        // MacOS: /System/Library/Fonts/Helvetica.ttc
-        // Linux: /usr/share/fonts/truetype/dejavu/DejaVuSans.ttf
-        
-        let font_path = if cfg!(target_os = "macos") {
-            "/System/Library/Fonts/Helvetica.ttc"
+        // Element rects are converted to image coordinates (CSS pixels) and clipped to the captured area
+        let (offset_x, offset_y, area_width, area_height) = if params.full_page {
+            (geometry.scroll_x, geometry.scroll_y, geometry.page_width, geometry.page_height)
         } else {
-             "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf"
+            (0.0, 0.0, geometry.viewport_width, geometry.viewport_height)
         };
-        
-        // Very basic font loading fallback
-        let font_bytes = std::fs::read(font_path).unwrap_or_default();
-        let font = Font::try_from_bytes(&font_bytes);
-
-        // 5. Draw annotations
-        let mut map = HashMap::new();
-        let mut valid_indices = Vec::new();
-
-        // Collect all interactive elements with valid rects
-        // We traverse the DOM tree
-        let mut queue = vec![&dom.root];
-        while let Some(node) = queue.pop() {
-            if let Some(index) = node.index {
-                if let Some(rect) = &node.box_info.rect {
-                    if rect.width > 0.0 && rect.height > 0.0 && rect.x >= 0.0 && rect.y >= 0.0 {
-                        // Check if rect is within viewport roughly
-                         if rect.x < width as f64 && rect.y < height as f64 {
-                             valid_indices.push((index, rect.clone()));
-                             
-                             // Add to selector map
-                             if let Some(selector) = dom.get_selector(index) {
-                                 map.insert(index.to_string(), selector.clone());
-                             }
-                         }
-                    }
-                }
+
+        let mut nodes = Vec::new();
+        collect_indexed(&dom.root, &mut nodes);
+
+        let mut elements = Vec::new();
+        for node in nodes {
+            let (Some(index), Some(rect)) = (node.index, &node.box_info.rect) else {
+                continue;
+            };
+            if !params.wants(index, &node.role) || rect.width <= 0.0 || rect.height <= 0.0 {
+                continue;
             }
-            
-            for child in &node.children {
-                if let AriaChild::Node(child_node) = child {
-                    queue.push(child_node);
-                }
+            let rect = Rect { x: rect.x + offset_x, y: rect.y + offset_y, width: rect.width, height: rect.height };
+            let outside = rect.x + rect.width <= 0.0 || rect.y + rect.height <= 0.0;
+            if outside || rect.x >= area_width || rect.y >= area_height {
+                continue;
             }
+            elements.push(AnnotatedElement {
+                index,
+                role: node.role.clone(),
+                name: node.name.clone(),
+                rect,
+                selector: dom.get_selector(index).cloned(),
+            });
+        }
+        elements.sort_by_key(|e| e.index);
+
+        let font = label_font();
+        if params.grid {
+            draw_grid(&mut img, params.grid_size as f64 * geometry.dpr, geometry.dpr, &font);
+        }
+
+        // Outlines first, then badges placed so they do not cover each other
+        let outlines: Vec<ImageRect> = elements.iter().map(|e| device_rect(&e.rect, geometry.dpr)).collect();
+        for (element, outline) in elements.iter().zip(&outlines) {
+            draw_outline(&mut img, *outline, role_color(&element.role));
         }
-        
-        // Sort indices for consistent visualization if needed, but they are already indexed
-        
-        for (index, rect) in valid_indices {
-             // Draw yellow box
-             let x = rect.x as i32;
-             let y = rect.y as i32;
-             let w = rect.width as u32;
-             let h = rect.height as u32;
-             
-             // Define color: Yellow with alpha
-             let color = Rgba([255, 255, 0, 128]); // Semi-transparent yellow
-             let border_color = Rgba([255, 0, 0, 255]); // Red border
-             
-             // Draw filled rect (marker)
-             // We'll draw a small badge at the top-left corner of the element
-             let badge_size = 20;
-             let badge_rect = ImageRect::at(x, y).of_size(badge_size, badge_size);
-             
-             draw_filled_rect_mut(&mut img, badge_rect, border_color);
-             
-             // Draw text number
-             if let Some(font) = &font {
-                 let scale = Scale::uniform(16.0);
-                 let text = index.to_string();
-                 draw_text_mut(&mut img, Rgba([255, 255, 255, 255]), x + 2, y + 2, scale, font, &text);
-             }
+        let scale = Scale::uniform(LABEL_SCALE * geometry.dpr as f32);
+        let sizes: Vec<(u32, u32)> =
+            elements.iter().map(|e| badge_size(&e.index.to_string(), scale, geometry.dpr, &font)).collect();
+        let badges = layout_badges(&outlines, &sizes, img.dimensions());
+        for (element, badge) in elements.iter().zip(badges) {
+            let color = role_color(&element.role);
+            draw_badge(&mut img, badge, &element.index.to_string(), color, scale, geometry.dpr, &font);
         }
 
-        // 6. Save or return
         let mut bytes: Vec<u8> = Vec::new();
         img.write_to(&mut Cursor::new(&mut bytes), image::ImageOutputFormat::Png)
-             .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to encode annotated image: {}", e)))?;
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to encode annotated image: {}", e)))?;
+
+        let map: HashMap<String, String> = elements
+            .iter()
+            .filter_map(|e| e.selector.clone().map(|selector| (e.index.to_string(), selector)))
+            .collect();
 
         let mut result_data = serde_json::Map::new();
-        result_data.insert("map".to_string(), serde_json::to_value(&map).unwrap());
-        
+        result_data.insert("map".to_string(), serde_json::to_value(&map)?);
+        result_data.insert("elements".to_string(), serde_json::to_value(&elements)?);
+        result_data.insert("count".to_string(), elements.len().into());
+        result_data.insert("full_page".to_string(), params.full_page.into());
+        if params.grid {
+            result_data.insert("grid_size".to_string(), params.grid_size.into());
+        }
+
         if params.return_base64 {
-            let base64_string = BASE64.encode(&bytes);
-            result_data.insert("image_base64".to_string(), serde_json::Value::String(base64_string));
+            result_data.insert("image_base64".to_string(), Value::String(BASE64.encode(&bytes)));
         }
-        
+
         if let Some(path) = params.path {
             std::fs::write(&path, &bytes)
                 .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to save annotated screenshot: {}", e)))?;
-            result_data.insert("path".to_string(), serde_json::Value::String(path));
+            result_data.insert("path".to_string(), Value::String(path));
+        }
+
+        Ok(ToolResult::success(Some(Value::Object(result_data))))
+    }
+}
+
+/// Collect every node with an index, depth first
+fn collect_indexed<'a>(node: &'a AriaNode, out: &mut Vec<&'a AriaNode>) {
+    if node.index.is_some() {
+        out.push(node);
+    }
+    for child in &node.children {
+        if let AriaChild::Node(child_node) = child {
+            collect_indexed(child_node, out);
         }
+    }
+}
+
+/// Convert a CSS-pixel rect to an image rect in device pixels
+fn device_rect(rect: &Rect, dpr: f64) -> ImageRect {
+    let w = ((rect.width * dpr).round() as u32).max(1);
+    let h = ((rect.height * dpr).round() as u32).max(1);
+    ImageRect::at((rect.x * dpr).round() as i32, (rect.y * dpr).round() as i32).of_size(w, h)
+}
+
+/// Draw a two pixel wide outline
+fn draw_outline(img: &mut RgbaImage, rect: ImageRect, color: Rgba<u8>) {
+    draw_hollow_rect_mut(img, rect, color);
+    if rect.width() > 2 && rect.height() > 2 {
+        let inner = ImageRect::at(rect.left() + 1, rect.top() + 1).of_size(rect.width() - 2, rect.height() - 2);
+        draw_hollow_rect_mut(img, inner, color);
+    }
+}
+
+/// Size of a badge holding `text`, in device pixels
+fn badge_size(text: &str, scale: Scale, dpr: f64, font: &Font) -> (u32, u32) {
+    let (text_width, _) = text_size(scale, font, text);
+    (text_width.max(0) as u32 + (6.0 * dpr) as u32, (BADGE_HEIGHT as f64 * dpr) as u32)
+}
+
+fn draw_badge(img: &mut RgbaImage, badge: ImageRect, text: &str, color: Rgba<u8>, scale: Scale, dpr: f64, font: &Font) {
+    draw_filled_rect_mut(img, badge, color);
+    let (x, y) = (badge.left() + (3.0 * dpr) as i32, badge.top() + (2.0 * dpr) as i32);
+    draw_text_mut(img, Rgba([255, 255, 255, 255]), x, y, scale, font, text);
+}
+
+/// Place one badge per target so that badges overlap as little as possible.
+///
+/// Each badge tries the corners of its target (inside, then just outside) in order and takes the
+/// first spot that is clear of the badges already placed, or the least covered spot otherwise.
+/// Badges are always kept inside `bounds`.
+fn layout_badges(targets: &[ImageRect], sizes: &[(u32, u32)], bounds: (u32, u32)) -> Vec<ImageRect> {
+    let mut placed: Vec<ImageRect> = Vec::with_capacity(targets.len());
+    for (target, &(width, height)) in targets.iter().zip(sizes) {
+        let (x, y) = (target.left(), target.top());
+        let (right, bottom) = (target.right() + 1, target.bottom() + 1);
+        let (w, h) = (width as i32, height as i32);
+        let candidates = [
+            (x, y),
+            (x, y - h),
+            (right - w, y),
+            (right - w, y - h),
+            (x, bottom - h),
+            (x, bottom),
+            (x - w, y),
+            (right, y),
+        ];
+
+        let max_x = bounds.0.saturating_sub(width) as i32;
+        let max_y = bounds.1.saturating_sub(height) as i32;
+        let overlap = |rect: &ImageRect| -> u32 {
+            placed.iter().filter_map(|p| p.intersect(*rect)).map(|r| r.width() * r.height()).sum()
+        };
+
+        let mut best: Option<(ImageRect, u32)> = None;
+        for (cx, cy) in candidates {
+            let rect = ImageRect::at(cx.clamp(0, max_x), cy.clamp(0, max_y)).of_size(width, height);
+            let covered = overlap(&rect);
+            if best.is_none_or(|(_, least)| covered < least) {
+                best = Some((rect, covered));
+            }
+            if covered == 0 {
+                break;
+            }
+        }
+        if let Some((rect, _)) = best {
+            placed.push(rect);
+        }
+    }
+    placed
+}
+
+/// Spreadsheet-style column name for a zero-based grid column: A..Z, AA, AB, ...
+pub fn column_label(mut column: usize) -> String {
+    let mut label = Vec::new();
+    loop {
+        label.push(b'A' + (column % 26) as u8);
+        if column < 26 {
+            break;
+        }
+        column = column / 26 - 1;
+    }
+    label.reverse();
+    String::from_utf8(label).unwrap_or_default()
+}
+
+/// Parse a grid cell name such as "C4" into zero-based (column, row)
+pub fn parse_cell(cell: &str) -> Option<(usize, usize)> {
+    let cell = cell.trim().to_ascii_uppercase();
+    let split = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    if letters.is_empty() || !letters.bytes().all(|b| b.is_ascii_uppercase()) {
+        return None;
+    }
+    let column =
+        letters.bytes().try_fold(0usize, |acc, b| acc.checked_mul(26)?.checked_add((b - b'A' + 1) as usize))? - 1;
+    let row = digits.parse::<usize>().ok()?.checked_sub(1)?;
+    Some((column, row))
+}
+
+/// Blend a color into one pixel
+fn blend_pixel(img: &mut RgbaImage, x: u32, y: u32, color: [u8; 3], alpha: f32) {
+    let pixel = img.get_pixel_mut(x, y);
+    for (channel, value) in pixel.0.iter_mut().zip(color) {
+        *channel = (*channel as f32 * (1.0 - alpha) + value as f32 * alpha).round() as u8;
+    }
+}
+
+/// Draw a coordinate grid with lettered columns along the top and numbered rows down the left
+fn draw_grid(img: &mut RgbaImage, cell: f64, dpr: f64, font: &Font) {
+    let (width, height) = img.dimensions();
+    let line = [255, 0, 255];
+
+    let mut position = cell;
+    while position < width as f64 {
+        for y in 0..height {
+            blend_pixel(img, position as u32, y, line, 0.5);
+        }
+        position += cell;
+    }
+    let mut position = cell;
+    while position < height as f64 {
+        for x in 0..width {
+            blend_pixel(img, x, position as u32, line, 0.5);
+        }
+        position += cell;
+    }
+
+    let scale = Scale::uniform(LABEL_SCALE * dpr as f32);
+    let columns = (width as f64 / cell).ceil() as usize;
+    let rows = (height as f64 / cell).ceil() as usize;
+    let chip = |img: &mut RgbaImage, text: &str, center_x: f64, center_y: f64| {
+        let (w, h) = badge_size(text, scale, dpr, font);
+        let x = (center_x - w as f64 / 2.0).clamp(0.0, width.saturating_sub(w) as f64) as i32;
+        let y = (center_y - h as f64 / 2.0).clamp(0.0, height.saturating_sub(h) as f64) as i32;
+        let badge = ImageRect::at(x, y).of_size(w, h);
+        draw_filled_rect_mut(img, badge, Rgba([255, 255, 255, 255]));
+        draw_hollow_rect_mut(img, badge, Rgba([255, 0, 255, 255]));
+        let (tx, ty) = (x + (3.0 * dpr) as i32, y + (2.0 * dpr) as i32);
+        draw_text_mut(img, Rgba([0, 0, 0, 255]), tx, ty, scale, font, text);
+    };
+    for column in 0..columns {
+        chip(img, &column_label(column), (column as f64 + 0.5) * cell, 0.0);
+    }
+    for row in 0..rows {
+        chip(img, &(row + 1).to_string(), 0.0, (row as f64 + 0.5) * cell);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_font_loads() {
+        let font = Font::try_from_bytes(EMBEDDED_FONT).expect("embedded font should parse");
+        let (width, _) = text_size(Scale::uniform(LABEL_SCALE), &font, "42");
+        assert!(width > 0);
+    }
+
+    #[test]
+    fn test_role_filters() {
+        let params: AnnotateParams = serde_json::from_value(serde_json::json!({ "roles": ["Button"] })).unwrap();
+        assert!(params.wants(3, "button"));
+        assert!(!params.wants(3, "link"));
+
+        let params: AnnotateParams =
+            serde_json::from_value(serde_json::json!({ "roles": ["link"], "indices": [1, 2] })).unwrap();
+        assert!(params.wants(2, "link"));
+        assert!(!params.wants(5, "link"));
+        assert!(!params.wants(1, "button"));
+
+        assert!(AnnotateParams::default().wants(7, "textbox"));
+    }
+
+    #[test]
+    fn test_role_colors_distinguish_kinds() {
+        assert_ne!(role_color("button"), role_color("link"));
+        assert_ne!(role_color("link"), role_color("textbox"));
+        assert_eq!(role_color("searchbox"), role_color("textbox"));
+    }
+
+    #[test]
+    fn test_layout_badges_avoid_overlap() {
+        let targets = [ImageRect::at(10, 30).of_size(100, 40), ImageRect::at(10, 30).of_size(100, 40)];
+        let badges = layout_badges(&targets, &[(20, 18), (20, 18)], (800, 600));
+        assert_eq!((badges[0].left(), badges[0].top()), (10, 30));
+        assert!(badges[0].intersect(badges[1]).is_none());
+
+        // Badges stay inside the image
+        let badges = layout_badges(&[ImageRect::at(-50, -50).of_size(60, 60)], &[(20, 18)], (800, 600));
+        assert!(badges[0].left() >= 0 && badges[0].top() >= 0);
+    }
 
-        Ok(ToolResult::success(Some(serde_json::Value::Object(result_data))))
+    #[test]
+    fn test_grid_cell_names() {
+        assert_eq!(column_label(0), "A");
+        assert_eq!(column_label(25), "Z");
+        assert_eq!(column_label(26), "AA");
+        assert_eq!(column_label(27), "AB");
+        assert_eq!(parse_cell("A1"), Some((0, 0)));
+        assert_eq!(parse_cell("c4"), Some((2, 3)));
+        assert_eq!(parse_cell("AB10"), Some((27, 9)));
+        assert_eq!(parse_cell("A0"), None);
+        assert_eq!(parse_cell("12"), None);
+        assert_eq!(parse_cell("ZZZZZZZZZZZZZZ1"), None);
     }
 }
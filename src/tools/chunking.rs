@@ -0,0 +1,414 @@
+//! Markdown chunking along document structure
+//!
+//! Splits markdown into chunks of a maximum size without cutting through headings, code fences,
+//! tables or paragraphs where it can be avoided. Sizes are measured in characters (never bytes,
+//! so multi-byte text is never split mid-character) or approximate tokens.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Unit chunk sizes are measured in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkUnit {
+    /// Unicode characters
+    #[default]
+    Chars,
+    /// Approximate LLM tokens (about four characters each)
+    Tokens,
+}
+
+impl ChunkUnit {
+    /// Size of a piece of text in this unit
+    pub fn measure(&self, text: &str) -> usize {
+        let chars = text.chars().count();
+        match self {
+            ChunkUnit::Chars => chars,
+            ChunkUnit::Tokens => chars.div_ceil(4),
+        }
+    }
+}
+
+/// A chunk of markdown
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chunk {
+    /// First heading in the chunk, or the enclosing section's heading marked "(continued)"
+    pub title: String,
+    pub content: String,
+    /// Size in the requested unit
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum BlockKind {
+    Heading(String),
+    Code,
+    Table,
+    Text,
+}
+
+#[derive(Debug, Clone)]
+struct Block {
+    kind: BlockKind,
+    text: String,
+}
+
+fn heading_text(line: &str) -> Option<String> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+        Some(trimmed[level..].trim().trim_end_matches('#').trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn fence_marker(line: &str) -> Option<&'static str> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("```") {
+        Some("```")
+    } else if trimmed.starts_with("~~~") {
+        Some("~~~")
+    } else {
+        None
+    }
+}
+
+fn is_table_line(line: &str) -> bool {
+    line.trim_start().starts_with('|')
+}
+
+/// Split markdown into headings, code fences, tables and paragraphs
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block { kind: BlockKind::Text, text: paragraph.join("\n") });
+            paragraph.clear();
+        }
+    };
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+
+        if let Some(marker) = fence_marker(line) {
+            flush(&mut paragraph, &mut blocks);
+            let start = i;
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(marker) {
+                i += 1;
+            }
+            let end = (i + 1).min(lines.len());
+            blocks.push(Block { kind: BlockKind::Code, text: lines[start..end].join("\n") });
+            i = end;
+            continue;
+        }
+
+        if let Some(text) = heading_text(line) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block { kind: BlockKind::Heading(text), text: line.to_string() });
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if is_table_line(line) && paragraph.is_empty() {
+            let start = i;
+            while i + 1 < lines.len() && is_table_line(lines[i + 1]) {
+                i += 1;
+            }
+            blocks.push(Block { kind: BlockKind::Table, text: lines[start..=i].join("\n") });
+        } else {
+            paragraph.push(line);
+        }
+        i += 1;
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+/// Break a block that is larger than a chunk into pieces, on line boundaries where possible.
+///
+/// The first piece is at most `first_max` (the room left in the current chunk), later ones at most
+/// `max`. Lines that are still too long are cut between words, or between characters for code.
+/// Code fences are closed at the end of each piece and reopened at the start of the next.
+fn split_block(block: &Block, first_max: usize, max: usize, unit: ChunkUnit) -> Vec<String> {
+    let is_code = block.kind == BlockKind::Code;
+    let mut body: Vec<&str> = block.text.lines().collect();
+    let (open, close) = if is_code && !body.is_empty() {
+        let open = body.remove(0).to_string();
+        let close = match body.last() {
+            Some(last) if fence_marker(last).is_some() => body.pop().unwrap_or_default().to_string(),
+            _ => open.trim_start().chars().take(3).collect(),
+        };
+        (open, close)
+    } else {
+        (String::new(), String::new())
+    };
+
+    let wrap = |content: &str| {
+        if open.is_empty() { content.to_string() } else { format!("{}\n{}\n{}", open, content, close) }
+    };
+    let overhead = if open.is_empty() { 0 } else { unit.measure(&open) + unit.measure(&close) + 2 };
+    let budget = max.saturating_sub(overhead).max(1);
+    let first_budget = first_max.saturating_sub(overhead).max(1);
+
+    let mut pieces: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for line in body {
+        let mut rest = line.to_string();
+        loop {
+            let limit = if pieces.is_empty() { first_budget } else { budget };
+            let used = if current.is_empty() { 0 } else { unit.measure(&current) + 1 };
+            let room = limit.saturating_sub(used);
+            let size = unit.measure(&rest);
+
+            if size <= room {
+                if !current.is_empty() {
+                    current.push('\n');
+                }
+                current.push_str(&rest);
+                break;
+            }
+            // Start a new piece if the line fits there whole, or nothing more fits here
+            let room_chars = match unit {
+                ChunkUnit::Chars => room,
+                ChunkUnit::Tokens => room * 4,
+            };
+            if !current.is_empty() && (size <= budget || room_chars == 0) {
+                pieces.push(wrap(&current));
+                current.clear();
+                continue;
+            }
+
+            // Cut the line to fill this piece, preferring the last space for prose
+            let chars: Vec<char> = rest.chars().collect();
+            let mut cut = room_chars.clamp(1, chars.len());
+            if !is_code && let Some(space) = chars[..cut].iter().rposition(|c| *c == ' ').filter(|p| *p > 0) {
+                cut = space + 1;
+            }
+            let head: String = chars[..cut].iter().collect();
+            if !current.is_empty() {
+                current.push('\n');
+            }
+            current.push_str(head.trim_end());
+            pieces.push(wrap(&current));
+            current.clear();
+
+            rest = chars[cut..].iter().collect();
+            if rest.is_empty() {
+                break;
+            }
+        }
+    }
+    if !current.is_empty() || pieces.is_empty() {
+        pieces.push(wrap(&current));
+    }
+
+    pieces
+}
+
+/// Pick a chunk title: its first heading, else the enclosing section, else its opening words
+fn chunk_title(blocks: &[Block], section: Option<&str>) -> String {
+    let first_heading = blocks.iter().find_map(|b| match &b.kind {
+        BlockKind::Heading(text) => Some(text),
+        _ => None,
+    });
+    if let Some(text) = first_heading {
+        return text.clone();
+    }
+    if let Some(section) = section {
+        return format!("{} (continued)", section);
+    }
+    let first_line = blocks.first().and_then(|b| b.text.lines().next()).unwrap_or("").trim();
+    let mut title: String = first_line.chars().take(60).collect();
+    if first_line.chars().count() > 60 {
+        title.push('…');
+    }
+    title
+}
+
+/// Accumulates blocks into chunks
+struct Packer {
+    unit: ChunkUnit,
+    chunks: Vec<Chunk>,
+    current: Vec<Block>,
+    current_size: usize,
+    /// Heading in effect at the start of the current chunk
+    section: Option<String>,
+    /// Most recent heading seen
+    last_heading: Option<String>,
+}
+
+impl Packer {
+    /// Size of the current chunk if `size` more were appended to it
+    fn joined_size(&self, size: usize) -> usize {
+        if self.current.is_empty() { size } else { self.current_size + 2 + size }
+    }
+
+    fn push(&mut self, block: Block) {
+        if let BlockKind::Heading(text) = &block.kind {
+            self.last_heading = Some(text.clone());
+        }
+        self.current_size = self.joined_size(self.unit.measure(&block.text));
+        self.current.push(block);
+    }
+
+    fn emit(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+        let content = self.current.iter().map(|b| b.text.as_str()).collect::<Vec<_>>().join("\n\n");
+        self.chunks.push(Chunk {
+            title: chunk_title(&self.current, self.section.as_deref()),
+            size: self.unit.measure(&content),
+            content,
+        });
+        self.current.clear();
+        self.current_size = 0;
+    }
+
+    /// Close the current chunk, carrying a trailing heading over to the next one
+    fn flush(&mut self) {
+        let carried = match self.current.last() {
+            Some(Block { kind: BlockKind::Heading(_), .. }) if self.current.len() > 1 => self.current.pop(),
+            _ => None,
+        };
+        self.emit();
+        self.section = self.last_heading.clone();
+        if let Some(heading) = carried {
+            self.current_size = self.unit.measure(&heading.text);
+            self.current.push(heading);
+        }
+    }
+}
+
+/// Split markdown into chunks of at most `max_size` (in `unit`), preferring to break before headings.
+///
+/// Blocks larger than a chunk are split on line boundaries, then between words (or characters
+/// inside code), so every chunk stays within `max_size`.
+pub fn chunk_markdown(markdown: &str, max_size: usize, unit: ChunkUnit) -> Vec<Chunk> {
+    let max_size = max_size.max(1);
+    let mut packer =
+        Packer { unit, chunks: Vec::new(), current: Vec::new(), current_size: 0, section: None, last_heading: None };
+
+    for block in parse_blocks(markdown) {
+        let size = unit.measure(&block.text);
+        let is_heading = matches!(block.kind, BlockKind::Heading(_));
+
+        if !packer.current.is_empty()
+            && (packer.joined_size(size) > max_size || (is_heading && packer.current_size >= max_size / 2))
+        {
+            packer.flush();
+        }
+
+        if size > max_size {
+            let mut room = max_size.saturating_sub(packer.joined_size(0));
+            if room < max_size / 4 {
+                packer.flush();
+                room = max_size.saturating_sub(packer.joined_size(0));
+            }
+            for (i, piece) in split_block(&block, room, max_size, unit).into_iter().enumerate() {
+                if i > 0 {
+                    packer.flush();
+                }
+                packer.push(Block { kind: BlockKind::Text, text: piece });
+            }
+            continue;
+        }
+
+        packer.push(block);
+    }
+
+    // A trailing heading with nothing after it stays in the last chunk
+    packer.emit();
+    packer.chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_document_is_one_chunk() {
+        let chunks = chunk_markdown("# Title\n\nHello world.", 1000, ChunkUnit::Chars);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].title, "Title");
+        assert_eq!(chunks[0].content, "# Title\n\nHello world.");
+    }
+
+    #[test]
+    fn test_breaks_before_headings() {
+        let markdown = format!(
+            "# Intro\n\n{}\n\n## Install\n\n{}\n\n## Usage\n\n{}",
+            "a".repeat(60),
+            "b".repeat(60),
+            "c".repeat(60)
+        );
+        let chunks = chunk_markdown(&markdown, 100, ChunkUnit::Chars);
+
+        let titles: Vec<&str> = chunks.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["Intro", "Install", "Usage"]);
+        assert!(chunks[1].content.starts_with("## Install"));
+        assert!(chunks.iter().all(|c| c.size <= 100));
+    }
+
+    #[test]
+    fn test_code_fence_is_not_split_when_it_fits() {
+        let code = format!("```rust\n{}\n```", "let x = 1;\n".repeat(5).trim_end());
+        let markdown = format!("{}\n\n{}\n\n{}", "p".repeat(50), code, "q".repeat(50));
+        let chunks = chunk_markdown(&markdown, 80, ChunkUnit::Chars);
+
+        assert!(chunks.iter().any(|c| c.content == code));
+        for chunk in &chunks {
+            assert_eq!(chunk.content.matches("```").count() % 2, 0, "unbalanced fence in {:?}", chunk.content);
+        }
+    }
+
+    #[test]
+    fn test_oversized_code_fence_is_reopened() {
+        let code = format!("```\n{}\n```", (0..20).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n"));
+        let chunks = chunk_markdown(&code, 50, ChunkUnit::Chars);
+
+        assert!(chunks.len() > 1);
+        for chunk in &chunks {
+            assert!(chunk.content.starts_with("```\n") && chunk.content.ends_with("\n```"));
+            assert!(chunk.size <= 50);
+        }
+    }
+
+    #[test]
+    fn test_multibyte_text_never_panics() {
+        let markdown = format!("# 日本語\n\n{}\n\n{}", "漢字かな交じり文".repeat(40), "🎉".repeat(300));
+        let chunks = chunk_markdown(&markdown, 64, ChunkUnit::Chars);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.size <= 64));
+        let rejoined: String = chunks.iter().map(|c| c.content.replace('\n', "")).collect();
+        assert_eq!(rejoined.matches('🎉').count(), 300);
+    }
+
+    #[test]
+    fn test_continued_titles_and_tokens() {
+        let markdown = format!("## Reference\n\n{}\n\n{}", "word ".repeat(40).trim(), "more ".repeat(40).trim());
+        let chunks = chunk_markdown(&markdown, 60, ChunkUnit::Tokens);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].title, "Reference");
+        assert_eq!(chunks[1].title, "Reference (continued)");
+        assert_eq!(ChunkUnit::Tokens.measure("abcdefgh"), 2);
+        assert_eq!(ChunkUnit::Tokens.measure("abcdefghi"), 3);
+    }
+
+    #[test]
+    fn test_table_kept_whole() {
+        let table = "| a | b |\n|---|---|\n| 1 | 2 |\n| 3 | 4 |";
+        let markdown = format!("{}\n\n{}", "x".repeat(30), table);
+        let chunks = chunk_markdown(&markdown, 45, ChunkUnit::Chars);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].content, table);
+    }
+}
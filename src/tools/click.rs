@@ -1,5 +1,6 @@
-use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+use crate::{browser::MouseButton,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, mouse::element_center}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -44,9 +45,13 @@ impl Tool for ClickTool {
             _ => {}
         }
 
+        if context.session.is_human() {
+            return human_click(params, context);
+        }
+
         if let Some(selector) = params.selector {
             // CSS selector path
-            let tab = context.session.tab()?;
+            let tab = context.tab()?;
             let element = context.session.find_element(&tab, &selector)?;
             element
                 .click()
@@ -66,7 +71,7 @@ impl Tool for ClickTool {
                 selector.clone()
             };
 
-            let tab = context.session.tab()?;
+            let tab = context.tab()?;
             let element = context.session.find_element(&tab, &css_selector)?;
             element
                 .click()
@@ -82,3 +87,28 @@ impl Tool for ClickTool {
         }
     }
 }
+
+/// Click with human emulation: the pointer travels to the element center and hovers before clicking
+fn human_click(params: ClickParams, context: &mut ToolContext) -> Result<ToolResult> {
+    let (css_selector, method) = match (params.selector, params.index) {
+        (Some(selector), _) => (selector, "css"),
+        (None, Some(index)) => {
+            let dom = context.get_dom()?;
+            let selector = dom
+                .get_selector(index)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+            (selector.clone(), "index")
+        }
+        (None, None) => unreachable!("Validation ensures one field is Some"),
+    };
+
+    let (x, y) = element_center("click", &css_selector, context)?;
+    let tab = context.tab()?;
+    context.session.pointer_click(&tab, x, y, MouseButton::Left, 1)?;
+
+    Ok(ToolResult::success_with(serde_json::json!({
+        "index": params.index,
+        "selector": css_selector,
+        "method": method
+    })))
+}
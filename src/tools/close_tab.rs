@@ -20,7 +20,7 @@ impl Tool for CloseTabTool {
 
     fn execute_typed(&self, _params: CloseTabParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Get the current tab info before closing
-        let active_tab = context.session.tab()?;
+        let active_tab = context.tab()?;
         let tab_title = active_tab.get_title().unwrap_or_default();
         let tab_url = active_tab.get_url();
 
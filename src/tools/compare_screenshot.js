@@ -0,0 +1,51 @@
+// Measure the capture target and mask regions for compare_screenshot
+// All rectangles are in document (page) coordinates, in CSS pixels
+// Returns JSON string with the geometry
+
+(function () {
+  const config = __COMPARE_CONFIG__;
+
+  const documentRect = function (el) {
+    const rect = el.getBoundingClientRect();
+    return {
+      x: rect.left + window.scrollX,
+      y: rect.top + window.scrollY,
+      width: rect.width,
+      height: rect.height,
+    };
+  };
+
+  let target = null;
+  if (config.selector) {
+    const el = document.querySelector(config.selector);
+    if (!el) {
+      return JSON.stringify({
+        success: false,
+        error: "Element not found: " + config.selector,
+      });
+    }
+    el.scrollIntoView({ block: "nearest", inline: "nearest" });
+    target = documentRect(el);
+  }
+
+  const masks = [];
+  (config.masks || []).forEach(function (selector) {
+    document.querySelectorAll(selector).forEach(function (el) {
+      const rect = documentRect(el);
+      if (rect.width > 0 && rect.height > 0) masks.push(rect);
+    });
+  });
+
+  const root = document.documentElement;
+  const body = document.body || root;
+  return JSON.stringify({
+    success: true,
+    dpr: window.devicePixelRatio || 1,
+    scrollX: window.scrollX,
+    scrollY: window.scrollY,
+    pageWidth: Math.max(root.scrollWidth, body.scrollWidth),
+    pageHeight: Math.max(root.scrollHeight, body.scrollHeight),
+    target: target,
+    masks: masks,
+  });
+})();
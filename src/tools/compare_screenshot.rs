@@ -0,0 +1,521 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::protocol::cdp::Page;
+use image::{Rgba, RgbaImage};
+use imageproc::rect::Rect;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+const COMPARE_JS: &str = include_str!("compare_screenshot.js");
+
+/// Largest possible YIQ color distance, used to scale the threshold
+const MAX_YIQ_DELTA: f64 = 35215.0;
+
+/// Diff image colors for differences, anti-aliasing and masked regions
+const DIFF_COLOR: Rgba<u8> = Rgba([255, 0, 0, 255]);
+const ANTI_ALIAS_COLOR: Rgba<u8> = Rgba([255, 255, 0, 255]);
+const MASK_COLOR: Rgba<u8> = Rgba([200, 220, 255, 255]);
+
+/// Options for [`compare_images`]
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Per-pixel color distance threshold from 0 (exact) to 1 (anything matches)
+    pub threshold: f64,
+
+    /// Do not count pixels that look like anti-aliasing differences
+    pub ignore_anti_aliasing: bool,
+
+    /// Regions (in image pixels) excluded from the comparison
+    pub masks: Vec<Rect>,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self { threshold: 0.1, ignore_anti_aliasing: true, masks: Vec::new() }
+    }
+}
+
+/// Result of [`compare_images`]
+#[derive(Debug, Clone)]
+pub struct ImageDiff {
+    /// Width of the compared area (the larger of the two images)
+    pub width: u32,
+
+    /// Height of the compared area (the larger of the two images)
+    pub height: u32,
+
+    /// Pixels that differ beyond the threshold (including pixels outside the smaller image)
+    pub diff_pixels: u64,
+
+    /// Differing pixels ignored as anti-aliasing
+    pub anti_aliased_pixels: u64,
+
+    /// Pixels inside mask regions
+    pub masked_pixels: u64,
+
+    /// Diff visualization: the current image faded to gray, differences in red, anti-aliasing in yellow
+    pub image: RgbaImage,
+}
+
+impl ImageDiff {
+    /// Share of compared (unmasked) pixels that differ, in percent
+    pub fn mismatch_percent(&self) -> f64 {
+        let compared = u64::from(self.width) * u64::from(self.height) - self.masked_pixels;
+        if compared == 0 { 0.0 } else { self.diff_pixels as f64 * 100.0 / compared as f64 }
+    }
+}
+
+/// Compare two images pixel by pixel.
+///
+/// Colors are compared by perceptual (YIQ) distance; with `ignore_anti_aliasing`, differing pixels
+/// on the edge of shapes present in both images are reported separately instead of as differences.
+pub fn compare_images(baseline: &RgbaImage, current: &RgbaImage, options: &DiffOptions) -> ImageDiff {
+    let width = baseline.width().max(current.width());
+    let height = baseline.height().max(current.height());
+    let max_delta = MAX_YIQ_DELTA * options.threshold * options.threshold;
+
+    let mut image = RgbaImage::new(width, height);
+    let mut diff_pixels = 0;
+    let mut anti_aliased_pixels = 0;
+    let mut masked_pixels = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let (a, b) = match (baseline.get_pixel_checked(x, y), current.get_pixel_checked(x, y)) {
+                (Some(a), Some(b)) => (*a, *b),
+                // Outside one of the images
+                _ => {
+                    if is_masked(&options.masks, x, y) {
+                        masked_pixels += 1;
+                        image.put_pixel(x, y, MASK_COLOR);
+                    } else {
+                        diff_pixels += 1;
+                        image.put_pixel(x, y, DIFF_COLOR);
+                    }
+                    continue;
+                }
+            };
+
+            if is_masked(&options.masks, x, y) {
+                masked_pixels += 1;
+                image.put_pixel(x, y, MASK_COLOR);
+                continue;
+            }
+
+            if color_delta(a, b, false).abs() <= max_delta {
+                image.put_pixel(x, y, gray(b));
+            } else if options.ignore_anti_aliasing
+                && (is_anti_aliased(baseline, x, y, current) || is_anti_aliased(current, x, y, baseline))
+            {
+                anti_aliased_pixels += 1;
+                image.put_pixel(x, y, ANTI_ALIAS_COLOR);
+            } else {
+                diff_pixels += 1;
+                image.put_pixel(x, y, DIFF_COLOR);
+            }
+        }
+    }
+
+    ImageDiff { width, height, diff_pixels, anti_aliased_pixels, masked_pixels, image }
+}
+
+fn is_masked(masks: &[Rect], x: u32, y: u32) -> bool {
+    let (x, y) = (x as i32, y as i32);
+    masks.iter().any(|m| x >= m.left() && x <= m.right() && y >= m.top() && y <= m.bottom())
+}
+
+/// Blend a color channel with white by `alpha`
+fn blend(channel: f64, alpha: f64) -> f64 {
+    255.0 + (channel - 255.0) * alpha
+}
+
+fn yiq(pixel: Rgba<u8>) -> (f64, f64, f64) {
+    let alpha = f64::from(pixel[3]) / 255.0;
+    let r = blend(f64::from(pixel[0]), alpha);
+    let g = blend(f64::from(pixel[1]), alpha);
+    let b = blend(f64::from(pixel[2]), alpha);
+    (
+        r * 0.29889531 + g * 0.58662247 + b * 0.11448223,
+        r * 0.59597799 - g * 0.27417610 - b * 0.32180189,
+        r * 0.21147017 - g * 0.52261711 + b * 0.31114694,
+    )
+}
+
+/// Perceptual distance between two colors; negative when `a` is lighter than `b`.
+/// With `brightness_only`, only the difference in luma is returned.
+fn color_delta(a: Rgba<u8>, b: Rgba<u8>, brightness_only: bool) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    let (y1, i1, q1) = yiq(a);
+    let (y2, i2, q2) = yiq(b);
+    let y = y1 - y2;
+    if brightness_only {
+        return y;
+    }
+    let (i, q) = (i1 - i2, q1 - q2);
+    let delta = 0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q;
+    if y1 > y2 { -delta } else { delta }
+}
+
+/// 3x3 neighborhood of (x, y) clamped to the image, and whether (x, y) is on the image edge
+fn neighborhood(image: &RgbaImage, x: u32, y: u32) -> (u32, u32, u32, u32, bool) {
+    let (x0, y0) = (x.saturating_sub(1), y.saturating_sub(1));
+    let (x2, y2) = ((x + 1).min(image.width() - 1), (y + 1).min(image.height() - 1));
+    let on_edge = x == x0 || x == x2 || y == y0 || y == y2;
+    (x0, y0, x2, y2, on_edge)
+}
+
+/// Whether the pixel has more than two identical neighbors (i.e. it is part of a flat area)
+fn has_many_siblings(image: &RgbaImage, x: u32, y: u32) -> bool {
+    if x >= image.width() || y >= image.height() {
+        return false;
+    }
+    let (x0, y0, x2, y2, on_edge) = neighborhood(image, x, y);
+    let pixel = image.get_pixel(x, y);
+    let mut zeroes = usize::from(on_edge);
+
+    for ny in y0..=y2 {
+        for nx in x0..=x2 {
+            if (nx, ny) != (x, y) && image.get_pixel(nx, ny) == pixel {
+                zeroes += 1;
+                if zeroes > 2 {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Whether a differing pixel is likely anti-aliasing: it sits between a darker and a brighter
+/// neighbor, and one of those neighbors belongs to a flat area in both images.
+fn is_anti_aliased(image: &RgbaImage, x: u32, y: u32, other: &RgbaImage) -> bool {
+    let (x0, y0, x2, y2, on_edge) = neighborhood(image, x, y);
+    let pixel = *image.get_pixel(x, y);
+    let mut zeroes = usize::from(on_edge);
+    let (mut min, mut max) = (0.0, 0.0);
+    let (mut darkest, mut brightest) = ((x, y), (x, y));
+
+    for ny in y0..=y2 {
+        for nx in x0..=x2 {
+            if (nx, ny) == (x, y) {
+                continue;
+            }
+            let delta = color_delta(pixel, *image.get_pixel(nx, ny), true);
+            if delta == 0.0 {
+                zeroes += 1;
+                if zeroes > 2 {
+                    return false;
+                }
+            } else if delta < min {
+                min = delta;
+                darkest = (nx, ny);
+            } else if delta > max {
+                max = delta;
+                brightest = (nx, ny);
+            }
+        }
+    }
+
+    if min == 0.0 || max == 0.0 {
+        return false;
+    }
+
+    let flat_in_both = |(px, py): (u32, u32)| has_many_siblings(image, px, py) && has_many_siblings(other, px, py);
+    flat_in_both(darkest) || flat_in_both(brightest)
+}
+
+/// Faded grayscale version of a pixel, used as the diff image background
+fn gray(pixel: Rgba<u8>) -> Rgba<u8> {
+    let (y, _, _) = yiq(pixel);
+    let value = blend(y, 0.1 * f64::from(pixel[3]) / 255.0).round() as u8;
+    Rgba([value, value, value, 255])
+}
+
+/// Parameters for the compare_screenshot tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CompareScreenshotParams {
+    /// Path of the baseline PNG (created from the current screenshot if it does not exist)
+    pub baseline: String,
+
+    /// Path to write the diff image (default: baseline path with a .diff.png extension)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_path: Option<String>,
+
+    /// Compare the full scrollable page instead of the viewport (default: false)
+    #[serde(default)]
+    pub full_page: bool,
+
+    /// CSS selector of an element to compare instead of the viewport (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree to compare instead of the viewport (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Per-pixel color distance threshold from 0 to 1 (default: 0.1)
+    #[serde(default = "default_threshold")]
+    pub threshold: f64,
+
+    /// Ignore differences caused by anti-aliasing (default: true)
+    #[serde(default = "default_ignore_anti_aliasing")]
+    pub ignore_anti_aliasing: bool,
+
+    /// CSS selectors of regions to ignore, such as timestamps or ads
+    #[serde(default)]
+    pub mask: Vec<String>,
+
+    /// Largest mismatch percentage still reported as a match (default: 0)
+    #[serde(default)]
+    pub max_mismatch_percent: f64,
+
+    /// Overwrite the baseline with the current screenshot after comparing (default: false)
+    #[serde(default)]
+    pub update_baseline: bool,
+}
+
+fn default_threshold() -> f64 {
+    0.1
+}
+
+fn default_ignore_anti_aliasing() -> bool {
+    true
+}
+
+/// Geometry reported by compare_screenshot.js, in CSS pixels
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageGeometry {
+    dpr: f64,
+    scroll_x: f64,
+    scroll_y: f64,
+    page_width: f64,
+    page_height: f64,
+    target: Option<CssRect>,
+    masks: Vec<CssRect>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct CssRect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+/// Tool for comparing the page against a baseline screenshot
+#[derive(Default)]
+pub struct CompareScreenshotTool;
+
+impl Tool for CompareScreenshotTool {
+    type Params = CompareScreenshotParams;
+
+    fn name(&self) -> &str {
+        "compare_screenshot"
+    }
+
+    fn execute_typed(&self, params: CompareScreenshotParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let failed =
+            |reason: String| BrowserError::ToolExecutionFailed { tool: "compare_screenshot".to_string(), reason };
+
+        if !(0.0..=1.0).contains(&params.threshold) {
+            return Err(BrowserError::InvalidArgument(format!(
+                "threshold must be between 0 and 1, got {}",
+                params.threshold
+            )));
+        }
+
+        let selector = match (params.selector, params.index) {
+            (Some(_), Some(_)) => {
+                return Err(failed("Cannot specify both 'selector' and 'index'. Use one or the other.".to_string()));
+            }
+            (Some(selector), None) => Some(selector),
+            (None, Some(index)) => {
+                let dom = context.get_dom()?;
+                let selector = dom
+                    .get_selector(index)
+                    .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+                Some(selector.clone())
+            }
+            (None, None) => None,
+        };
+        if selector.is_some() && params.full_page {
+            return Err(failed("Cannot combine 'full_page' with 'selector' or 'index'.".to_string()));
+        }
+
+        // Measure the capture area and mask regions
+        let tab = context.tab()?;
+        let config = serde_json::json!({ "selector": selector, "masks": params.mask });
+        let js = COMPARE_JS.replace("__COMPARE_CONFIG__", &config.to_string());
+        let result = tab.evaluate(&js, false).map_err(|e| failed(e.to_string()))?;
+
+        let result_json: Value = if let Some(Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            serde_json::json!({"success": false, "error": "No result returned"})
+        };
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(failed(result_json["error"].as_str().unwrap_or("Unknown error").to_string()));
+        }
+        let geometry: PageGeometry = serde_json::from_value(result_json)?;
+
+        // Capture, remembering where the image's top-left corner is on the page
+        let (clip, origin) = match (geometry.target, params.full_page) {
+            (Some(rect), _) => (Some(rect), (rect.x, rect.y)),
+            (None, true) => {
+                let page = CssRect { x: 0.0, y: 0.0, width: geometry.page_width, height: geometry.page_height };
+                (Some(page), (0.0, 0.0))
+            }
+            (None, false) => (None, (geometry.scroll_x, geometry.scroll_y)),
+        };
+
+        let screenshot = tab
+            .call_method(Page::CaptureScreenshot {
+                format: Some(Page::CaptureScreenshotFormatOption::Png),
+                quality: None,
+                clip: clip.map(|r| Page::Viewport { x: r.x, y: r.y, width: r.width, height: r.height, scale: 1.0 }),
+                from_surface: Some(true),
+                capture_beyond_viewport: Some(clip.is_some()),
+                optimize_for_speed: None,
+            })
+            .map_err(|e| BrowserError::ScreenshotFailed(e.to_string()))?;
+        let png = BASE64
+            .decode(&screenshot.data)
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Invalid screenshot data: {}", e)))?;
+        let current = image::load_from_memory(&png)
+            .map_err(|e| BrowserError::ScreenshotFailed(format!("Failed to load screenshot image: {}", e)))?
+            .to_rgba8();
+
+        // First run: the current screenshot becomes the baseline
+        let baseline_path = Path::new(&params.baseline);
+        if !baseline_path.exists() {
+            std::fs::write(baseline_path, &png).map_err(|e| failed(format!("Failed to write baseline: {}", e)))?;
+            return Ok(ToolResult::success_with(serde_json::json!({
+                "baseline": params.baseline,
+                "baseline_created": true,
+                "matches": true,
+                "mismatch_percent": 0.0,
+                "width": current.width(),
+                "height": current.height(),
+            })));
+        }
+
+        let baseline = image::open(baseline_path)
+            .map_err(|e| failed(format!("Failed to load baseline {}: {}", params.baseline, e)))?
+            .to_rgba8();
+
+        let masks = geometry
+            .masks
+            .iter()
+            .filter_map(|m| {
+                let x = ((m.x - origin.0) * geometry.dpr).floor() as i32;
+                let y = ((m.y - origin.1) * geometry.dpr).floor() as i32;
+                let width = (m.width * geometry.dpr).ceil() as u32;
+                let height = (m.height * geometry.dpr).ceil() as u32;
+                (width > 0 && height > 0).then(|| Rect::at(x, y).of_size(width, height))
+            })
+            .collect();
+        let options =
+            DiffOptions { threshold: params.threshold, ignore_anti_aliasing: params.ignore_anti_aliasing, masks };
+
+        let diff = compare_images(&baseline, &current, &options);
+        let mismatch_percent = diff.mismatch_percent();
+
+        let diff_path =
+            params.diff_path.unwrap_or_else(|| baseline_path.with_extension("diff.png").to_string_lossy().to_string());
+        diff.image.save(&diff_path).map_err(|e| failed(format!("Failed to write diff image: {}", e)))?;
+
+        if params.update_baseline {
+            std::fs::write(baseline_path, &png).map_err(|e| failed(format!("Failed to update baseline: {}", e)))?;
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "baseline": params.baseline,
+            "baseline_created": false,
+            "baseline_updated": params.update_baseline,
+            "matches": mismatch_percent <= params.max_mismatch_percent,
+            "mismatch_percent": mismatch_percent,
+            "diff_pixels": diff.diff_pixels,
+            "anti_aliased_pixels": diff.anti_aliased_pixels,
+            "masked_pixels": diff.masked_pixels,
+            "size_mismatch": baseline.dimensions() != current.dimensions(),
+            "width": diff.width,
+            "height": diff.height,
+            "diff_path": diff_path,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+    const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+
+    /// White image with a black square at (2..6, 2..6)
+    fn square() -> RgbaImage {
+        RgbaImage::from_fn(10, 10, |x, y| if (2..6).contains(&x) && (2..6).contains(&y) { BLACK } else { WHITE })
+    }
+
+    #[test]
+    fn test_identical_images() {
+        let diff = compare_images(&square(), &square(), &DiffOptions::default());
+        assert_eq!(diff.diff_pixels, 0);
+        assert_eq!(diff.mismatch_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_changed_pixels_and_threshold() {
+        let mut changed = square();
+        changed.put_pixel(8, 8, BLACK);
+        changed.put_pixel(0, 9, Rgba([250, 250, 250, 255]));
+
+        let diff = compare_images(&square(), &changed, &DiffOptions::default());
+        assert_eq!(diff.diff_pixels, 1);
+        assert_eq!(diff.image.get_pixel(8, 8), &DIFF_COLOR);
+        assert!((diff.mismatch_percent() - 1.0).abs() < 1e-9);
+
+        let strict = DiffOptions { threshold: 0.0, ..Default::default() };
+        assert_eq!(compare_images(&square(), &changed, &strict).diff_pixels, 2);
+    }
+
+    #[test]
+    fn test_masks() {
+        let mut changed = square();
+        changed.put_pixel(8, 8, BLACK);
+
+        let options = DiffOptions { masks: vec![Rect::at(7, 7).of_size(3, 3)], ..Default::default() };
+        let diff = compare_images(&square(), &changed, &options);
+        assert_eq!(diff.diff_pixels, 0);
+        assert_eq!(diff.masked_pixels, 9);
+    }
+
+    #[test]
+    fn test_anti_aliasing() {
+        // A gray pixel on the edge of the square, as a renderer smoothing the edge would produce
+        let mut smoothed = square();
+        smoothed.put_pixel(6, 3, Rgba([128, 128, 128, 255]));
+
+        let diff = compare_images(&square(), &smoothed, &DiffOptions::default());
+        assert_eq!(diff.diff_pixels, 0);
+        assert_eq!(diff.anti_aliased_pixels, 1);
+
+        let strict = DiffOptions { ignore_anti_aliasing: false, ..Default::default() };
+        assert_eq!(compare_images(&square(), &smoothed, &strict).diff_pixels, 1);
+    }
+
+    #[test]
+    fn test_size_mismatch() {
+        let taller = RgbaImage::from_fn(10, 12, |x, y| *square().get_pixel_checked(x, y).unwrap_or(&WHITE));
+        let diff = compare_images(&square(), &taller, &DiffOptions::default());
+        assert_eq!((diff.width, diff.height), (10, 12));
+        assert_eq!(diff.diff_pixels, 20);
+    }
+}
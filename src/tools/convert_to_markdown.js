@@ -33,10 +33,70 @@
       throw new Error("Failed to load Readability constructor");
     }
 
+    // Element indices to annotate, as [index, selector] pairs (see MARKDOWN_CONFIG)
+    var refs =
+      typeof MARKDOWN_CONFIG !== "undefined" && MARKDOWN_CONFIG.refs
+        ? MARKDOWN_CONFIG.refs
+        : [];
+
+    // Tag indexed elements so they can be found again after Readability rewrites the content.
+    // Must run before anything is removed, since selectors rely on nth-child positions.
+    var tagRefs = function (root) {
+      refs.forEach(function (ref) {
+        try {
+          var el = root.querySelector(ref[1]);
+          if (el && !el.hasAttribute("data-bu-ref")) {
+            el.setAttribute("data-bu-ref", String(ref[0]));
+          }
+        } catch (e) {
+          // Ignore selectors that are not valid in this context
+        }
+      });
+      return root;
+    };
+
+    // Append a {#index} marker after each tagged element. Form fields have no
+    // markdown form, so they get a short description such as [input: Email]{#5}
+    var indexed = 0;
+    var markRefs = function (html) {
+      if (refs.length === 0) return html;
+      var template = document.createElement("template");
+      template.innerHTML = html;
+      template.content.querySelectorAll("[data-bu-ref]").forEach(function (el) {
+        var marker = "{#" + el.getAttribute("data-bu-ref") + "}";
+        var tag = el.tagName.toLowerCase();
+        if (tag === "input" || tag === "select" || tag === "textarea") {
+          var label =
+            el.getAttribute("aria-label") ||
+            el.getAttribute("placeholder") ||
+            el.getAttribute("name") ||
+            "";
+          var type = tag === "input" ? el.getAttribute("type") || "text" : tag;
+          marker = "[" + type + (label ? ": " + label : "") + "]" + marker;
+        }
+        el.removeAttribute("data-bu-ref");
+        indexed++;
+        el.after(document.createTextNode(marker));
+      });
+      return template.innerHTML;
+    };
+
     // Clone the document to avoid DOM flickering (visual artifacts)
     // This prevents the page from changing appearance during extraction
     // Use deep clone with true parameter to ensure all children are cloned
-    var documentClone = document.cloneNode(true);
+    var documentClone = tagRefs(document.cloneNode(true));
+
+    // Region to convert instead of the whole page, resolved before cleanup so
+    // nth-child selectors still match (see MARKDOWN_CONFIG)
+    var regionSelector =
+      typeof MARKDOWN_CONFIG !== "undefined" ? MARKDOWN_CONFIG.selector : null;
+    var region = null;
+    if (regionSelector) {
+      region = documentClone.querySelector(regionSelector);
+      if (!region) {
+        throw new Error("No element matches selector: " + regionSelector);
+      }
+    }
 
     // Clean up unwanted elements from the clone
     // These elements don't contribute to the main content and can interfere with extraction
@@ -59,39 +119,63 @@
         el.remove();
       });
 
-    // Use Mozilla Readability algorithm to extract main content
-    // This filters out navigation, ads, sidebars, etc.
-    var reader = new ReadabilityConstructor(documentClone);
-    var article = reader.parse();
-
-    if (!article) {
-      // Readability failed to extract content, fall back to basic extraction
-      // This can happen on pages with insufficient content or unusual structure
-      var fallbackContent = document.body ? document.body.innerHTML : "";
-      var fallbackText = document.body ? document.body.textContent : "";
-
+    // Result without Readability metadata, for regions and fallbacks
+    var plainResult = function (root, strategy) {
+      var text = root ? root.textContent : "";
       return JSON.stringify({
         title: document.title || "",
-        content: fallbackContent,
-        textContent: fallbackText,
+        content: root ? markRefs(root.innerHTML) : "",
+        textContent: text,
         url: window.location.href,
         excerpt: "",
         byline: "",
         siteName: "",
-        length: fallbackText.length,
+        length: text.length,
         lang: document.documentElement.lang || "",
         dir: document.documentElement.dir || "",
         publishedTime: "",
-        readabilityFailed: true,
-        error: "Readability.parse() returned null - using fallback extraction",
+        readabilityFailed: false,
+        strategy: strategy,
+        indexed: indexed,
       });
+    };
+
+    if (region) {
+      return plainResult(region, "region");
+    }
+
+    // Readability modifies the document it parses, so keep a copy for the fallback
+    var fallbackClone = documentClone.cloneNode(true);
+
+    // Use Mozilla Readability algorithm to extract main content
+    // This filters out navigation, ads, sidebars, etc.
+    var reader = new ReadabilityConstructor(documentClone);
+    var article = reader.parse();
+
+    if (!article || !(article.textContent || "").trim()) {
+      // Readability failed to extract content (common on app-like pages, search
+      // results and dashboards): convert <main> or <body> without page chrome
+      fallbackClone
+        .querySelectorAll(
+          "nav, header, footer, aside, form[role=search], [role=navigation], " +
+            "[role=banner], [role=contentinfo], [role=complementary], [hidden]",
+        )
+        .forEach(function (el) {
+          el.remove();
+        });
+
+      var main = fallbackClone.querySelector("main, [role=main]");
+      if (main && main.textContent.trim()) {
+        return plainResult(main, "main");
+      }
+      return plainResult(fallbackClone.body, "body");
     }
 
     // Return structured data as JSON string
     // The HTML content will be converted to Markdown on the Rust side
     return JSON.stringify({
       title: article.title || document.title || "",
-      content: article.content || "", // Main HTML content
+      content: markRefs(article.content || ""), // Main HTML content
       textContent: article.textContent || "", // Plain text version
       url: window.location.href,
       excerpt: article.excerpt || "",
@@ -102,6 +186,8 @@
       dir: article.dir || document.documentElement.dir || "",
       publishedTime: article.publishedTime || "",
       readabilityFailed: false,
+      strategy: "readability",
+      indexed: indexed, // Number of elements annotated with {#index}
     });
   } catch (error) {
     // If anything goes wrong, return error information
@@ -0,0 +1,67 @@
+// Emulate HTML5 drag and drop between two viewport points
+// Native drags do not run in headless Chrome, so when the element under
+// `from` is draggable the drag events are dispatched from script with a
+// shared DataTransfer. Nothing is done when it is not draggable.
+// The "start" phase picks up the element at `from`; the "drop" phase runs
+// after the drop target has been scrolled into view and drops it at `to`.
+// Returns JSON string telling whether the drag was emulated
+
+(function () {
+  const config = __DRAG_CONFIG__;
+
+  const fire = function (target, type, point, dataTransfer) {
+    const event = new DragEvent(type, {
+      bubbles: true,
+      cancelable: true,
+      composed: true,
+      clientX: point[0],
+      clientY: point[1],
+      dataTransfer: dataTransfer,
+    });
+    target.dispatchEvent(event);
+    return event;
+  };
+
+  if (config.phase === "start") {
+    const hit = document.elementFromPoint(config.from[0], config.from[1]);
+    const source = hit && hit.closest("[draggable=true]");
+    if (!source) {
+      return JSON.stringify({ success: true, emulated: false });
+    }
+
+    const dataTransfer = new DataTransfer();
+    if (fire(source, "dragstart", config.from, dataTransfer).defaultPrevented) {
+      return JSON.stringify({ success: true, emulated: true, cancelled: true, dropped: false });
+    }
+    fire(source, "drag", config.from, dataTransfer);
+    window.__browserUseDrag = { source: source, dataTransfer: dataTransfer };
+    return JSON.stringify({ success: true, emulated: true });
+  }
+
+  const drag = window.__browserUseDrag;
+  delete window.__browserUseDrag;
+  if (!drag) {
+    return JSON.stringify({ success: false, error: "No drag in progress" });
+  }
+
+  const target = document.elementFromPoint(config.to[0], config.to[1]);
+  let dropped = false;
+  if (target) {
+    fire(target, "dragenter", config.to, drag.dataTransfer);
+    // A drop target signals that it accepts the drop by cancelling dragover
+    if (fire(target, "dragover", config.to, drag.dataTransfer).defaultPrevented) {
+      fire(target, "drop", config.to, drag.dataTransfer);
+      dropped = true;
+    } else {
+      fire(target, "dragleave", config.to, drag.dataTransfer);
+    }
+  }
+  fire(drag.source, "dragend", config.to, drag.dataTransfer);
+
+  return JSON.stringify({
+    success: true,
+    emulated: true,
+    dropped: dropped,
+    target: target ? target.tagName.toLowerCase() : null,
+  });
+})();
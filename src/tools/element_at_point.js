@@ -0,0 +1,35 @@
+// Map the node hit at a point back to its nearest indexed element
+// Called with `this` bound to the hit node and the snapshot selectors as the
+// only argument (selectors[i] is the CSS selector of element index i)
+// Returns JSON string with the index (null when no indexed element contains
+// the node) and the tag of the element that was hit
+
+(function (selectors) {
+  const hit = this.nodeType === Node.ELEMENT_NODE ? this : this.parentElement;
+  if (!hit) return JSON.stringify({ index: null, tag: null });
+
+  const indexed = new Map();
+  selectors.forEach(function (selector, i) {
+    if (!selector) return;
+    try {
+      const el = document.querySelector(selector);
+      if (el && !indexed.has(el)) indexed.set(el, i);
+    } catch (e) {
+      // Skip selectors that do not apply to the main document
+    }
+  });
+
+  // Walk up through ancestors, crossing shadow roots to their hosts
+  let el = hit;
+  while (el) {
+    if (indexed.has(el)) {
+      return JSON.stringify({
+        index: indexed.get(el),
+        tag: hit.tagName.toLowerCase(),
+      });
+    }
+    const root = el.getRootNode();
+    el = el.parentElement || (root instanceof ShadowRoot ? root.host : null);
+  }
+  return JSON.stringify({ index: null, tag: hit.tagName.toLowerCase() });
+})
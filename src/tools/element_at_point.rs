@@ -0,0 +1,233 @@
+use crate::{browser::MouseButton,
+            dom::DomTree,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult,
+                    annotate::{DEFAULT_GRID_SIZE, parse_cell}}};
+use headless_chrome::{Tab, protocol::cdp::{DOM, Runtime}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const ELEMENT_AT_POINT_JS: &str = include_str!("element_at_point.js");
+
+/// A point on an annotate screenshot: pixel coordinates or a grid cell
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MarkTarget {
+    /// X coordinate in CSS pixels (use with y, or use cell)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<f64>,
+
+    /// Y coordinate in CSS pixels (use with x, or use cell)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<f64>,
+
+    /// Grid cell from an annotate screenshot, e.g. "C4" (the point is the cell center)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cell: Option<String>,
+
+    /// Grid cell size used for the annotation, in CSS pixels (default: 100)
+    #[serde(default = "default_grid_size")]
+    pub grid_size: u32,
+
+    /// Coordinates are relative to the whole page (a full_page annotation) rather than the viewport;
+    /// the page is scrolled to bring the point into view (default: false)
+    #[serde(default)]
+    pub full_page: bool,
+}
+
+fn default_grid_size() -> u32 {
+    DEFAULT_GRID_SIZE
+}
+
+impl MarkTarget {
+    /// The point in annotation coordinates (CSS pixels)
+    pub fn point(&self) -> Result<(f64, f64)> {
+        match (&self.cell, self.x, self.y) {
+            (Some(_), Some(_), _) | (Some(_), _, Some(_)) => Err(BrowserError::InvalidArgument(
+                "Cannot specify both 'cell' and 'x'/'y'. Use one or the other.".into(),
+            )),
+            (Some(cell), None, None) => {
+                let (column, row) = parse_cell(cell).ok_or_else(|| {
+                    BrowserError::InvalidArgument(format!("Invalid grid cell '{}', expected e.g. \"C4\"", cell))
+                })?;
+                let size = self.grid_size.max(1) as f64;
+                Ok(((column as f64 + 0.5) * size, (row as f64 + 0.5) * size))
+            }
+            (None, Some(x), Some(y)) => Ok((x, y)),
+            _ => Err(BrowserError::InvalidArgument("Must specify either 'cell' or both 'x' and 'y'.".into())),
+        }
+    }
+}
+
+/// Parameters for the element_at_point tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ElementAtPointParams {
+    #[serde(flatten)]
+    pub target: MarkTarget,
+}
+
+/// Parameters for the click_at_mark tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClickAtMarkParams {
+    #[serde(flatten)]
+    pub target: MarkTarget,
+}
+
+/// Convert annotation coordinates to viewport coordinates, scrolling page coordinates into view
+fn viewport_point(tab: &Tab, target: &MarkTarget) -> Result<(f64, f64)> {
+    let (x, y) = target.point()?;
+    if !target.full_page {
+        return Ok((x, y));
+    }
+
+    let js = format!(
+        "(function () {{ var x = {}, y = {}; if (x < scrollX || y < scrollY || x >= scrollX + innerWidth || \
+         y >= scrollY + innerHeight) {{ scrollTo(x - innerWidth / 2, y - innerHeight / 2); }} \
+         return JSON.stringify([x - scrollX, y - scrollY]); }})()",
+        x, y
+    );
+    let result = tab.evaluate(&js, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+    result
+        .value
+        .and_then(|v| v.as_str().and_then(|s| serde_json::from_str::<(f64, f64)>(s).ok()))
+        .ok_or_else(|| BrowserError::EvaluationFailed("Failed to scroll to point".to_string()))
+}
+
+/// Find the element at a viewport point with DOM.getNodeForLocation and map it to its snapshot index
+fn hit_test(tab: &Tab, dom: &DomTree, x: f64, y: f64) -> Result<Value> {
+    let failed = |reason: String| BrowserError::ToolExecutionFailed { tool: "element_at_point".to_string(), reason };
+    if x < 0.0 || y < 0.0 {
+        return Err(BrowserError::InvalidArgument(format!("Point ({}, {}) is outside the viewport", x, y)));
+    }
+
+    tab.call_method(DOM::Enable { include_whitespace: None }).map_err(|e| failed(e.to_string()))?;
+    let location = tab
+        .call_method(DOM::GetNodeForLocation {
+            x: x.round() as u32,
+            y: y.round() as u32,
+            include_user_agent_shadow_dom: Some(false),
+            ignore_pointer_events_none: Some(true),
+        })
+        .map_err(|e| failed(format!("No element at ({}, {}): {}", x, y, e)))?;
+    let object_id = tab
+        .call_method(DOM::ResolveNode {
+            node_id: None,
+            backend_node_id: Some(location.backend_node_id),
+            object_group: None,
+            execution_context_id: None,
+        })
+        .map_err(|e| failed(e.to_string()))?
+        .object
+        .object_id
+        .ok_or_else(|| failed("Hit node could not be resolved".to_string()))?;
+
+    let result = tab
+        .call_method(Runtime::CallFunctionOn {
+            function_declaration: ELEMENT_AT_POINT_JS.to_string(),
+            object_id: Some(object_id),
+            arguments: Some(vec![Runtime::CallArgument {
+                value: Some(serde_json::to_value(&dom.selectors)?),
+                unserializable_value: None,
+                object_id: None,
+            }]),
+            silent: None,
+            return_by_value: Some(true),
+            generate_preview: None,
+            user_gesture: None,
+            await_promise: None,
+            execution_context_id: None,
+            object_group: None,
+            throw_on_side_effect: None,
+            unique_context_id: None,
+            serialization_options: None,
+        })
+        .map_err(|e| failed(e.to_string()))?;
+
+    let hit: Value = match result.result.value {
+        Some(Value::String(json_str)) => serde_json::from_str(&json_str)?,
+        _ => return Err(failed("No result returned".to_string())),
+    };
+
+    let index = hit["index"].as_u64().map(|i| i as usize);
+    let node = index.and_then(|i| dom.find_node_by_index(i));
+    Ok(serde_json::json!({
+        "x": x,
+        "y": y,
+        "index": index,
+        "role": node.map(|n| n.role.clone()),
+        "name": node.map(|n| n.name.clone()),
+        "selector": index.and_then(|i| dom.get_selector(i)),
+        "tag": hit["tag"],
+    }))
+}
+
+/// Tool for mapping a point or grid cell on an annotated screenshot back to a snapshot index
+#[derive(Default)]
+pub struct ElementAtPointTool;
+
+impl Tool for ElementAtPointTool {
+    type Params = ElementAtPointParams;
+
+    fn name(&self) -> &str {
+        "element_at_point"
+    }
+
+    fn execute_typed(&self, params: ElementAtPointParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let (x, y) = viewport_point(&tab, &params.target)?;
+        let dom = context.get_dom()?;
+        Ok(ToolResult::success_with(hit_test(&tab, dom, x, y)?))
+    }
+}
+
+/// Tool for clicking a point or grid cell on an annotated screenshot
+#[derive(Default)]
+pub struct ClickAtMarkTool;
+
+impl Tool for ClickAtMarkTool {
+    type Params = ClickAtMarkParams;
+
+    fn name(&self) -> &str {
+        "click_at_mark"
+    }
+
+    fn execute_typed(&self, params: ClickAtMarkParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let (x, y) = viewport_point(&tab, &params.target)?;
+        let dom = context.get_dom()?;
+        let element = hit_test(&tab, dom, x, y)?;
+
+        context.session.pointer_click(&tab, x, y, MouseButton::Left, 1)?;
+
+        Ok(ToolResult::success_with(element))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(json: Value) -> MarkTarget {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_mark_target_point() {
+        assert_eq!(target(serde_json::json!({ "x": 12.5, "y": 40 })).point().unwrap(), (12.5, 40.0));
+        assert_eq!(target(serde_json::json!({ "cell": "C4" })).point().unwrap(), (250.0, 350.0));
+        assert_eq!(target(serde_json::json!({ "cell": "b1", "grid_size": 50 })).point().unwrap(), (75.0, 25.0));
+
+        assert!(target(serde_json::json!({ "cell": "C4", "x": 1 })).point().is_err());
+        assert!(target(serde_json::json!({ "x": 1 })).point().is_err());
+        assert!(target(serde_json::json!({ "cell": "4C" })).point().is_err());
+    }
+
+    #[test]
+    fn test_params_flatten_target() {
+        let params: ClickAtMarkParams =
+            serde_json::from_value(serde_json::json!({ "cell": "A1", "full_page": true })).unwrap();
+        assert_eq!(params.target.cell.as_deref(), Some("A1"));
+        assert!(params.target.full_page);
+        assert_eq!(params.target.grid_size, DEFAULT_GRID_SIZE);
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{browser::emulation::{ColorScheme, DEVICE_PRESETS, Device, Emulation, Geolocation, MediaType,
+                                 NetworkConditions},
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the set_viewport tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SetViewportParams {
+    /// Device preset: iphone_15, iphone_se, pixel_7, ipad, ipad_pro, desktop or desktop_hidpi.
+    /// Sets viewport, scale factor, touch, mobile mode and user agent; other fields override it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+
+    /// Viewport width in CSS pixels (required without a device)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+
+    /// Viewport height in CSS pixels (required without a device)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+
+    /// Device pixel ratio (default: 1, or the device's)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_scale_factor: Option<f64>,
+
+    /// Mobile viewport with touch events (default: false, or the device's)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<bool>,
+
+    /// Rotate the viewport to landscape (default: false)
+    #[serde(default)]
+    pub landscape: bool,
+}
+
+/// Parameters for the emulate tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct EmulateParams {
+    /// Value of the prefers-color-scheme media feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_scheme: Option<ColorScheme>,
+
+    /// Value of the prefers-reduced-motion media feature (true: reduce)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduced_motion: Option<bool>,
+
+    /// CSS media type, e.g. "print" to see print styles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<MediaType>,
+
+    /// IANA timezone, e.g. "America/New_York"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Locale for Intl/navigator.language and the Accept-Language header, e.g. "de-DE"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+
+    /// Position reported by navigator.geolocation (the permission is granted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geolocation: Option<Geolocation>,
+
+    /// Remove all emulation (including the device) before applying the other fields (default: false)
+    #[serde(default)]
+    pub reset: bool,
+}
+
+/// Network throttling profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum NetworkPreset {
+    /// No throttling
+    #[serde(alias = "online")]
+    None,
+    Offline,
+    #[serde(rename = "slow-3g", alias = "slow_3g")]
+    Slow3g,
+    #[serde(rename = "fast-3g", alias = "fast_3g")]
+    Fast3g,
+    /// Use latency_ms, download_kbps and upload_kbps
+    Custom,
+}
+
+/// Parameters for the emulate_network tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmulateNetworkParams {
+    /// Profile: "none", "offline", "slow-3g", "fast-3g" or "custom"
+    pub preset: NetworkPreset,
+
+    /// Latency added to each request, in milliseconds (custom, or overrides the preset's)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<f64>,
+
+    /// Download bandwidth in kilobits per second (custom, or overrides the preset's; default: unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_kbps: Option<f64>,
+
+    /// Upload bandwidth in kilobits per second (custom, or overrides the preset's; default: unlimited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_kbps: Option<f64>,
+}
+
+impl EmulateNetworkParams {
+    /// The network conditions these parameters describe
+    pub fn conditions(&self) -> Result<NetworkConditions> {
+        let mut conditions = match self.preset {
+            NetworkPreset::None => NetworkConditions::none(),
+            NetworkPreset::Offline => NetworkConditions::offline(),
+            NetworkPreset::Slow3g => NetworkConditions::slow_3g(),
+            NetworkPreset::Fast3g => NetworkConditions::fast_3g(),
+            NetworkPreset::Custom => NetworkConditions::custom(0.0, None, None),
+        };
+        let values = [
+            ("latency_ms", self.latency_ms),
+            ("download_kbps", self.download_kbps),
+            ("upload_kbps", self.upload_kbps),
+        ];
+        for (name, value) in values {
+            if value.is_some_and(|v| v < 0.0 || !v.is_finite()) {
+                return Err(BrowserError::InvalidArgument(format!("'{}' must be a non-negative number", name)));
+            }
+        }
+        if let Some(latency) = self.latency_ms {
+            conditions.latency_ms = latency;
+        }
+        conditions.download_kbps = self.download_kbps.or(conditions.download_kbps);
+        conditions.upload_kbps = self.upload_kbps.or(conditions.upload_kbps);
+        Ok(conditions)
+    }
+}
+
+/// Parameters for the emulate_cpu tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmulateCpuParams {
+    /// Slowdown factor, e.g. 4 for a mid-range phone or 6 for a low-end one (1: no throttling)
+    pub rate: f64,
+}
+
+/// Viewport size and user agent as the page sees them
+fn page_viewport(context: &mut ToolContext) -> Result<serde_json::Value> {
+    let js = "JSON.stringify({ innerWidth, innerHeight, devicePixelRatio, userAgent: navigator.userAgent, \
+              language: navigator.language, touch: navigator.maxTouchPoints > 0 })";
+    let result = context.tab()?.evaluate(js, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+    match result.value {
+        Some(serde_json::Value::String(json_str)) => Ok(serde_json::from_str(&json_str)?),
+        _ => Ok(serde_json::Value::Null),
+    }
+}
+
+/// Tool for changing the viewport size or emulating a device
+#[derive(Default)]
+pub struct SetViewportTool;
+
+impl Tool for SetViewportTool {
+    type Params = SetViewportParams;
+
+    fn name(&self) -> &str {
+        "set_viewport"
+    }
+
+    fn execute_typed(&self, params: SetViewportParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let mut device = match (&params.device, params.width, params.height) {
+            (Some(name), _, _) => Device::preset(name).ok_or_else(|| {
+                BrowserError::InvalidArgument(format!(
+                    "Unknown device '{}'. Available devices: {}",
+                    name,
+                    DEVICE_PRESETS.join(", ")
+                ))
+            })?,
+            (None, Some(width), Some(height)) => Device::viewport(width, height),
+            (None, _, _) => {
+                return Err(BrowserError::InvalidArgument(
+                    "Must specify either 'device' or both 'width' and 'height'.".to_string(),
+                ));
+            }
+        };
+
+        if let Some(width) = params.width {
+            device.width = width;
+        }
+        if let Some(height) = params.height {
+            device.height = height;
+        }
+        if let Some(scale) = params.device_scale_factor {
+            if scale <= 0.0 {
+                return Err(BrowserError::InvalidArgument("'device_scale_factor' must be positive".to_string()));
+            }
+            device.device_scale_factor = scale;
+        }
+        if let Some(mobile) = params.mobile {
+            device.mobile = mobile;
+            device.touch = mobile;
+        }
+        if device.width == 0 || device.height == 0 {
+            return Err(BrowserError::InvalidArgument("'width' and 'height' must be positive".to_string()));
+        }
+        if params.landscape {
+            device = device.landscape();
+        }
+
+        context.session.emulate(Emulation::new().device(device.clone()))?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "device": device,
+            "page": page_viewport(context)?,
+        })))
+    }
+}
+
+/// Tool for emulating media features, timezone, locale and geolocation
+#[derive(Default)]
+pub struct EmulateTool;
+
+impl Tool for EmulateTool {
+    type Params = EmulateParams;
+
+    fn name(&self) -> &str {
+        "emulate"
+    }
+
+    fn execute_typed(&self, params: EmulateParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let emulation = Emulation {
+            device: None,
+            color_scheme: params.color_scheme,
+            reduced_motion: params.reduced_motion,
+            media: params.media,
+            timezone: params.timezone,
+            locale: params.locale,
+            geolocation: params.geolocation,
+            ..Emulation::default()
+        };
+        if emulation.is_empty() && !params.reset {
+            return Err(BrowserError::InvalidArgument(
+                "Specify at least one of color_scheme, reduced_motion, media, timezone, locale, geolocation or reset."
+                    .to_string(),
+            ));
+        }
+
+        if params.reset {
+            context.session.clear_emulation()?;
+        }
+        let current = context.session.emulate(emulation)?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "emulation": current,
+            "page": page_viewport(context)?,
+        })))
+    }
+}
+
+/// Tool for throttling the network or taking it offline
+#[derive(Default)]
+pub struct EmulateNetworkTool;
+
+impl Tool for EmulateNetworkTool {
+    type Params = EmulateNetworkParams;
+
+    fn name(&self) -> &str {
+        "emulate_network"
+    }
+
+    fn execute_typed(&self, params: EmulateNetworkParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let conditions = params.conditions()?;
+        context.session.emulate(Emulation::new().network(conditions))?;
+        Ok(ToolResult::success_with(serde_json::json!({ "preset": params.preset, "network": conditions })))
+    }
+}
+
+/// Tool for slowing the CPU down
+#[derive(Default)]
+pub struct EmulateCpuTool;
+
+impl Tool for EmulateCpuTool {
+    type Params = EmulateCpuParams;
+
+    fn name(&self) -> &str {
+        "emulate_cpu"
+    }
+
+    fn execute_typed(&self, params: EmulateCpuParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if params.rate < 1.0 || !params.rate.is_finite() {
+            return Err(BrowserError::InvalidArgument("'rate' must be at least 1 (1 disables throttling)".to_string()));
+        }
+        context.session.emulate(Emulation::new().cpu_throttling(params.rate))?;
+        Ok(ToolResult::success_with(serde_json::json!({ "rate": params.rate })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_viewport_params() {
+        let params: SetViewportParams =
+            serde_json::from_value(serde_json::json!({ "device": "pixel_7", "landscape": true })).unwrap();
+        assert_eq!(params.device.as_deref(), Some("pixel_7"));
+        assert!(params.landscape);
+        assert_eq!(params.width, None);
+    }
+
+    #[test]
+    fn test_emulate_params() {
+        let params: EmulateParams = serde_json::from_value(serde_json::json!({
+            "color_scheme": "dark",
+            "media": "print",
+            "locale": "de-DE",
+            "geolocation": { "latitude": 52.52, "longitude": 13.405 }
+        }))
+        .unwrap();
+        assert_eq!(params.color_scheme, Some(ColorScheme::Dark));
+        assert_eq!(params.media, Some(MediaType::Print));
+        assert_eq!(params.geolocation.unwrap().accuracy, 10.0);
+        assert!(!params.reset);
+    }
+
+    #[test]
+    fn test_network_params() {
+        let params: EmulateNetworkParams = serde_json::from_value(serde_json::json!({ "preset": "slow-3g" })).unwrap();
+        assert_eq!(params.conditions().unwrap(), NetworkConditions::slow_3g());
+
+        let params: EmulateNetworkParams =
+            serde_json::from_value(serde_json::json!({ "preset": "fast_3g", "latency_ms": 100 })).unwrap();
+        assert_eq!(params.conditions().unwrap().latency_ms, 100.0);
+
+        let params: EmulateNetworkParams = serde_json::from_value(serde_json::json!({
+            "preset": "custom", "latency_ms": 300, "download_kbps": 1000
+        }))
+        .unwrap();
+        assert_eq!(params.conditions().unwrap(), NetworkConditions::custom(300.0, Some(1000.0), None));
+
+        let params: EmulateNetworkParams =
+            serde_json::from_value(serde_json::json!({ "preset": "custom", "latency_ms": -5 })).unwrap();
+        assert!(params.conditions().is_err());
+        assert!(serde_json::from_value::<EmulateNetworkParams>(serde_json::json!({ "preset": "5g" })).is_err());
+    }
+}
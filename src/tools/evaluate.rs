@@ -26,7 +26,6 @@ impl Tool for EvaluateTool {
 
     fn execute_typed(&self, params: EvaluateParams, context: &mut ToolContext) -> Result<ToolResult> {
         let result = context
-            .session
             .tab()?
             .evaluate(&params.code, params.await_promise)
             .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
@@ -0,0 +1,365 @@
+JSON.stringify(
+  (function () {
+    const config = __FORMS_CONFIG__;
+
+    function normalize(text) {
+      return (text || "").replace(/\s+/g, " ").trim();
+    }
+
+    __HELPERS__
+
+    function labelFor(element) {
+      const ariaLabel = element.getAttribute("aria-label");
+      if (ariaLabel) return normalize(ariaLabel);
+
+      const labelledBy = element.getAttribute("aria-labelledby");
+      if (labelledBy) {
+        const text = labelledBy
+          .split(/\s+/)
+          .map((id) => document.getElementById(id))
+          .filter((el) => el)
+          .map((el) => el.textContent)
+          .join(" ");
+        if (normalize(text)) return normalize(text);
+      }
+
+      if (element.labels && element.labels.length > 0) {
+        return normalize(element.labels[0].textContent);
+      }
+
+      const placeholder = element.getAttribute("placeholder");
+      if (placeholder) return normalize(placeholder);
+
+      return normalize(element.getAttribute("title") || "");
+    }
+
+    function fieldType(element) {
+      if (element.tagName === "SELECT") {
+        return element.multiple ? "select-multiple" : "select";
+      }
+      if (element.tagName === "TEXTAREA") return "textarea";
+      return (element.type || "text").toLowerCase();
+    }
+
+    function isFillable(element) {
+      if (!["INPUT", "SELECT", "TEXTAREA"].includes(element.tagName)) {
+        return false;
+      }
+      const type = fieldType(element);
+      return !["hidden", "submit", "button", "reset", "image", "file"].includes(
+        type,
+      );
+    }
+
+    function formElements(form) {
+      const elements = form
+        ? Array.from(form.elements)
+        : Array.from(document.querySelectorAll("input, select, textarea")).filter(
+            (el) => !el.form,
+          );
+      return elements.filter(isFillable);
+    }
+
+    function forms() {
+      const result = Array.from(document.forms).map((form) => ({
+        form,
+        elements: formElements(form),
+      }));
+      const orphans = formElements(null);
+      if (orphans.length > 0) {
+        result.push({ form: null, elements: orphans });
+      }
+      return result;
+    }
+
+    function describeField(element, radios) {
+      const type = fieldType(element);
+      const field = {
+        label: labelFor(element),
+        name: element.name || "",
+        type: type,
+        required: element.required,
+        disabled: element.disabled,
+        selector: buildSelector(element),
+      };
+
+      if (type === "radio") {
+        const group = radios.filter((r) => r.name === element.name);
+        const checked = group.find((r) => r.checked);
+        field.value = checked ? checked.value : "";
+        field.options = group.map((r) => ({
+          value: r.value,
+          text: labelFor(r),
+          selected: r.checked,
+        }));
+        if (!field.label) {
+          const fieldset = element.closest("fieldset");
+          const legend = fieldset && fieldset.querySelector("legend");
+          if (legend) field.label = normalize(legend.textContent);
+        }
+      } else if (type === "checkbox") {
+        field.value = element.value;
+        field.checked = element.checked;
+      } else if (element.tagName === "SELECT") {
+        field.value = element.multiple
+          ? Array.from(element.selectedOptions)
+              .map((o) => o.value)
+              .join(",")
+          : element.value;
+        field.options = Array.from(element.options).map((o) => ({
+          value: o.value,
+          text: normalize(o.text),
+          selected: o.selected,
+        }));
+      } else {
+        field.value = element.value;
+      }
+
+      return field;
+    }
+
+    function listForms() {
+      return forms().map((entry, formIndex) => {
+        const radios = entry.elements.filter((el) => fieldType(el) === "radio");
+        const seenRadioGroups = new Set();
+        const fields = [];
+
+        for (const element of entry.elements) {
+          if (fieldType(element) === "radio") {
+            if (seenRadioGroups.has(element.name)) continue;
+            seenRadioGroups.add(element.name);
+          }
+          fields.push(describeField(element, radios));
+        }
+
+        return {
+          form_index: formIndex,
+          selector: entry.form ? buildSelector(entry.form) : null,
+          name: entry.form
+            ? entry.form.getAttribute("name") || entry.form.id || ""
+            : "",
+          action: entry.form ? entry.form.getAttribute("action") || "" : "",
+          method: entry.form
+            ? (entry.form.getAttribute("method") || "get").toLowerCase()
+            : "",
+          fields: fields,
+        };
+      });
+    }
+
+    function fireEvents(element) {
+      element.dispatchEvent(new Event("input", { bubbles: true }));
+      element.dispatchEvent(new Event("change", { bubbles: true }));
+      element.dispatchEvent(new Event("blur", { bubbles: false }));
+    }
+
+    function matches(candidate, key) {
+      return normalize(candidate).toLowerCase() === normalize(key).toLowerCase();
+    }
+
+    function findField(elements, key) {
+      return (
+        elements.find((el) => el.name === key) ||
+        elements.find((el) => el.id === key) ||
+        elements.find((el) => matches(labelFor(el), key)) ||
+        elements.find((el) => {
+          const fieldset = el.closest("fieldset");
+          const legend = fieldset && fieldset.querySelector("legend");
+          return legend && matches(legend.textContent, key);
+        })
+      );
+    }
+
+    function toBool(value) {
+      if (typeof value === "string") {
+        return !["", "false", "0", "off", "no"].includes(
+          value.toLowerCase(),
+        );
+      }
+      return Boolean(value);
+    }
+
+    function radioOption(elements, element, value) {
+      const group = elements.filter(
+        (el) => fieldType(el) === "radio" && el.name === element.name,
+      );
+      return group.find(
+        (r) => r.value === String(value) || matches(labelFor(r), String(value)),
+      );
+    }
+
+    // The pointer and keyboard work human emulation does for a field before
+    // it is filled: clicking a checkbox or radio, typing text, or resting on
+    // a select. Returns null when there is nothing to do.
+    function planField(elements, element, value) {
+      const type = fieldType(element);
+
+      if (type === "checkbox") {
+        if (element.checked === toBool(value)) return null;
+        return { kind: "click", selector: buildSelector(element) };
+      }
+
+      if (type === "radio") {
+        const target = radioOption(elements, element, value);
+        if (!target || target.checked) return null;
+        return { kind: "click", selector: buildSelector(target) };
+      }
+
+      if (element.tagName === "SELECT") {
+        return { kind: "hover", selector: buildSelector(element) };
+      }
+
+      const text = value === null ? "" : String(value);
+      if (element.value === text) return null;
+      return {
+        kind: "type",
+        selector: buildSelector(element),
+        text: text,
+        length: element.value.length,
+      };
+    }
+
+    function fillField(elements, element, value) {
+      const type = fieldType(element);
+
+      if (type === "checkbox") {
+        const wanted = toBool(value);
+        if (element.checked !== wanted) element.click();
+        return { success: element.checked === wanted, value: element.checked };
+      }
+
+      if (type === "radio") {
+        const target = radioOption(elements, element, value);
+        if (!target) {
+          return { success: false, error: "No radio option matches " + value };
+        }
+        if (!target.checked) target.click();
+        return { success: target.checked, value: target.value };
+      }
+
+      if (element.tagName === "SELECT") {
+        const wanted = (Array.isArray(value) ? value : [value]).map(String);
+        const options = Array.from(element.options);
+        const chosen = wanted.map((w) =>
+          options.find((o) => o.value === w || matches(o.text, w)),
+        );
+        if (chosen.some((o) => !o)) {
+          return { success: false, error: "No option matches " + wanted.join(", ") };
+        }
+        if (element.multiple) {
+          options.forEach((o) => (o.selected = chosen.includes(o)));
+        } else {
+          setNativeValue(element, chosen[0].value);
+        }
+        fireEvents(element);
+        const selected = Array.from(element.selectedOptions).map((o) => o.value);
+        return {
+          success: chosen.every((o) => o.selected),
+          value: element.multiple ? selected : element.value,
+        };
+      }
+
+      const text = value === null ? "" : String(value);
+      element.focus();
+      // Keep a value that was already typed in, without firing its events again
+      if (element.value !== text) {
+        setNativeValue(element, text);
+        fireEvents(element);
+      }
+      return { success: element.value === text, value: element.value };
+    }
+
+    function fillForm() {
+      const all = forms();
+      let entry;
+      if (config.formSelector) {
+        const form = document.querySelector(config.formSelector);
+        entry = all.find((e) => e.form === form);
+        if (!entry) {
+          return { success: false, error: "Form not found: " + config.formSelector };
+        }
+      } else if (typeof config.formIndex === "number") {
+        entry = all[config.formIndex];
+        if (!entry) {
+          return { success: false, error: "No form with index " + config.formIndex };
+        }
+      }
+
+      const results = [];
+      const actions = [];
+      for (const [key, value] of config.values) {
+        const scopes = entry ? [entry] : all;
+        let scope = null;
+        let element = null;
+        for (const candidate of scopes) {
+          element = findField(candidate.elements, key);
+          if (element) {
+            scope = candidate;
+            break;
+          }
+        }
+
+        if (!element) {
+          results.push({ field: key, success: false, error: "Field not found" });
+          continue;
+        }
+        if (element.disabled) {
+          results.push({
+            field: key,
+            selector: buildSelector(element),
+            success: false,
+            error: "Field is disabled",
+          });
+          continue;
+        }
+
+        if (config.action === "plan") {
+          const action = planField(scope.elements, element, value);
+          if (action) actions.push(action);
+          if (!entry) entry = scope;
+          continue;
+        }
+
+        try {
+          const outcome = fillField(scope.elements, element, value);
+          results.push(
+            Object.assign(
+              { field: key, selector: buildSelector(element) },
+              outcome,
+            ),
+          );
+        } catch (e) {
+          results.push({ field: key, success: false, error: e.toString() });
+        }
+        if (!entry) entry = scope;
+      }
+
+      if (config.action === "plan") {
+        return { success: true, actions: actions };
+      }
+
+      let submitted = false;
+      let warning = null;
+      if (config.submit) {
+        const form = entry && entry.form;
+        if (!form) {
+          // Keep the field results: the values were still filled
+          warning = "Fields are not inside a form, so nothing was submitted";
+        } else if (typeof form.requestSubmit === "function") {
+          form.requestSubmit();
+          submitted = true;
+        } else {
+          form.submit();
+          submitted = true;
+        }
+      }
+
+      return { success: true, fields: results, submitted: submitted, warning: warning };
+    }
+
+    if (config.action === "fill" || config.action === "plan") {
+      return fillForm();
+    }
+    return { success: true, forms: listForms() };
+  })(),
+);
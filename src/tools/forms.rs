@@ -0,0 +1,276 @@
+use crate::{browser::MouseButton,
+            dom::with_helpers,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, input::clear_field, mouse::element_center}};
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const FORMS_JS: &str = include_str!("forms.js");
+
+/// Parameters for the get_forms tool (no parameters needed)
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetFormsParams {}
+
+/// Parameters for the fill_form tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FillFormParams {
+    /// Map of field label, name or id to the value to set.
+    /// Use booleans for checkboxes, option values or labels for selects and radios,
+    /// and arrays for multi-selects.
+    pub values: IndexMap<String, Value>,
+
+    /// CSS selector of the form to fill (optional, use either this or form_index)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_selector: Option<String>,
+
+    /// Form index as returned by get_forms (optional, use either this or form_selector)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_index: Option<usize>,
+
+    /// Submit the form after filling (default: false)
+    #[serde(default)]
+    pub submit: bool,
+}
+
+/// A form detected on the page
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FormInfo {
+    /// Position of the form on the page (fields outside any form are grouped last)
+    pub form_index: usize,
+    /// CSS selector of the form element (null for fields outside any form)
+    pub selector: Option<String>,
+    pub name: String,
+    pub action: String,
+    pub method: String,
+    pub fields: Vec<FormField>,
+}
+
+/// A fillable field within a form
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FormField {
+    pub label: String,
+    pub name: String,
+    /// Input type (text, email, checkbox, radio, select, select-multiple, textarea, date, ...)
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub required: bool,
+    pub disabled: bool,
+    /// Current value (comma-separated for multi-selects)
+    #[serde(default)]
+    pub value: String,
+    /// Checked state for checkboxes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+    /// Available options for selects and radio groups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<FieldOption>>,
+    pub selector: String,
+    /// Element index from the DOM tree, if the field is indexed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
+
+/// An option of a select or radio group
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FieldOption {
+    pub value: String,
+    pub text: String,
+    pub selected: bool,
+}
+
+/// Evaluate forms.js with the given config and parse the JSON it returns
+fn run_forms_script(context: &ToolContext, tool: &str, config: Value) -> Result<Value> {
+    let forms_js = with_helpers(FORMS_JS).replace("__FORMS_CONFIG__", &config.to_string());
+
+    let result = context
+        .tab()?
+        .evaluate(&forms_js, false)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: tool.to_string(), reason: e.to_string() })?;
+
+    let result_json: Value = if let Some(Value::String(json_str)) = result.value {
+        serde_json::from_str(&json_str)
+            .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+    } else {
+        result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+    };
+
+    if result_json["success"].as_bool() == Some(true) {
+        Ok(result_json)
+    } else {
+        Err(BrowserError::ToolExecutionFailed {
+            tool: tool.to_string(),
+            reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+        })
+    }
+}
+
+/// Tool for listing the forms on the page and their fields
+#[derive(Default)]
+pub struct GetFormsTool;
+
+impl Tool for GetFormsTool {
+    type Params = GetFormsParams;
+
+    fn name(&self) -> &str {
+        "get_forms"
+    }
+
+    fn execute_typed(&self, _params: GetFormsParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let result_json = run_forms_script(context, "get_forms", serde_json::json!({ "action": "list" }))?;
+
+        let mut forms: Vec<FormInfo> = serde_json::from_value(result_json["forms"].clone()).map_err(|e| {
+            BrowserError::ToolExecutionFailed { tool: "get_forms".to_string(), reason: format!("Invalid forms: {}", e) }
+        })?;
+
+        // Map each field back to its DOM tree index so agents can interact with it directly
+        let dom = context.get_dom()?;
+        for field in forms.iter_mut().flat_map(|form| form.fields.iter_mut()) {
+            field.index = dom.selectors.iter().position(|s| !s.is_empty() && *s == field.selector);
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "forms": forms,
+            "count": forms.len()
+        })))
+    }
+}
+
+/// Tool for filling several form fields in one call
+#[derive(Default)]
+pub struct FillFormTool;
+
+impl Tool for FillFormTool {
+    type Params = FillFormParams;
+
+    fn name(&self) -> &str {
+        "fill_form"
+    }
+
+    fn execute_typed(&self, params: FillFormParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if params.form_selector.is_some() && params.form_index.is_some() {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "fill_form".to_string(),
+                reason: "Cannot specify both 'form_selector' and 'form_index'. Use one or the other.".to_string(),
+            });
+        }
+
+        if params.values.is_empty() {
+            return Err(BrowserError::InvalidArgument("'values' must contain at least one field".to_string()));
+        }
+
+        // Pass values as [key, value] pairs: a JSON object would lose the caller's field order
+        let values: Vec<(&String, &Value)> = params.values.iter().collect();
+        let mut config = serde_json::json!({
+            "action": "plan",
+            "values": values,
+            "formSelector": params.form_selector,
+            "formIndex": params.form_index,
+            "submit": params.submit,
+        });
+
+        // With human emulation, click and type into the fields first; the fill below then only
+        // picks select options, checks the result and submits
+        if context.session.is_human() {
+            let plan = run_forms_script(context, "fill_form", config.clone())?;
+            for action in plan["actions"].as_array().into_iter().flatten() {
+                human_fill(action, context)?;
+            }
+        }
+
+        config["action"] = "fill".into();
+        let result_json = run_forms_script(context, "fill_form", config)?;
+
+        let fields = result_json["fields"].as_array().cloned().unwrap_or_default();
+        let filled = fields.iter().filter(|f| f["success"].as_bool() == Some(true)).count();
+
+        // Field values changed, so any cached DOM tree is stale
+        context.dom_tree = None;
+
+        let mut result = serde_json::json!({
+            "fields": fields,
+            "filled": filled,
+            "failed": fields.len() - filled,
+            "submitted": result_json["submitted"].as_bool().unwrap_or(false)
+        });
+        if let Some(warning) = result_json["warning"].as_str() {
+            result["warning"] = warning.into();
+        }
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+/// Carry out one step of a fill_form plan with the emulated pointer and keyboard
+fn human_fill(action: &Value, context: &mut ToolContext) -> Result<()> {
+    let selector = action["selector"].as_str().unwrap_or_default();
+    let (x, y) = element_center("fill_form", selector, context)?;
+    let tab = context.tab()?;
+    match action["kind"].as_str() {
+        Some("click") => context.session.pointer_click(&tab, x, y, MouseButton::Left, 1),
+        Some("type") => {
+            context.session.pointer_click(&tab, x, y, MouseButton::Left, 1)?;
+            let length = action["length"].as_u64().unwrap_or(0) as usize;
+            if length > 0 {
+                clear_field(&tab, length);
+            }
+            context.session.type_text(&tab, action["text"].as_str().unwrap_or_default())
+        }
+        // Native dropdowns are drawn outside the page, so the pointer only rests on the select
+        _ => {
+            context.session.pointer_move(&tab, x, y, 1)?;
+            if let Some(dwell) = context.session.with_human(|human| human.hover_delay())? {
+                std::thread::sleep(dwell);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_form_params_preserve_order() {
+        // Parse from a string: serde_json::Map sorts keys, which would hide ordering bugs
+        let json = r#"{
+            "values": {
+                "Email": "jane@example.com",
+                "newsletter": true,
+                "Country": "Canada",
+                "interests": ["rust", "browsers"]
+            },
+            "submit": true
+        }"#;
+
+        let params: FillFormParams = serde_json::from_str(json).unwrap();
+        let keys: Vec<&String> = params.values.keys().collect();
+        assert_eq!(keys, vec!["Email", "newsletter", "Country", "interests"]);
+        assert_eq!(params.values["newsletter"], Value::Bool(true));
+        assert!(params.submit);
+        assert_eq!(params.form_index, None);
+    }
+
+    #[test]
+    fn test_form_field_deserialize() {
+        let json = serde_json::json!({
+            "label": "Country",
+            "name": "country",
+            "type": "select",
+            "required": true,
+            "disabled": false,
+            "value": "us",
+            "options": [
+                {"value": "us", "text": "United States", "selected": true},
+                {"value": "ca", "text": "Canada", "selected": false}
+            ],
+            "selector": "#country"
+        });
+
+        let field: FormField = serde_json::from_value(json).unwrap();
+        assert_eq!(field.field_type, "select");
+        assert_eq!(field.options.unwrap().len(), 2);
+        assert_eq!(field.index, None);
+    }
+}
@@ -25,7 +25,7 @@ impl Tool for GoBackTool {
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "go_back".to_string(), reason: e.to_string() })?;
 
         // Get current URL after going back
-        let current_url = context.session.tab()?.get_url();
+        let current_url = context.tab()?.get_url();
 
         Ok(ToolResult::success_with(serde_json::json!({
             "message": "Navigated back in history",
@@ -1,5 +1,5 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+            tools::{Tool, ToolContext, ToolResult, mouse::element_center}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -58,14 +58,21 @@ impl Tool for HoverTool {
             unreachable!("Validation above ensures one field is Some")
         };
 
-        // Find the element (to verify it exists)
+        // With human emulation the real pointer travels to the element and rests on it
+        if context.session.is_human() {
+            let (x, y) = element_center("hover", &css_selector, context)?;
+            let tab = context.tab()?;
+            context.session.pointer_move(&tab, x, y, 1)?;
+            if let Some(dwell) = context.session.with_human(|human| human.hover_delay())? {
+                std::thread::sleep(dwell);
+            }
+        }
 
         // Scroll into view if needed, then hover
         let selector_json = serde_json::to_string(&css_selector).expect("serializing CSS selector never fails");
         let hover_js = HOVER_JS.replace("__SELECTOR__", &selector_json);
 
         let result = context
-            .session
             .tab()?
             .evaluate(&hover_js, false)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "hover".to_string(), reason: e.to_string() })?;
@@ -1,5 +1,8 @@
-use crate::{error::{BrowserError, Result},
+use crate::{browser::MouseButton,
+            dom::with_helpers,
+            error::{BrowserError, Result},
             tools::{Tool, ToolContext, ToolResult,
+                    mouse::element_center,
                     snapshot::{RenderMode, render_aria_tree}}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -17,11 +20,30 @@ pub struct InputParams {
     /// Text to type into the element
     pub text: String,
 
-    /// Clear existing content first (default: false)
+    /// Clear existing content first (default: false, only used in "type" mode)
     #[serde(default)]
     pub clear: bool,
+
+    /// How the value is entered (default: "type")
+    #[serde(default)]
+    pub mode: InputMode,
 }
 
+/// How the input tool enters text into the element
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InputMode {
+    /// Type the text key by key
+    #[default]
+    Type,
+    /// Set the value through the native setter and fire input/change/blur events.
+    /// Use this for React/Vue controlled inputs, sliders, date/time/color inputs and
+    /// contenteditable rich-text editors.
+    SetValue,
+}
+
+const SET_VALUE_JS: &str = include_str!("set_value.js");
+
 #[derive(Default)]
 pub struct InputTool;
 
@@ -63,21 +85,32 @@ impl Tool for InputTool {
             unreachable!("Validation above ensures one field is Some")
         };
 
-        let tab = context.session.tab()?;
-        let element = context.session.find_element(&tab, &css_selector)?;
+        if params.mode == InputMode::SetValue {
+            return set_value(&css_selector, &params.text, context);
+        }
 
-        if params.clear {
-            element.click().ok(); // Focus
-            // Clear with Ctrl+A and Delete
-            tab.press_key("End").ok();
-            for _ in 0..params.text.len() + 100 {
-                tab.press_key("Backspace").ok();
+        if context.session.is_human() {
+            // Click into the field like a person would, then type key by key
+            let (x, y) = element_center("input", &css_selector, context)?;
+            let tab = context.tab()?;
+            context.session.pointer_click(&tab, x, y, MouseButton::Left, 1)?;
+            if params.clear {
+                clear_field(&tab, params.text.len());
             }
-        }
+            context.session.type_text(&tab, &params.text)?;
+        } else {
+            let tab = context.tab()?;
+            let element = context.session.find_element(&tab, &css_selector)?;
 
-        element
-            .type_into(&params.text)
-            .map_err(|e| BrowserError::ToolExecutionFailed { tool: "input".to_string(), reason: e.to_string() })?;
+            if params.clear {
+                element.click().ok(); // Focus
+                clear_field(&tab, params.text.len());
+            }
+
+            element
+                .type_into(&params.text)
+                .map_err(|e| BrowserError::ToolExecutionFailed { tool: "input".to_string(), reason: e.to_string() })?;
+        }
 
         let snapshot = {
             let dom = context.get_dom()?;
@@ -91,3 +124,86 @@ impl Tool for InputTool {
         Ok(ToolResult::success_with(result_json))
     }
 }
+
+/// Clear the focused field with End and Backspace
+pub(crate) fn clear_field(tab: &headless_chrome::Tab, text_len: usize) {
+    tab.press_key("End").ok();
+    for _ in 0..text_len + 100 {
+        tab.press_key("Backspace").ok();
+    }
+}
+
+/// Set an element's value the way a framework-controlled input expects, then verify it stuck
+fn set_value(css_selector: &str, value: &str, context: &mut ToolContext) -> Result<ToolResult> {
+    let config = serde_json::json!({
+        "selector": css_selector,
+        "value": value,
+    });
+    let set_value_js = with_helpers(SET_VALUE_JS).replace("__SET_VALUE_CONFIG__", &config.to_string());
+
+    let result = context
+        .tab()?
+        .evaluate(&set_value_js, true)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "input".to_string(), reason: e.to_string() })?;
+
+    // Parse the JSON string returned by JavaScript
+    let result_json: serde_json::Value = if let Some(serde_json::Value::String(json_str)) = result.value {
+        serde_json::from_str(&json_str)
+            .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+    } else {
+        result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+    };
+
+    if result_json["success"].as_bool() != Some(true) {
+        return Err(BrowserError::ToolExecutionFailed {
+            tool: "input".to_string(),
+            reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+        });
+    }
+
+    // The value changed, so re-extract instead of rendering a stale cached tree
+    context.dom_tree = None;
+    let snapshot = {
+        let dom = context.get_dom()?;
+        render_aria_tree(&dom.root, RenderMode::Ai, None)
+    };
+
+    Ok(ToolResult::success_with(serde_json::json!({
+        "selector": css_selector,
+        "mode": "set_value",
+        "kind": result_json["kind"],
+        "value": result_json["value"],
+        "verified": result_json["verified"].as_bool().unwrap_or(false),
+        "snapshot": snapshot
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_params_default_mode() {
+        let json = serde_json::json!({
+            "index": 3,
+            "text": "hello"
+        });
+
+        let params: InputParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.mode, InputMode::Type);
+        assert!(!params.clear);
+    }
+
+    #[test]
+    fn test_input_params_set_value_mode() {
+        let json = serde_json::json!({
+            "selector": "#volume",
+            "text": "75",
+            "mode": "set_value"
+        });
+
+        let params: InputParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.mode, InputMode::SetValue);
+        assert_eq!(params.selector, Some("#volume".to_string()));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{browser::keyboard::{self, Chord},
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Parameters for the keyboard tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct KeyboardParams {
+    /// Key chords to press in order, e.g. ["Control+A", "Backspace"], ["Shift+Tab"] or ["Meta+Enter"].
+    /// Modifiers: Control/Ctrl, Shift, Alt/Option, Meta/Cmd, ControlOrMeta (Meta on macOS)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keys: Vec<String>,
+
+    /// Text to type into the focused element (use either keys or text)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Delay after each typed character, in milliseconds (default: none, or the human emulation cadence)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay_ms: Option<u64>,
+
+    /// Insert the text in one go without key events, like an IME commit; use for emoji and
+    /// non-Latin scripts (default: false)
+    #[serde(default)]
+    pub insert_text: bool,
+}
+
+/// Tool for key chords and typing into the focused element
+#[derive(Default)]
+pub struct KeyboardTool;
+
+impl Tool for KeyboardTool {
+    type Params = KeyboardParams;
+
+    fn name(&self) -> &str {
+        "keyboard"
+    }
+
+    fn execute_typed(&self, params: KeyboardParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+
+        match (params.keys.is_empty(), params.text) {
+            (false, Some(_)) => Err(BrowserError::InvalidArgument(
+                "Cannot specify both 'keys' and 'text'. Use one or the other.".to_string(),
+            )),
+            (true, None) => Err(BrowserError::InvalidArgument("Must specify either 'keys' or 'text'.".to_string())),
+            (false, None) => {
+                let chords = params.keys.iter().map(|k| Chord::parse(k)).collect::<Result<Vec<_>>>()?;
+                for chord in &chords {
+                    keyboard::press_chord(&tab, chord)?;
+                }
+                Ok(ToolResult::success_with(serde_json::json!({
+                    "keys": chords.iter().map(Chord::to_string).collect::<Vec<_>>(),
+                })))
+            }
+            (true, Some(text)) => {
+                if params.insert_text {
+                    keyboard::insert_text(&tab, &text)?;
+                } else if params.delay_ms.is_none() && context.session.is_human() {
+                    context.session.type_text(&tab, &text)?;
+                } else {
+                    keyboard::type_text(&tab, &text, Duration::from_millis(params.delay_ms.unwrap_or(0)))?;
+                }
+                Ok(ToolResult::success_with(serde_json::json!({
+                    "typed": text.chars().count(),
+                    "insert_text": params.insert_text,
+                })))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyboard_params() {
+        let params: KeyboardParams = serde_json::from_value(serde_json::json!({ "keys": ["Control+A"] })).unwrap();
+        assert_eq!(params.keys, vec!["Control+A"]);
+        assert!(params.text.is_none());
+        assert!(!params.insert_text);
+
+        let params: KeyboardParams =
+            serde_json::from_value(serde_json::json!({ "text": "héllo 👋", "insert_text": true })).unwrap();
+        assert_eq!(params.text.as_deref(), Some("héllo 👋"));
+        assert!(params.insert_text);
+    }
+}
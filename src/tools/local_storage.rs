@@ -65,7 +65,7 @@ impl Tool for GetLocalStorageTool {
 
     
 
-            let remote_object = context.session.tab()?.evaluate(&script, false)
+            let remote_object = context.tab()?.evaluate(&script, false)
 
                 .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to get local storage: {}", e)))?;
 
@@ -113,7 +113,7 @@ impl Tool for SetLocalStorageTool {
             params.value.replace("\"", "\\\"")
         );
 
-        context.session.tab()?.evaluate(&script, false)
+        context.tab()?.evaluate(&script, false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to set local storage: {}", e)))?;
 
         Ok(ToolResult::success(None))
@@ -133,7 +133,7 @@ impl Tool for RemoveLocalStorageTool {
     fn execute_typed(&self, params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
         let script = format!(r#"window.localStorage.removeItem("{}")"#, params.key.replace("\"", "\\\""));
 
-        context.session.tab()?.evaluate(&script, false)
+        context.tab()?.evaluate(&script, false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to remove local storage item: {}", e)))?;
 
         Ok(ToolResult::success(None))
@@ -151,7 +151,7 @@ impl Tool for ClearLocalStorageTool {
     }
 
     fn execute_typed(&self, _params: Self::Params, context: &mut ToolContext) -> Result<ToolResult> {
-        context.session.tab()?.evaluate("window.localStorage.clear()", false)
+        context.tab()?.evaluate("window.localStorage.clear()", false)
             .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to clear local storage: {}", e)))?;
 
         Ok(ToolResult::success(None))
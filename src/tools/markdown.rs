@@ -1,19 +1,49 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult, html_to_markdown::convert_html_to_markdown,
+            tools::{Tool, ToolContext, ToolResult,
+                    chunking::{ChunkUnit, chunk_markdown},
+                    html_to_markdown::convert_html_to_markdown,
+                    pdf_reader,
                     readability_script::READABILITY_SCRIPT}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for getting markdown content with pagination support
+///
+/// Pages are chunks split at heading, paragraph and code-fence boundaries; the result's `toc`
+/// lists every chunk's title so a specific section can be requested by page number.
+///
+/// The main content is found with Readability, falling back to `<main>` or `<body>` without
+/// navigation, headers and footers. With `selector` or `index`, only that region is converted.
+/// PDF documents are downloaded and returned as text with one page per PDF page.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GetMarkdownParams {
     /// Page number to extract (1-based index, default: 1)
     #[serde(default = "default_page")]
     pub page: usize,
 
-    /// Maximum characters per page (default: 100000)
+    /// Maximum size per page, measured in `unit` (default: 100000)
     #[serde(default = "default_page_size")]
     pub page_size: usize,
+
+    /// Unit for page_size: "chars" (default) or "tokens" (approximate)
+    #[serde(default)]
+    pub unit: ChunkUnit,
+
+    /// Return the whole document as a single page, ignoring page and page_size (default: false)
+    #[serde(default)]
+    pub single_page: bool,
+
+    /// Annotate links, buttons and inputs with their snapshot indices, e.g. `[Pricing](/pricing){#23}`
+    #[serde(default)]
+    pub with_indices: bool,
+
+    /// CSS selector of the region to convert (use either this or index, not both)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree of the region to convert (use either this or selector, not both)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
 }
 
 fn default_page() -> usize {
@@ -26,7 +56,15 @@ fn default_page_size() -> usize {
 
 impl Default for GetMarkdownParams {
     fn default() -> Self {
-        Self { page: default_page(), page_size: default_page_size() }
+        Self {
+            page: default_page(),
+            page_size: default_page_size(),
+            unit: ChunkUnit::default(),
+            single_page: false,
+            with_indices: false,
+            selector: None,
+            index: None,
+        }
     }
 }
 
@@ -46,21 +84,56 @@ impl Tool for GetMarkdownTool {
         // we add a small delay to let dynamic content load
         std::thread::sleep(std::time::Duration::from_millis(1000));
 
-        // Inject Readability.js script and the conversion script
+        // Chrome's PDF viewer has no readable DOM, so read the document itself
+        let tab = context.tab()?;
+        if pdf_reader::is_pdf(&tab)? {
+            return pdf_markdown(&params, &tab);
+        }
+
+        let region = match (params.selector, params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "get_markdown".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (Some(selector), None) => Some(selector),
+            (None, Some(index)) => {
+                let dom = context.get_dom()?;
+                let selector = dom
+                    .get_selector(index)
+                    .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+                Some(selector.clone())
+            }
+            (None, None) => None,
+        };
+
+        // Selectors of indexed elements, taken from the same DomTree the snapshot uses so the
+        // annotated indices can be passed straight to click/input
+        let refs: Vec<(usize, String)> = if params.with_indices {
+            let dom = context.get_dom()?;
+            dom.selectors
+                .iter()
+                .enumerate()
+                .filter(|(_, selector)| !selector.is_empty())
+                .map(|(index, selector)| (index, selector.clone()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Inject Readability.js script, the conversion config and the conversion script
         // Use 'var' instead of 'const' to allow redeclaration on subsequent calls
         // This prevents "identifier already declared" errors when calling get_markdown multiple times
         let js_code = format!(
-            "var READABILITY_SCRIPT = {};\n{}",
+            "var READABILITY_SCRIPT = {};\nvar MARKDOWN_CONFIG = {};\n{}",
             serde_json::to_string(READABILITY_SCRIPT).unwrap(),
+            serde_json::json!({ "refs": refs, "selector": region }),
             include_str!("convert_to_markdown.js")
         );
 
         // Execute the JavaScript to extract and convert content
-        let result = context
-            .session
-            .tab()?
-            .evaluate(&js_code, false)
-            .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        let result = tab.evaluate(&js_code, false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
 
         // Parse the result
         let result_value = result.value.ok_or_else(|| {
@@ -90,51 +163,40 @@ impl Tool for GetMarkdownTool {
             })?
         };
 
-        // Check if Readability failed
+        // Check if extraction failed (Readability failures fall back to <main>/<body> in the script)
         if extraction_result.readability_failed {
             return Err(BrowserError::ToolExecutionFailed {
                 tool: "get_markdown".to_string(),
-                reason: extraction_result.error.unwrap_or_else(|| "Readability extraction failed".to_string()),
+                reason: extraction_result.error.unwrap_or_else(|| "Content extraction failed".to_string()),
             });
         }
 
         // Convert the extracted HTML content to Markdown
         let full_markdown = convert_html_to_markdown(&extraction_result.content);
 
-        // Calculate pagination information
-        let total_pages =
-            if full_markdown.is_empty() { 1 } else { (full_markdown.len() + params.page_size - 1) / params.page_size };
+        // Split into chunks along headings, paragraphs and code fences
+        let max_size = if params.single_page { usize::MAX } else { params.page_size };
+        let chunks = chunk_markdown(&full_markdown, max_size, params.unit);
+        let total_pages = chunks.len().max(1);
 
         // Clamp page number to valid range
-        let current_page = params.page.clamp(1, total_pages.max(1));
-
-        // Calculate start and end indices for the requested page
-        let start_idx = (current_page - 1) * params.page_size;
-        let end_idx = (start_idx + params.page_size).min(full_markdown.len());
+        let current_page = params.page.clamp(1, total_pages);
 
-        // Extract the content for the current page
-        let mut page_content =
-            if start_idx < full_markdown.len() { full_markdown[start_idx..end_idx].to_string() } else { String::new() };
+        let mut page_content = chunks.get(current_page - 1).map(|c| c.content.clone()).unwrap_or_default();
 
         // Add title to the first page only
         if current_page == 1 && !extraction_result.title.is_empty() {
             page_content = format!("# {}\n\n{}", extraction_result.title, page_content);
         }
 
-        // Add pagination information if there are multiple pages
-        if total_pages > 1 {
-            let pagination_info = if current_page < total_pages {
-                format!(
-                    "\n\n---\n\n*Page {} of {}. There are {} more page(s) with additional content.*\n",
-                    current_page,
-                    total_pages,
-                    total_pages - current_page
-                )
-            } else {
-                format!("\n\n---\n\n*Page {} of {}. This is the last page.*\n", current_page, total_pages)
-            };
-            page_content.push_str(&pagination_info);
-        }
+        page_content.push_str(&pagination_info(current_page, total_pages));
+
+        // Table of contents so agents can jump to the page holding the section they need
+        let toc: Vec<serde_json::Value> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| serde_json::json!({ "page": i + 1, "title": chunk.title, "size": chunk.size }))
+            .collect();
 
         // Return the result with pagination metadata
         Ok(ToolResult::success_with(serde_json::json!({
@@ -144,6 +206,10 @@ impl Tool for GetMarkdownTool {
             "currentPage": current_page,
             "totalPages": total_pages,
             "hasMorePages": current_page < total_pages,
+            "toc": toc,
+            "unit": params.unit,
+            "strategy": extraction_result.strategy,
+            "indexed": extraction_result.indexed,
             "length": page_content.len(),
             "byline": extraction_result.byline,
             "excerpt": extraction_result.excerpt,
@@ -152,6 +218,65 @@ impl Tool for GetMarkdownTool {
     }
 }
 
+/// Footer telling the reader where this page sits (empty for single-page content)
+fn pagination_info(current_page: usize, total_pages: usize) -> String {
+    if total_pages <= 1 {
+        String::new()
+    } else if current_page < total_pages {
+        format!(
+            "\n\n---\n\n*Page {} of {}. There are {} more page(s) with additional content.*\n",
+            current_page,
+            total_pages,
+            total_pages - current_page
+        )
+    } else {
+        format!("\n\n---\n\n*Page {} of {}. This is the last page.*\n", current_page, total_pages)
+    }
+}
+
+/// Read a PDF document shown in the tab, one markdown page per PDF page
+fn pdf_markdown(params: &GetMarkdownParams, tab: &headless_chrome::Tab) -> Result<ToolResult> {
+    let bytes = pdf_reader::fetch_pdf(tab)?;
+    let mut pages = pdf_reader::extract_pages(&bytes)?;
+    if params.single_page {
+        pages = vec![pages.join("\n\n")];
+    }
+    let total_pages = pages.len().max(1);
+    let current_page = params.page.clamp(1, total_pages);
+
+    let title = tab.get_title().unwrap_or_default();
+    let mut page_content = pages.get(current_page - 1).cloned().unwrap_or_default();
+    if current_page == 1 && !title.is_empty() {
+        page_content = format!("# {}\n\n{}", title, page_content);
+    }
+    page_content.push_str(&pagination_info(current_page, total_pages));
+
+    let toc: Vec<serde_json::Value> = pages
+        .iter()
+        .enumerate()
+        .map(|(i, page)| {
+            serde_json::json!({ "page": i + 1, "title": format!("Page {}", i + 1), "size": params.unit.measure(page) })
+        })
+        .collect();
+
+    Ok(ToolResult::success_with(serde_json::json!({
+        "markdown": page_content,
+        "title": title,
+        "url": tab.get_url(),
+        "currentPage": current_page,
+        "totalPages": total_pages,
+        "hasMorePages": current_page < total_pages,
+        "toc": toc,
+        "unit": params.unit,
+        "strategy": "pdf",
+        "indexed": 0,
+        "length": page_content.len(),
+        "byline": "",
+        "excerpt": "",
+        "siteName": "",
+    })))
+}
+
 /// Structure for extraction result returned from JavaScript
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -178,4 +303,9 @@ struct ExtractionResult {
     readability_failed: bool,
     #[serde(default)]
     error: Option<String>,
+    #[serde(default)]
+    indexed: usize,
+    /// How the content was found: "readability", "main", "body" or "region"
+    #[serde(default)]
+    strategy: String,
 }
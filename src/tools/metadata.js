@@ -0,0 +1,211 @@
+JSON.stringify(
+  (function () {
+    function normalize(text) {
+      return (text || "").replace(/\s+/g, " ").trim();
+    }
+
+    function metaContent(selector) {
+      const element = document.querySelector(selector);
+      return element ? element.getAttribute("content") : null;
+    }
+
+    // <script type="application/ld+json"> blocks; @graph containers are flattened
+    function jsonLd() {
+      const items = [];
+      const errors = [];
+      document
+        .querySelectorAll('script[type="application/ld+json"]')
+        .forEach((script, i) => {
+          try {
+            const data = JSON.parse(script.textContent);
+            const blocks = Array.isArray(data) ? data : [data];
+            for (const block of blocks) {
+              if (block && Array.isArray(block["@graph"])) {
+                items.push(...block["@graph"]);
+              } else {
+                items.push(block);
+              }
+            }
+          } catch (e) {
+            errors.push({ block: i, error: e.message });
+          }
+        });
+      return { items, errors };
+    }
+
+    // og:*, article:*, product:* and similar properties; repeated keys become arrays
+    function metaGroup(attribute, prefixes) {
+      const result = {};
+      document.querySelectorAll("meta[" + attribute + "]").forEach((meta) => {
+        const key = meta.getAttribute(attribute);
+        if (!prefixes.some((p) => key.startsWith(p))) return;
+        const value = meta.getAttribute("content");
+        if (value === null) return;
+        if (key in result) {
+          result[key] = [].concat(result[key], value);
+        } else {
+          result[key] = value;
+        }
+      });
+      return result;
+    }
+
+    function propValue(element) {
+      if (element.hasAttribute("content")) {
+        return element.getAttribute("content");
+      }
+      switch (element.tagName) {
+        case "META":
+          return element.getAttribute("content");
+        case "A":
+        case "AREA":
+        case "LINK":
+          return element.href;
+        case "IMG":
+        case "AUDIO":
+        case "VIDEO":
+        case "SOURCE":
+        case "IFRAME":
+        case "EMBED":
+          return element.src;
+        case "OBJECT":
+          return element.data;
+        case "DATA":
+        case "METER":
+          return element.value;
+        case "TIME":
+          return element.getAttribute("datetime") || normalize(element.textContent);
+        default:
+          return normalize(element.textContent);
+      }
+    }
+
+    function addProp(props, name, value) {
+      if (name in props) {
+        props[name] = [].concat(props[name], [value]);
+      } else {
+        props[name] = value;
+      }
+    }
+
+    // schema.org microdata (itemscope/itemprop)
+    function microdataItem(scope) {
+      const item = {};
+      const type = scope.getAttribute("itemtype");
+      if (type) item.type = type.split(/\s+/);
+      if (scope.getAttribute("itemid")) item.id = scope.getAttribute("itemid");
+      item.properties = {};
+
+      const visit = (element) => {
+        for (const child of element.children) {
+          const prop = child.getAttribute("itemprop");
+          if (prop) {
+            const value = child.hasAttribute("itemscope")
+              ? microdataItem(child)
+              : propValue(child);
+            prop
+              .split(/\s+/)
+              .forEach((name) => addProp(item.properties, name, value));
+          }
+          // Nested scopes own their descendants
+          if (!child.hasAttribute("itemscope")) visit(child);
+        }
+      };
+      visit(scope);
+      return item;
+    }
+
+    function microdata() {
+      return Array.from(document.querySelectorAll("[itemscope]"))
+        .filter((el) => !el.hasAttribute("itemprop"))
+        .map(microdataItem);
+    }
+
+    // RDFa Lite (typeof/property)
+    function rdfaItem(scope) {
+      const item = { type: scope.getAttribute("typeof").split(/\s+/) };
+      const vocab = scope.closest("[vocab]");
+      if (vocab) item.vocab = vocab.getAttribute("vocab");
+      if (scope.getAttribute("resource")) {
+        item.id = scope.getAttribute("resource");
+      }
+      item.properties = {};
+
+      const visit = (element) => {
+        for (const child of element.children) {
+          const prop = child.getAttribute("property");
+          if (prop) {
+            const value = child.hasAttribute("typeof")
+              ? rdfaItem(child)
+              : child.getAttribute("href")
+                ? child.href
+                : child.getAttribute("src")
+                  ? child.src
+                  : propValue(child);
+            prop
+              .split(/\s+/)
+              .forEach((name) => addProp(item.properties, name, value));
+          }
+          if (!child.hasAttribute("typeof")) visit(child);
+        }
+      };
+      visit(scope);
+      return item;
+    }
+
+    function rdfa() {
+      return Array.from(document.querySelectorAll("[typeof]"))
+        .filter((el) => !el.hasAttribute("property"))
+        .map(rdfaItem);
+    }
+
+    function links(rel) {
+      return Array.from(document.querySelectorAll("link[rel]")).filter((link) =>
+        link.rel.toLowerCase().split(/\s+/).includes(rel),
+      );
+    }
+
+    const canonical = links("canonical")[0];
+    const ld = jsonLd();
+
+    return {
+      success: true,
+      url: location.href,
+      title: document.title,
+      description: metaContent('meta[name="description"]'),
+      language: document.documentElement.getAttribute("lang"),
+      canonical: canonical ? canonical.href : null,
+      robots: metaContent('meta[name="robots"]'),
+      hreflang: links("alternate")
+        .filter((link) => link.hreflang)
+        .map((link) => ({ hreflang: link.hreflang, href: link.href })),
+      feeds: links("alternate")
+        .filter((link) =>
+          /(rss|atom|feed)\+xml|application\/feed\+json/i.test(link.type),
+        )
+        .map((link) => ({
+          type: link.type,
+          title: link.title || null,
+          href: link.href,
+        })),
+      open_graph: metaGroup("property", [
+        "og:",
+        "article:",
+        "product:",
+        "book:",
+        "profile:",
+        "music:",
+        "video:",
+        "fb:",
+      ]),
+      twitter: Object.assign(
+        metaGroup("property", ["twitter:"]),
+        metaGroup("name", ["twitter:"]),
+      ),
+      json_ld: ld.items,
+      json_ld_errors: ld.errors,
+      microdata: microdata(),
+      rdfa: rdfa(),
+    };
+  })(),
+);
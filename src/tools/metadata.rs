@@ -0,0 +1,92 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const METADATA_JS: &str = include_str!("metadata.js");
+
+/// Parameters for the get_metadata tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct GetMetadataParams {
+    /// Only return JSON-LD items of this schema.org type, e.g. "Product" or "Recipe" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_ld_type: Option<String>,
+}
+
+/// Whether a JSON-LD item's `@type` (a string or an array of strings) matches `wanted`.
+///
+/// Full IRIs such as "https://schema.org/Product" match their short name.
+pub fn json_ld_type_matches(item: &Value, wanted: &str) -> bool {
+    let matches = |t: &Value| {
+        t.as_str().is_some_and(|t| t == wanted || t.rsplit(['/', '#']).next() == Some(wanted))
+    };
+    match &item["@type"] {
+        Value::Array(types) => types.iter().any(matches),
+        t => matches(t),
+    }
+}
+
+/// Tool for reading a page's structured metadata (JSON-LD, OpenGraph, Twitter cards, microdata, RDFa, links)
+#[derive(Default)]
+pub struct GetMetadataTool;
+
+impl Tool for GetMetadataTool {
+    type Params = GetMetadataParams;
+
+    fn name(&self) -> &str {
+        "get_metadata"
+    }
+
+    fn execute_typed(&self, params: GetMetadataParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let result = context.tab()?.evaluate(METADATA_JS, false).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "get_metadata".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let mut result_json: Value = if let Some(Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "get_metadata".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
+        if let Some(wanted) = &params.json_ld_type
+            && let Value::Array(items) = &mut result_json["json_ld"]
+        {
+            items.retain(|item| json_ld_type_matches(item, wanted));
+        }
+
+        if let Value::Object(map) = &mut result_json {
+            map.remove("success");
+        }
+
+        Ok(ToolResult::success_with(result_json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_ld_type_matches() {
+        let product = serde_json::json!({ "@type": "Product", "name": "Widget" });
+        let multi = serde_json::json!({ "@type": ["Article", "NewsArticle"] });
+        let iri = serde_json::json!({ "@type": "https://schema.org/Recipe" });
+        let untyped = serde_json::json!({ "name": "x" });
+
+        assert!(json_ld_type_matches(&product, "Product"));
+        assert!(!json_ld_type_matches(&product, "Offer"));
+        assert!(json_ld_type_matches(&multi, "NewsArticle"));
+        assert!(json_ld_type_matches(&iri, "Recipe"));
+        assert!(!json_ld_type_matches(&untyped, "Product"));
+    }
+}
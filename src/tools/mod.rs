@@ -3,31 +3,47 @@
 //! This module provides a framework for browser automation tools and
 //! includes implementations of common browser operations.
 
+pub mod chunking;
 pub mod click;
 pub mod close;
 pub mod close_tab;
+pub mod compare_screenshot;
 pub mod cookies;
 pub mod debug;
+pub mod element_at_point;
+pub mod emulate;
 pub mod evaluate;
 pub mod extract;
+pub mod forms;
 pub mod go_back;
 pub mod go_forward;
 pub mod hover;
 pub mod html_to_markdown;
 pub mod input;
+pub mod keyboard;
 pub mod local_storage;
 pub mod markdown;
+pub mod metadata;
+pub mod mouse;
 pub mod navigate;
 pub mod new_tab;
+pub mod pdf;
+pub mod pdf_reader;
 pub mod press_key;
 pub mod read_links;
 pub mod readability_script;
+pub mod recording;
+pub mod save_page;
 pub mod screenshot;
 pub mod scroll;
 pub mod select;
+pub mod set_checked;
 pub mod snapshot;
+pub mod structured;
 pub mod switch_tab;
 pub mod tab_list;
+pub mod table;
+pub mod touch;
 pub mod annotate;
 mod utils;
 pub mod wait;
@@ -36,32 +52,47 @@ pub mod wait;
 pub use click::ClickParams;
 pub use close::CloseParams;
 pub use close_tab::CloseTabParams;
+pub use compare_screenshot::{CompareScreenshotParams, DiffOptions, ImageDiff, compare_images};
 pub use cookies::{GetCookiesParams, SetCookiesParams};
 pub use debug::{GetConsoleLogsParams, GetNetworkErrorsParams};
+pub use element_at_point::{ClickAtMarkParams, ElementAtPointParams, MarkTarget};
+pub use emulate::{EmulateCpuParams, EmulateNetworkParams, EmulateParams, NetworkPreset, SetViewportParams};
 pub use evaluate::EvaluateParams;
 pub use extract::ExtractParams;
+pub use forms::{FillFormParams, GetFormsParams};
 pub use go_back::GoBackParams;
 pub use go_forward::GoForwardParams;
 pub use hover::HoverParams;
-pub use input::InputParams;
+pub use input::{InputMode, InputParams};
+pub use keyboard::KeyboardParams;
 pub use local_storage::{
     ClearLocalStorageParams, GetLocalStorageParams, RemoveLocalStorageParams, SetLocalStorageParams,
 };
 pub use markdown::GetMarkdownParams;
+pub use metadata::GetMetadataParams;
+pub use mouse::{ClickAtParams, DragParams, MouseButtonParams, MouseMoveParams, PointerTarget, WheelParams};
 pub use navigate::NavigateParams;
 pub use new_tab::NewTabParams;
+pub use pdf::{PaperSize, SavePdfParams};
 pub use press_key::PressKeyParams;
 pub use read_links::ReadLinksParams;
+pub use recording::{StartRecordingParams, StopRecordingParams};
+pub use save_page::{PageFormat, SavePageParams};
 pub use screenshot::ScreenshotParams;
-pub use scroll::ScrollParams;
+pub use scroll::{ScrollAlign, ScrollParams};
 pub use select::SelectParams;
+pub use set_checked::SetCheckedParams;
 pub use snapshot::SnapshotParams;
+pub use structured::{ExtractStructuredParams, FieldSpec, FieldType};
 pub use switch_tab::SwitchTabParams;
 pub use tab_list::TabListParams;
+pub use table::{ExtractTableParams, TableFormat};
+pub use touch::{LongPressParams, PinchParams, SwipeDirection, SwipeParams, TapParams};
 pub use annotate::AnnotateParams;
 pub use wait::WaitParams;
 
 use crate::{browser::BrowserSession, dom::DomTree, error::Result};
+use headless_chrome::Tab;
 use serde_json::Value;
 use std::{collections::HashMap, sync::Arc};
 
@@ -72,23 +103,39 @@ pub struct ToolContext<'a> {
 
     /// Optional DOM tree (extracted on demand)
     pub dom_tree: Option<DomTree>,
+
+    /// Tab to run in instead of the session's active tab (set when driving several tabs at once)
+    pub tab: Option<Arc<Tab>>,
 }
 
 impl<'a> ToolContext<'a> {
     /// Create a new tool context
     pub fn new(session: &'a BrowserSession) -> Self {
-        Self { session, dom_tree: None }
+        Self { session, dom_tree: None, tab: None }
     }
 
     /// Create a context with a pre-extracted DOM tree
     pub fn with_dom(session: &'a BrowserSession, dom_tree: DomTree) -> Self {
-        Self { session, dom_tree: Some(dom_tree) }
+        Self { session, dom_tree: Some(dom_tree), tab: None }
+    }
+
+    /// Create a context bound to a specific tab
+    pub fn with_tab(session: &'a BrowserSession, tab: Arc<Tab>) -> Self {
+        Self { session, dom_tree: None, tab: Some(tab) }
+    }
+
+    /// Get the tab tools should operate on
+    pub fn tab(&self) -> Result<Arc<Tab>> {
+        match &self.tab {
+            Some(tab) => Ok(tab.clone()),
+            None => self.session.tab(),
+        }
     }
 
     /// Get or extract the DOM tree
     pub fn get_dom(&mut self) -> Result<&DomTree> {
         if self.dom_tree.is_none() {
-            self.dom_tree = Some(self.session.extract_dom()?);
+            self.dom_tree = Some(DomTree::from_tab(&self.tab()?)?);
         }
         Ok(self.dom_tree.as_ref().unwrap())
     }
@@ -207,9 +254,22 @@ impl ToolRegistry {
         registry.register(click::ClickTool);
         registry.register(input::InputTool);
         registry.register(select::SelectTool);
+        registry.register(set_checked::SetCheckedTool);
         registry.register(hover::HoverTool);
         registry.register(press_key::PressKeyTool);
+        registry.register(keyboard::KeyboardTool);
         registry.register(scroll::ScrollTool);
+        registry.register(forms::FillFormTool);
+        registry.register(mouse::MouseMoveTool);
+        registry.register(mouse::MouseDownTool);
+        registry.register(mouse::MouseUpTool);
+        registry.register(mouse::ClickAtTool);
+        registry.register(mouse::WheelTool);
+        registry.register(mouse::DragTool);
+        registry.register(touch::TapTool);
+        registry.register(touch::LongPressTool);
+        registry.register(touch::SwipeTool);
+        registry.register(touch::PinchTool);
 
         // Register tab management tools
         registry.register(new_tab::NewTabTool);
@@ -221,11 +281,26 @@ impl ToolRegistry {
         registry.register(extract::ExtractContentTool);
         registry.register(markdown::GetMarkdownTool);
         registry.register(read_links::ReadLinksTool);
+        registry.register(metadata::GetMetadataTool);
         registry.register(snapshot::SnapshotTool);
+        registry.register(forms::GetFormsTool);
+        registry.register(table::ExtractTableTool);
+        registry.register(structured::ExtractStructuredTool);
 
         // Register utility tools
         registry.register(screenshot::ScreenshotTool);
         registry.register(annotate::AnnotateTool);
+        registry.register(element_at_point::ElementAtPointTool);
+        registry.register(element_at_point::ClickAtMarkTool);
+        registry.register(compare_screenshot::CompareScreenshotTool);
+        registry.register(emulate::SetViewportTool);
+        registry.register(emulate::EmulateTool);
+        registry.register(emulate::EmulateNetworkTool);
+        registry.register(emulate::EmulateCpuTool);
+        registry.register(recording::StartRecordingTool);
+        registry.register(recording::StopRecordingTool);
+        registry.register(pdf::SavePdfTool);
+        registry.register(save_page::SavePageTool);
         registry.register(evaluate::EvaluateTool);
         registry.register(close::CloseTool);
         
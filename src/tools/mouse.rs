@@ -0,0 +1,408 @@
+use crate::{browser::MouseButton,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::Tab;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const POINTER_TARGET_JS: &str = include_str!("pointer_target.js");
+const DRAG_DROP_JS: &str = include_str!("drag_drop.js");
+
+/// Where a mouse or touch action happens: viewport coordinates or the center of an element
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PointerTarget {
+    /// X coordinate in CSS pixels, relative to the viewport (use with y)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<f64>,
+
+    /// Y coordinate in CSS pixels, relative to the viewport (use with x)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<f64>,
+
+    /// CSS selector of an element; its center is used (scrolled into view)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree; its center is used (scrolled into view)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+}
+
+impl PointerTarget {
+    /// Resolve to a viewport point, or None when no target was given
+    pub fn resolve(&self, tool: &str, context: &mut ToolContext) -> Result<Option<(f64, f64)>> {
+        let failed = |reason: String| BrowserError::ToolExecutionFailed { tool: tool.to_string(), reason };
+
+        let selector = match (&self.x, &self.y, &self.selector, self.index) {
+            (None, None, None, None) => return Ok(None),
+            (Some(x), Some(y), None, None) => return Ok(Some((*x, *y))),
+            (None, None, Some(_), Some(_)) => {
+                return Err(failed("Cannot specify both 'selector' and 'index'. Use one or the other.".to_string()));
+            }
+            (None, None, Some(selector), None) => selector.clone(),
+            (None, None, None, Some(index)) => {
+                let dom = context.get_dom()?;
+                dom.get_selector(index)
+                    .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?
+                    .clone()
+            }
+            (Some(_), None, None, None) | (None, Some(_), None, None) => {
+                return Err(failed("Both 'x' and 'y' are required for a coordinate.".to_string()));
+            }
+            _ => return Err(failed("Use either 'x'/'y' or an element ('selector' or 'index'), not both.".to_string())),
+        };
+
+        element_center(tool, &selector, context).map(Some)
+    }
+
+    /// Resolve to a viewport point, failing when no target was given
+    pub fn require(&self, tool: &str, context: &mut ToolContext) -> Result<(f64, f64)> {
+        self.resolve(tool, context)?.ok_or_else(|| BrowserError::ToolExecutionFailed {
+            tool: tool.to_string(),
+            reason: "Must specify 'x' and 'y', 'selector' or 'index'.".to_string(),
+        })
+    }
+}
+
+/// Scroll an element into view and return the viewport point at its center
+pub fn element_center(tool: &str, selector: &str, context: &mut ToolContext) -> Result<(f64, f64)> {
+    let failed = |reason: String| BrowserError::ToolExecutionFailed { tool: tool.to_string(), reason };
+    let config = serde_json::json!({ "selector": selector });
+    let js = POINTER_TARGET_JS.replace("__POINTER_CONFIG__", &config.to_string());
+    let result = context.tab()?.evaluate(&js, false).map_err(|e| failed(e.to_string()))?;
+    let result_json = parse_result(result.value);
+    if result_json["success"].as_bool() != Some(true) {
+        return Err(failed(result_json["error"].as_str().unwrap_or("Unknown error").to_string()));
+    }
+    match (result_json["x"].as_f64(), result_json["y"].as_f64()) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(failed("Failed to measure element".to_string())),
+    }
+}
+
+fn parse_result(value: Option<Value>) -> Value {
+    if let Some(Value::String(json_str)) = value {
+        serde_json::from_str(&json_str)
+            .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+    } else {
+        serde_json::json!({"success": false, "error": "No result returned"})
+    }
+}
+
+fn default_click_count() -> u32 {
+    1
+}
+
+fn default_move_steps() -> u32 {
+    1
+}
+
+fn default_drag_steps() -> u32 {
+    10
+}
+
+/// Parameters for the mouse_move tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MouseMoveParams {
+    #[serde(flatten)]
+    pub target: PointerTarget,
+
+    /// Number of intermediate mouse events along the way (default: 1)
+    #[serde(default = "default_move_steps")]
+    pub steps: u32,
+}
+
+/// Parameters for the mouse_down and mouse_up tools
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MouseButtonParams {
+    /// Move here first (default: the current pointer position)
+    #[serde(flatten)]
+    pub target: PointerTarget,
+
+    /// Mouse button: "left" (default), "right", "middle", "back" or "forward"
+    #[serde(default)]
+    pub button: MouseButton,
+
+    /// Click count reported with the event (default: 1)
+    #[serde(default = "default_click_count")]
+    pub click_count: u32,
+}
+
+/// Parameters for the click_at tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClickAtParams {
+    #[serde(flatten)]
+    pub target: PointerTarget,
+
+    /// Mouse button: "left" (default), "right", "middle", "back" or "forward"
+    #[serde(default)]
+    pub button: MouseButton,
+
+    /// Number of clicks, e.g. 2 for a double click (default: 1)
+    #[serde(default = "default_click_count")]
+    pub click_count: u32,
+}
+
+/// Parameters for the wheel tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WheelParams {
+    /// Horizontal scroll amount in pixels (positive scrolls right)
+    #[serde(default)]
+    pub dx: f64,
+
+    /// Vertical scroll amount in pixels (positive scrolls down)
+    #[serde(default)]
+    pub dy: f64,
+
+    /// Move here first (default: the current pointer position)
+    #[serde(flatten)]
+    pub target: PointerTarget,
+}
+
+/// Parameters for the drag tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DragParams {
+    /// Start point: {"x", "y"} or an element {"selector"} / {"index"}
+    pub from: PointerTarget,
+
+    /// End point: {"x", "y"} or an element {"selector"} / {"index"}
+    pub to: PointerTarget,
+
+    /// Number of mouse moves between the two points (default: 10)
+    #[serde(default = "default_drag_steps")]
+    pub steps: u32,
+}
+
+fn position(context: &ToolContext) -> Result<Value> {
+    let mouse = context.session.mouse()?;
+    Ok(serde_json::json!({ "x": mouse.x, "y": mouse.y, "buttons": mouse.buttons() }))
+}
+
+/// Tool for moving the mouse pointer
+#[derive(Default)]
+pub struct MouseMoveTool;
+
+impl Tool for MouseMoveTool {
+    type Params = MouseMoveParams;
+
+    fn name(&self) -> &str {
+        "mouse_move"
+    }
+
+    fn execute_typed(&self, params: MouseMoveParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let (x, y) = params.target.require("mouse_move", context)?;
+        let tab = context.tab()?;
+        context.session.pointer_move(&tab, x, y, params.steps)?;
+        Ok(ToolResult::success_with(position(context)?))
+    }
+}
+
+/// Press or release a button, moving to the target first if one was given
+fn press(tool: &str, params: MouseButtonParams, context: &mut ToolContext, down: bool) -> Result<ToolResult> {
+    let point = params.target.resolve(tool, context)?;
+    let tab = context.tab()?;
+    if let Some((x, y)) = point {
+        context.session.pointer_move(&tab, x, y, 1)?;
+    }
+    {
+        let mut mouse = context.session.mouse()?;
+        if down {
+            mouse.down(&tab, params.button, params.click_count)?;
+        } else {
+            mouse.up(&tab, params.button, params.click_count)?;
+        }
+    }
+    Ok(ToolResult::success_with(position(context)?))
+}
+
+/// Tool for pressing a mouse button without releasing it
+#[derive(Default)]
+pub struct MouseDownTool;
+
+impl Tool for MouseDownTool {
+    type Params = MouseButtonParams;
+
+    fn name(&self) -> &str {
+        "mouse_down"
+    }
+
+    fn execute_typed(&self, params: MouseButtonParams, context: &mut ToolContext) -> Result<ToolResult> {
+        press("mouse_down", params, context, true)
+    }
+}
+
+/// Tool for releasing a mouse button
+#[derive(Default)]
+pub struct MouseUpTool;
+
+impl Tool for MouseUpTool {
+    type Params = MouseButtonParams;
+
+    fn name(&self) -> &str {
+        "mouse_up"
+    }
+
+    fn execute_typed(&self, params: MouseButtonParams, context: &mut ToolContext) -> Result<ToolResult> {
+        press("mouse_up", params, context, false)
+    }
+}
+
+/// Tool for clicking at a coordinate with raw mouse events
+#[derive(Default)]
+pub struct ClickAtTool;
+
+impl Tool for ClickAtTool {
+    type Params = ClickAtParams;
+
+    fn name(&self) -> &str {
+        "click_at"
+    }
+
+    fn execute_typed(&self, params: ClickAtParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let (x, y) = params.target.require("click_at", context)?;
+        let tab = context.tab()?;
+        context.session.pointer_click(&tab, x, y, params.button, params.click_count)?;
+        Ok(ToolResult::success_with(serde_json::json!({
+            "x": x,
+            "y": y,
+            "button": params.button,
+            "click_count": params.click_count,
+        })))
+    }
+}
+
+/// Tool for scrolling with the mouse wheel
+#[derive(Default)]
+pub struct WheelTool;
+
+impl Tool for WheelTool {
+    type Params = WheelParams;
+
+    fn name(&self) -> &str {
+        "wheel"
+    }
+
+    fn execute_typed(&self, params: WheelParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let point = params.target.resolve("wheel", context)?;
+        let tab = context.tab()?;
+        if let Some((x, y)) = point {
+            context.session.pointer_move(&tab, x, y, 1)?;
+        }
+
+        // With human emulation the wheel turns in decaying steps
+        match context.session.with_human(|human| human.scroll_steps(1.0))? {
+            Some(steps) => {
+                for step in steps {
+                    context.session.mouse()?.wheel(&tab, params.dx * step.delta, params.dy * step.delta)?;
+                    std::thread::sleep(step.pause);
+                }
+            }
+            None => context.session.mouse()?.wheel(&tab, params.dx, params.dy)?,
+        }
+        let mut result = position(context)?;
+        result["dx"] = params.dx.into();
+        result["dy"] = params.dy.into();
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+/// Tool for dragging between two points or elements
+#[derive(Default)]
+pub struct DragTool;
+
+impl Tool for DragTool {
+    type Params = DragParams;
+
+    fn name(&self) -> &str {
+        "drag"
+    }
+
+    fn execute_typed(&self, params: DragParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let from = params.from.require("drag", context)?;
+        let tab = context.tab()?;
+        let session = context.session;
+
+        // Draggable elements use HTML5 drag and drop, which needs emulating
+        let start = emulate_drag_drop(&tab, serde_json::json!({ "phase": "start", "from": [from.0, from.1] }))?;
+        let emulated = start["emulated"].as_bool() == Some(true);
+        if !emulated {
+            session.pointer_move(&tab, from.0, from.1, 1)?;
+            session.mouse()?.down(&tab, MouseButton::Left, 1)?;
+        }
+
+        // Resolve the drop point only after picking up: scrolling it into view moves the start point
+        let to = match params.to.require("drag", context) {
+            Ok(to) => to,
+            Err(e) => {
+                if !emulated {
+                    session.mouse()?.up(&tab, MouseButton::Left, 1)?;
+                }
+                return Err(e);
+            }
+        };
+
+        let dropped = if !emulated {
+            session.pointer_move(&tab, to.0, to.1, params.steps)?;
+            session.mouse()?.up(&tab, MouseButton::Left, 1)?;
+            None
+        } else if start["cancelled"].as_bool() == Some(true) {
+            Some(false)
+        } else {
+            let drop = emulate_drag_drop(&tab, serde_json::json!({ "phase": "drop", "to": [to.0, to.1] }))?;
+            let mut mouse = session.mouse()?;
+            mouse.x = to.0;
+            mouse.y = to.1;
+            drop["dropped"].as_bool()
+        };
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "from": { "x": from.0, "y": from.1 },
+            "to": { "x": to.0, "y": to.1 },
+            "method": if emulated { "html5" } else { "mouse" },
+            "dropped": dropped,
+        })))
+    }
+}
+
+fn emulate_drag_drop(tab: &Tab, config: Value) -> Result<Value> {
+    let js = DRAG_DROP_JS.replace("__DRAG_CONFIG__", &config.to_string());
+    let result = tab
+        .evaluate(&js, false)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: "drag".to_string(), reason: e.to_string() })?;
+    let result_json = parse_result(result.value);
+    if result_json["success"].as_bool() != Some(true) {
+        return Err(BrowserError::ToolExecutionFailed {
+            tool: "drag".to_string(),
+            reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+        });
+    }
+    Ok(result_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_deserialize() {
+        let params: ClickAtParams =
+            serde_json::from_value(serde_json::json!({ "x": 10, "y": 20, "button": "right" })).unwrap();
+        assert_eq!(params.target.x, Some(10.0));
+        assert_eq!(params.button, MouseButton::Right);
+        assert_eq!(params.click_count, 1);
+
+        let params: DragParams = serde_json::from_value(serde_json::json!({
+            "from": { "selector": "#card" },
+            "to": { "x": 300, "y": 40 }
+        }))
+        .unwrap();
+        assert_eq!(params.from.selector.as_deref(), Some("#card"));
+        assert_eq!(params.to.y, Some(40.0));
+        assert_eq!(params.steps, 10);
+
+        let params: WheelParams = serde_json::from_value(serde_json::json!({ "dy": 250 })).unwrap();
+        assert_eq!((params.dx, params.dy), (0.0, 250.0));
+        assert!(params.target.x.is_none());
+    }
+}
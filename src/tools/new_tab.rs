@@ -25,11 +25,7 @@ impl Tool for NewTabTool {
 
     fn execute_typed(&self, params: NewTabParams, context: &mut ToolContext) -> Result<ToolResult> {
         let normalized_url = normalize_url(&params.url);
-        let tab = context
-            .session
-            .browser()
-            .new_tab()
-            .map_err(|e| crate::error::BrowserError::TabOperationFailed(format!("Failed to create tab: {}", e)))?;
+        let tab = context.session.new_tab()?;
 
         // Navigate to the normalized URL
         tab.navigate_to(&normalized_url).map_err(|e| {
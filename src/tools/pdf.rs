@@ -0,0 +1,257 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::types::PrintToPdfOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Standard paper sizes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaperSize {
+    /// 8.5 x 11 in
+    #[default]
+    Letter,
+    /// 8.5 x 14 in
+    Legal,
+    /// 11 x 17 in
+    Tabloid,
+    /// 297 x 420 mm
+    A3,
+    /// 210 x 297 mm
+    A4,
+    /// 148 x 210 mm
+    A5,
+}
+
+impl PaperSize {
+    /// Width and height in inches (portrait)
+    pub fn dimensions(self) -> (f64, f64) {
+        const MM: f64 = 1.0 / 25.4;
+        match self {
+            PaperSize::Letter => (8.5, 11.0),
+            PaperSize::Legal => (8.5, 14.0),
+            PaperSize::Tabloid => (11.0, 17.0),
+            PaperSize::A3 => (297.0 * MM, 420.0 * MM),
+            PaperSize::A4 => (210.0 * MM, 297.0 * MM),
+            PaperSize::A5 => (148.0 * MM, 210.0 * MM),
+        }
+    }
+}
+
+/// Parameters for the save_pdf tool
+///
+/// Lengths (paper width/height and margins) accept a number in inches or a value with a unit:
+/// "in", "cm", "mm" or "px" (96 px per inch), e.g. "1cm" or "0.5in".
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SavePdfParams {
+    /// Path to save the PDF (if omitted, the PDF is returned as base64)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Paper size: letter (default), legal, tabloid, a3, a4 or a5
+    #[serde(default)]
+    pub paper_size: PaperSize,
+
+    /// Custom paper width, overriding paper_size (e.g. "8in", "200mm")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_width: Option<String>,
+
+    /// Custom paper height, overriding paper_size (e.g. "11in", "280mm")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paper_height: Option<String>,
+
+    /// Margin applied to all sides unless overridden below (Chrome's default is about 0.4in)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin: Option<String>,
+
+    /// Top margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_top: Option<String>,
+
+    /// Bottom margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_bottom: Option<String>,
+
+    /// Left margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_left: Option<String>,
+
+    /// Right margin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub margin_right: Option<String>,
+
+    /// Landscape orientation (default: false)
+    #[serde(default)]
+    pub landscape: bool,
+
+    /// Scale of the page rendering, between 0.1 and 2 (default: 1)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<f64>,
+
+    /// HTML template for the page header; may use the classes date, title, url, pageNumber and totalPages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_template: Option<String>,
+
+    /// HTML template for the page footer; may use the same classes as header_template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_template: Option<String>,
+
+    /// Pages to print, e.g. "1-5, 8, 11-13" (default: all pages)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_ranges: Option<String>,
+
+    /// Print background graphics (default: false)
+    #[serde(default)]
+    pub print_background: bool,
+
+    /// Use the page size defined by CSS @page rules instead of paper_size (default: false)
+    #[serde(default)]
+    pub prefer_css_page_size: bool,
+}
+
+/// Parse a length such as "1.5", "2cm", "10mm", "0.5in" or "48px" into inches
+pub fn parse_length(value: &str) -> Option<f64> {
+    let value = value.trim().to_lowercase();
+    let split = value.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.trim().parse().ok()?;
+
+    let inches = match unit.trim() {
+        "" | "in" => number,
+        "cm" => number / 2.54,
+        "mm" => number / 25.4,
+        "px" => number / 96.0,
+        _ => return None,
+    };
+
+    (inches.is_finite() && inches >= 0.0).then_some(inches)
+}
+
+impl SavePdfParams {
+    /// Convert the parameters into Chrome's print options
+    pub fn to_print_options(&self) -> Result<PrintToPdfOptions> {
+        let length = |name: &str, value: &Option<String>| -> Result<Option<f64>> {
+            value
+                .as_deref()
+                .map(|v| {
+                    parse_length(v).ok_or_else(|| {
+                        BrowserError::InvalidArgument(format!("Invalid {} '{}': expected e.g. 1in or 2cm", name, v))
+                    })
+                })
+                .transpose()
+        };
+
+        if let Some(scale) = self.scale
+            && !(0.1..=2.0).contains(&scale)
+        {
+            return Err(BrowserError::InvalidArgument(format!("scale must be between 0.1 and 2, got {}", scale)));
+        }
+
+        let (default_width, default_height) = self.paper_size.dimensions();
+        let margin = length("margin", &self.margin)?;
+        let has_header_footer = self.header_template.is_some() || self.footer_template.is_some();
+
+        Ok(PrintToPdfOptions {
+            landscape: Some(self.landscape),
+            display_header_footer: Some(has_header_footer),
+            print_background: Some(self.print_background),
+            scale: self.scale,
+            paper_width: Some(length("paper_width", &self.paper_width)?.unwrap_or(default_width)),
+            paper_height: Some(length("paper_height", &self.paper_height)?.unwrap_or(default_height)),
+            margin_top: length("margin_top", &self.margin_top)?.or(margin),
+            margin_bottom: length("margin_bottom", &self.margin_bottom)?.or(margin),
+            margin_left: length("margin_left", &self.margin_left)?.or(margin),
+            margin_right: length("margin_right", &self.margin_right)?.or(margin),
+            page_ranges: self.page_ranges.clone(),
+            // Chrome prints its own default header/footer when only one template is given
+            header_template: has_header_footer.then(|| self.header_template.clone().unwrap_or_default()),
+            footer_template: has_header_footer.then(|| self.footer_template.clone().unwrap_or_default()),
+            prefer_css_page_size: Some(self.prefer_css_page_size),
+            ..Default::default()
+        })
+    }
+}
+
+/// Tool for printing the current page to PDF
+#[derive(Default)]
+pub struct SavePdfTool;
+
+impl Tool for SavePdfTool {
+    type Params = SavePdfParams;
+
+    fn name(&self) -> &str {
+        "save_pdf"
+    }
+
+    fn execute_typed(&self, params: SavePdfParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let options = params.to_print_options()?;
+
+        let pdf_data = context.tab()?.print_to_pdf(Some(options)).map_err(|e| {
+            BrowserError::ToolExecutionFailed { tool: "save_pdf".to_string(), reason: e.to_string() }
+        })?;
+
+        let mut result = serde_json::json!({ "size_bytes": pdf_data.len() });
+
+        match params.path {
+            Some(path) => {
+                std::fs::write(&path, &pdf_data).map_err(|e| BrowserError::ToolExecutionFailed {
+                    tool: "save_pdf".to_string(),
+                    reason: format!("Failed to save PDF: {}", e),
+                })?;
+                result["path"] = serde_json::Value::String(path);
+            }
+            None => {
+                result["pdf_base64"] = serde_json::Value::String(BASE64.encode(&pdf_data));
+            }
+        }
+
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_length() {
+        assert_eq!(parse_length("1"), Some(1.0));
+        assert_eq!(parse_length("0.5in"), Some(0.5));
+        assert_eq!(parse_length("2.54cm"), Some(1.0));
+        assert_eq!(parse_length(" 25.4 MM "), Some(1.0));
+        assert_eq!(parse_length("96px"), Some(1.0));
+        assert_eq!(parse_length("1pt"), None);
+        assert_eq!(parse_length("-1in"), None);
+        assert_eq!(parse_length("wide"), None);
+    }
+
+    #[test]
+    fn test_print_options() {
+        let params: SavePdfParams = serde_json::from_value(serde_json::json!({
+            "paper_size": "a4",
+            "margin": "1cm",
+            "margin_top": "2cm",
+            "landscape": true,
+            "footer_template": "<span class=\"pageNumber\"></span>",
+        }))
+        .unwrap();
+
+        let options = params.to_print_options().unwrap();
+        assert!((options.paper_width.unwrap() - 8.2677).abs() < 1e-3);
+        assert_eq!(options.margin_top, Some(2.0 / 2.54));
+        assert_eq!(options.margin_left, Some(1.0 / 2.54));
+        assert_eq!(options.landscape, Some(true));
+        assert_eq!(options.display_header_footer, Some(true));
+        assert_eq!(options.header_template.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_print_options_validation() {
+        let params = SavePdfParams { scale: Some(3.0), ..Default::default() };
+        assert!(params.to_print_options().is_err());
+
+        let params = SavePdfParams { margin: Some("1pt".to_string()), ..Default::default() };
+        assert!(params.to_print_options().is_err());
+    }
+}
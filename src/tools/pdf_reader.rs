@@ -0,0 +1,90 @@
+//! Reading PDF documents opened in a tab
+//!
+//! When a tab navigates to a PDF, the DOM is only Chrome's viewer shell. These helpers detect
+//! that case, download the document with the page's cookies and extract its text page by page.
+
+use crate::error::{BrowserError, Result};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use headless_chrome::Tab;
+use serde_json::Value;
+
+/// Whether the tab is showing a PDF document
+pub fn is_pdf(tab: &Tab) -> Result<bool> {
+    let result =
+        tab.evaluate("document.contentType", false).map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+    Ok(result.value.and_then(|v| v.as_str().map(|t| t.eq_ignore_ascii_case("application/pdf"))).unwrap_or(false))
+}
+
+/// Download the PDF shown in the tab, sending the session's cookies
+pub fn fetch_pdf(tab: &Tab) -> Result<Vec<u8>> {
+    let result = tab
+        .evaluate(include_str!("fetch_pdf.js"), true)
+        .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+
+    let result_json: Value = if let Some(Value::String(json_str)) = result.value {
+        serde_json::from_str(&json_str)
+            .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+    } else {
+        serde_json::json!({"success": false, "error": "No result returned"})
+    };
+
+    if result_json["success"].as_bool() != Some(true) {
+        return Err(BrowserError::DownloadFailed(result_json["error"].as_str().unwrap_or("Unknown error").to_string()));
+    }
+
+    BASE64
+        .decode(result_json["data"].as_str().unwrap_or_default())
+        .map_err(|e| BrowserError::DownloadFailed(format!("Invalid PDF data: {}", e)))
+}
+
+/// Extract the text of every page of a PDF document
+pub fn extract_pages(bytes: &[u8]) -> Result<Vec<String>> {
+    let parse_error = |reason: String| BrowserError::ToolExecutionFailed {
+        tool: "get_markdown".to_string(),
+        reason: format!("Failed to extract PDF text: {}", reason),
+    };
+
+    // The extractor panics on some malformed fonts and encodings rather than returning an error
+    let pages = std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem_by_pages(bytes))
+        .map_err(|_| parse_error("unsupported PDF content".to_string()))?
+        .map_err(|e| parse_error(e.to_string()))?;
+
+    Ok(pages.iter().map(|page| clean_page_text(page)).collect())
+}
+
+/// Trim trailing whitespace and collapse runs of blank lines left by the text extractor
+pub fn clean_page_text(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut blank_lines = 0;
+
+    for line in text.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+        if !cleaned.is_empty() {
+            cleaned.push_str(if blank_lines > 0 { "\n\n" } else { "\n" });
+        }
+        cleaned.push_str(line);
+        blank_lines = 0;
+    }
+
+    cleaned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_page_text() {
+        let text = "\n\n  Invoice #42   \nDate: 2024-01-01\n\n\n\n\nTotal: $10.00  \n \n";
+        assert_eq!(clean_page_text(text), "  Invoice #42\nDate: 2024-01-01\n\nTotal: $10.00");
+        assert_eq!(clean_page_text(" \n\n"), "");
+    }
+
+    #[test]
+    fn test_extract_pages_rejects_invalid_data() {
+        assert!(extract_pages(b"not a pdf").is_err());
+    }
+}
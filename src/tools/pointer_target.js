@@ -0,0 +1,28 @@
+// Resolve an element to the viewport point at its center for mouse tools
+// The element is scrolled into view first
+// Returns JSON string with the point in CSS pixels
+
+(function () {
+  const config = __POINTER_CONFIG__;
+  const el = document.querySelector(config.selector);
+  if (!el) {
+    return JSON.stringify({
+      success: false,
+      error: "Element not found: " + config.selector,
+    });
+  }
+
+  el.scrollIntoView({ block: "center", inline: "center" });
+  const rect = el.getBoundingClientRect();
+  if (rect.width === 0 && rect.height === 0) {
+    return JSON.stringify({
+      success: false,
+      error: "Element has no size: " + config.selector,
+    });
+  }
+  return JSON.stringify({
+    success: true,
+    x: rect.left + rect.width / 2,
+    y: rect.top + rect.height / 2,
+  });
+})();
@@ -23,7 +23,6 @@ impl Tool for PressKeyTool {
 
     fn execute_typed(&self, params: PressKeyParams, context: &mut ToolContext) -> Result<ToolResult> {
         context
-            .session
             .tab()?
             .press_key(&params.key)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "press_key".to_string(), reason: e.to_string() })?;
@@ -39,7 +39,6 @@ impl Tool for ReadLinksTool {
         "#;
 
         let result = context
-            .session
             .tab()?
             .evaluate(js_code, false)
             .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
@@ -0,0 +1,135 @@
+use crate::{browser::recording::{Recording, RecordingFormat, RecordingOptions},
+            error::Result,
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Parameters for the start_recording tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StartRecordingParams {
+    /// Maximum frame width in pixels (default: 1280)
+    #[serde(default = "default_max_width")]
+    pub max_width: u32,
+
+    /// Maximum frame height in pixels (default: 800)
+    #[serde(default = "default_max_height")]
+    pub max_height: u32,
+
+    /// Keep only every n-th frame to reduce size (default: 1)
+    #[serde(default = "default_every_nth_frame")]
+    pub every_nth_frame: u32,
+
+    /// JPEG quality of captured frames, 0-100 (default: 80)
+    #[serde(default = "default_quality")]
+    pub quality: u32,
+
+    /// Draw each tool call as a caption on the frames that follow it (default: true)
+    #[serde(default = "default_captions")]
+    pub captions: bool,
+
+    /// Stop capturing after this many frames to bound memory use (default: 3000)
+    #[serde(default = "default_max_frames")]
+    pub max_frames: usize,
+}
+
+fn default_max_width() -> u32 {
+    1280
+}
+
+fn default_max_height() -> u32 {
+    800
+}
+
+fn default_every_nth_frame() -> u32 {
+    1
+}
+
+fn default_quality() -> u32 {
+    80
+}
+
+fn default_captions() -> bool {
+    true
+}
+
+fn default_max_frames() -> usize {
+    3000
+}
+
+impl Default for StartRecordingParams {
+    fn default() -> Self {
+        Self {
+            max_width: default_max_width(),
+            max_height: default_max_height(),
+            every_nth_frame: default_every_nth_frame(),
+            quality: default_quality(),
+            captions: default_captions(),
+            max_frames: default_max_frames(),
+        }
+    }
+}
+
+/// Parameters for the stop_recording tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StopRecordingParams {
+    /// Output path: the GIF file, or the directory for PNG frames
+    pub path: String,
+
+    /// Output format: "gif" (default) or "png" (numbered frame files)
+    #[serde(default)]
+    pub format: RecordingFormat,
+}
+
+/// Tool for starting a screencast recording of the active tab
+#[derive(Default)]
+pub struct StartRecordingTool;
+
+impl Tool for StartRecordingTool {
+    type Params = StartRecordingParams;
+
+    fn name(&self) -> &str {
+        "start_recording"
+    }
+
+    fn execute_typed(&self, params: StartRecordingParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let options = RecordingOptions {
+            max_width: Some(params.max_width),
+            max_height: Some(params.max_height),
+            every_nth_frame: params.every_nth_frame,
+            quality: params.quality,
+            captions: params.captions,
+            max_frames: params.max_frames,
+        };
+        context.session.start_recording(context.tab()?, options)?;
+
+        Ok(ToolResult::success_with(serde_json::json!({ "recording": true })))
+    }
+}
+
+/// Tool for stopping the recording and encoding it to a GIF or PNG frames
+#[derive(Default)]
+pub struct StopRecordingTool;
+
+impl Tool for StopRecordingTool {
+    type Params = StopRecordingParams;
+
+    fn name(&self) -> &str {
+        "stop_recording"
+    }
+
+    fn execute_typed(&self, params: StopRecordingParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Check the output path first: once stopped, the frames exist only in this call
+        Recording::prepare_output(params.format, Path::new(&params.path))?;
+        let frames = context.session.stop_recording()?;
+        let files = Recording::save(&frames, params.format, Path::new(&params.path))?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "path": params.path,
+            "format": params.format,
+            "frames": frames.len(),
+            "duration_secs": frames.last().map(|f| f.timestamp).unwrap_or(0.0),
+            "files": files.len(),
+        })))
+    }
+}
@@ -0,0 +1,212 @@
+// Serialize the current page as a single self-contained HTML document
+// Stylesheets and images are inlined, form state is written into attributes
+// and scripts are removed
+// Returns JSON string with the HTML
+
+(async function () {
+  try {
+    // Fetch a resource with the page's cookies and return it as a data: URL
+    var toDataUrl = async function (url) {
+      var response = await fetch(url, { credentials: "include" });
+      if (!response.ok) throw new Error("HTTP " + response.status);
+      var blob = await response.blob();
+      return await new Promise(function (resolve, reject) {
+        var reader = new FileReader();
+        reader.onload = function () {
+          resolve(reader.result);
+        };
+        reader.onerror = reject;
+        reader.readAsDataURL(blob);
+      });
+    };
+
+    var failed = [];
+    var inline = async function (url) {
+      if (!url || url.indexOf("data:") === 0) return url;
+      try {
+        return await toDataUrl(new URL(url, document.baseURI).href);
+      } catch (e) {
+        failed.push(url);
+        return url;
+      }
+    };
+
+    // Replace url(...) references in CSS text with data: URLs, resolving
+    // relative URLs against the stylesheet's own URL
+    var inlineCssUrls = async function (css, baseUrl) {
+      var pattern = /url\(\s*(['"]?)([^'")]+)\1\s*\)/g;
+      var urls = [];
+      css.replace(pattern, function (match, quote, url) {
+        urls.push(url);
+        return match;
+      });
+      var replacements = {};
+      for (var i = 0; i < urls.length; i++) {
+        if (urls[i].indexOf("data:") === 0 || urls[i].charAt(0) === "#") {
+          continue;
+        }
+        var absolute = new URL(urls[i], baseUrl).href;
+        replacements[urls[i]] = await inline(absolute);
+      }
+      return css.replace(pattern, function (match, quote, url) {
+        return replacements[url] ? 'url("' + replacements[url] + '")' : match;
+      });
+    };
+
+    // Read the text of every stylesheet; cross-origin sheets are re-fetched
+    var readSheet = async function (sheet) {
+      var baseUrl = sheet.href || document.baseURI;
+      var css;
+      try {
+        css = Array.from(sheet.cssRules)
+          .map(function (rule) {
+            return rule.cssText;
+          })
+          .join("\n");
+      } catch (e) {
+        try {
+          var response = await fetch(sheet.href, { credentials: "include" });
+          css = await response.text();
+        } catch (e2) {
+          failed.push(sheet.href);
+          return "";
+        }
+      }
+      return await inlineCssUrls(css, baseUrl);
+    };
+
+    var clone = document.documentElement.cloneNode(true);
+
+    // Pair live and cloned images now, before canvases are turned into images
+    var images = document.querySelectorAll("img");
+    var clonedImages = clone.querySelectorAll("img");
+
+    // cloneNode does not copy current form values; write them into attributes
+    var fields = document.querySelectorAll("input, textarea, select");
+    var clonedFields = clone.querySelectorAll("input, textarea, select");
+    fields.forEach(function (el, i) {
+      var copy = clonedFields[i];
+      if (!copy) return;
+      var tag = el.tagName.toLowerCase();
+      if (tag === "textarea") {
+        copy.textContent = el.value;
+      } else if (tag === "select") {
+        Array.from(el.options).forEach(function (option, j) {
+          if (option.selected) {
+            copy.options[j].setAttribute("selected", "");
+          } else {
+            copy.options[j].removeAttribute("selected");
+          }
+        });
+      } else if (el.type === "checkbox" || el.type === "radio") {
+        if (el.checked) copy.setAttribute("checked", "");
+        else copy.removeAttribute("checked");
+      } else if (el.type !== "password" && el.type !== "file") {
+        copy.setAttribute("value", el.value);
+      }
+    });
+
+    // Canvas content is not part of the DOM; snapshot it as an image
+    var canvases = document.querySelectorAll("canvas");
+    clone.querySelectorAll("canvas").forEach(function (copy, i) {
+      try {
+        var img = document.createElement("img");
+        img.src = canvases[i].toDataURL();
+        img.width = canvases[i].width;
+        img.height = canvases[i].height;
+        copy.replaceWith(img);
+      } catch (e) {
+        // Tainted canvases cannot be read
+      }
+    });
+
+    // Scripts would re-run and change what was captured
+    clone
+      .querySelectorAll(
+        "script, noscript, link[rel=preload], link[rel=modulepreload]",
+      )
+      .forEach(function (el) {
+        el.remove();
+      });
+    clone.querySelectorAll("*").forEach(function (el) {
+      Array.from(el.attributes).forEach(function (attr) {
+        if (attr.name.indexOf("on") === 0) el.removeAttribute(attr.name);
+      });
+    });
+
+    // Replace stylesheet links with the CSS actually applied to the page
+    var css = [];
+    var sheets = Array.from(document.styleSheets);
+    for (var i = 0; i < sheets.length; i++) {
+      css.push(await readSheet(sheets[i]));
+    }
+    clone
+      .querySelectorAll("link[rel~=stylesheet], style")
+      .forEach(function (el) {
+        el.remove();
+      });
+    var head = clone.querySelector("head");
+    if (!head) {
+      head = document.createElement("head");
+      clone.insertBefore(head, clone.firstChild);
+    }
+    var style = document.createElement("style");
+    style.textContent = css.join("\n");
+    head.appendChild(style);
+
+    // Inline images, keeping the rendered source of responsive images
+    for (var j = 0; j < clonedImages.length; j++) {
+      var copy = clonedImages[j];
+      var source =
+        (images[j] && images[j].currentSrc) || copy.getAttribute("src");
+      if (source && source.indexOf("data:") !== 0) {
+        copy.setAttribute("src", await inline(source));
+        copy.removeAttribute("srcset");
+        copy.removeAttribute("loading");
+      }
+    }
+    clone.querySelectorAll("picture source").forEach(function (el) {
+      el.remove();
+    });
+
+    var styled = clone.querySelectorAll("[style*='url(']");
+    for (var k = 0; k < styled.length; k++) {
+      styled[k].setAttribute(
+        "style",
+        await inlineCssUrls(styled[k].getAttribute("style"), document.baseURI),
+      );
+    }
+
+    // Keep relative links working and record where the copy came from
+    clone.querySelectorAll("base").forEach(function (el) {
+      el.remove();
+    });
+    var base = document.createElement("base");
+    base.href = document.baseURI;
+    head.insertBefore(base, head.firstChild);
+
+    var capturedAt = new Date().toISOString();
+    var doctype = document.doctype
+      ? "<!DOCTYPE " + document.doctype.name + ">\n"
+      : "";
+    var html =
+      doctype +
+      "<!-- Saved from " +
+      window.location.href.replace(/--/g, "%2D%2D") +
+      " at " +
+      capturedAt +
+      " -->\n" +
+      clone.outerHTML;
+
+    return JSON.stringify({
+      success: true,
+      html: html,
+      url: window.location.href,
+      title: document.title,
+      capturedAt: capturedAt,
+      failedResources: failed,
+    });
+  } catch (e) {
+    return JSON.stringify({ success: false, error: e.message });
+  }
+})();
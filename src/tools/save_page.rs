@@ -0,0 +1,126 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use headless_chrome::protocol::cdp::Page;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const SAVE_PAGE_JS: &str = include_str!("save_page.js");
+
+/// Archive format for the save_page tool
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PageFormat {
+    /// MHTML archive captured by Chrome (Page.captureSnapshot)
+    #[default]
+    Mhtml,
+    /// Single HTML file with inlined CSS and images, current form values and no scripts
+    Html,
+}
+
+/// Parameters for the save_page tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct SavePageParams {
+    /// Path to save the archive (if omitted, the archive is returned as text)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Archive format: "mhtml" (default) or "html" (self-contained HTML)
+    #[serde(default)]
+    pub format: PageFormat,
+}
+
+/// Tool for archiving the current page exactly as it is rendered
+#[derive(Default)]
+pub struct SavePageTool;
+
+impl Tool for SavePageTool {
+    type Params = SavePageParams;
+
+    fn name(&self) -> &str {
+        "save_page"
+    }
+
+    fn execute_typed(&self, params: SavePageParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let tab = context.tab()?;
+        let failed = |reason: String| BrowserError::ToolExecutionFailed { tool: "save_page".to_string(), reason };
+
+        let mut result = match params.format {
+            PageFormat::Mhtml => {
+                let captured_at = tab
+                    .evaluate("new Date().toISOString()", false)
+                    .ok()
+                    .and_then(|r| r.value)
+                    .unwrap_or(Value::Null);
+                let snapshot = tab
+                    .call_method(Page::CaptureSnapshot { format: Some(Page::CaptureSnapshotFormatOption::Mhtml) })
+                    .map_err(|e| failed(e.to_string()))?;
+
+                serde_json::json!({
+                    "content": snapshot.data,
+                    "url": tab.get_url(),
+                    "title": tab.get_title().unwrap_or_default(),
+                    "captured_at": captured_at,
+                })
+            }
+            PageFormat::Html => {
+                let eval = tab.evaluate(SAVE_PAGE_JS, true).map_err(|e| failed(e.to_string()))?;
+
+                let result_json: Value = if let Some(Value::String(json_str)) = eval.value {
+                    serde_json::from_str(&json_str)
+                        .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+                } else {
+                    serde_json::json!({"success": false, "error": "No result returned"})
+                };
+
+                if result_json["success"].as_bool() != Some(true) {
+                    return Err(failed(result_json["error"].as_str().unwrap_or("Unknown error").to_string()));
+                }
+
+                serde_json::json!({
+                    "content": result_json["html"],
+                    "url": result_json["url"],
+                    "title": result_json["title"],
+                    "captured_at": result_json["capturedAt"],
+                    "failed_resources": result_json["failedResources"],
+                })
+            }
+        };
+
+        let content = match result["content"].take() {
+            Value::String(content) => content,
+            _ => return Err(failed("Empty page archive".to_string())),
+        };
+        result["format"] = serde_json::to_value(params.format)?;
+        result["size_bytes"] = content.len().into();
+
+        match params.path {
+            Some(path) => {
+                std::fs::write(&path, &content).map_err(|e| failed(format!("Failed to save page: {}", e)))?;
+                result["path"] = Value::String(path);
+                if let Value::Object(map) = &mut result {
+                    map.remove("content");
+                }
+            }
+            None => result["content"] = Value::String(content),
+        }
+
+        Ok(ToolResult::success_with(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_deserialize() {
+        let json = serde_json::json!({ "path": "page.html", "format": "html" });
+        let params: SavePageParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.format, PageFormat::Html);
+
+        let params: SavePageParams = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(params.format, PageFormat::Mhtml);
+        assert!(params.path.is_none());
+    }
+}
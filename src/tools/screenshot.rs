@@ -25,7 +25,6 @@ impl Tool for ScreenshotTool {
 
     fn execute_typed(&self, params: ScreenshotParams, context: &mut ToolContext) -> Result<ToolResult> {
         let screenshot_data = context
-            .session
             .tab()?
             .capture_screenshot(
                 headless_chrome::protocol::cdp::Page::CaptureScreenshotFormatOption::Png,
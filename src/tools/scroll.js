@@ -1,22 +1,230 @@
 (async function () {
   const config = __SCROLL_CONFIG__;
-  const beforeScrollY = window.scrollY;
-  const scrollAmount =
-    typeof config.amount === "number" ? config.amount : window.innerHeight;
+  const sleep = (ms) => new Promise((resolve) => setTimeout(resolve, ms));
+  const page = document.scrollingElement || document.documentElement;
 
-  window.scrollBy(0, scrollAmount);
+  function describe(el) {
+    if (el === page) return "page";
+    if (el.id) return "#" + el.id;
+    const classes = Array.from(el.classList).slice(0, 2);
+    return el.tagName.toLowerCase() + classes.map((c) => "." + c).join("");
+  }
 
-  await new Promise((resolve) => setTimeout(resolve, 100));
+  function isScrollable(el) {
+    if (el === page) return true;
+    const style = getComputedStyle(el);
+    const overflows = (value) => /(auto|scroll|overlay)/.test(value);
+    return (
+      (overflows(style.overflowY) && el.scrollHeight > el.clientHeight) ||
+      (overflows(style.overflowX) && el.scrollWidth > el.clientWidth)
+    );
+  }
 
-  const actualScroll = window.scrollY - beforeScrollY;
-  const scrollHeight = Math.max(
-    document.documentElement.scrollHeight,
-    document.body.scrollHeight,
-  );
-  const scrollTop = window.scrollY;
-  const clientHeight =
-    window.innerHeight || document.documentElement.clientHeight;
-  const isAtBottom = Math.abs(scrollHeight - scrollTop - clientHeight) <= 1;
+  function metrics(el) {
+    if (el === page) {
+      return {
+        top: window.scrollY,
+        left: window.scrollX,
+        height: Math.max(document.documentElement.scrollHeight, document.body.scrollHeight),
+        width: Math.max(document.documentElement.scrollWidth, document.body.scrollWidth),
+        clientHeight: window.innerHeight || document.documentElement.clientHeight,
+        clientWidth: window.innerWidth || document.documentElement.clientWidth,
+      };
+    }
+    return {
+      top: el.scrollTop,
+      left: el.scrollLeft,
+      height: el.scrollHeight,
+      width: el.scrollWidth,
+      clientHeight: el.clientHeight,
+      clientWidth: el.clientWidth,
+    };
+  }
 
-  return JSON.stringify({ actualScroll, isAtBottom });
+  function position(el, before) {
+    const m = metrics(el);
+    const result = {
+      container: describe(el),
+      scrollTop: Math.round(m.top),
+      scrollLeft: Math.round(m.left),
+      scrollHeight: m.height,
+      scrollWidth: m.width,
+      atTop: m.top <= 1,
+      atBottom: Math.abs(m.height - m.top - m.clientHeight) <= 1,
+      atLeft: m.left <= 1,
+      atRight: Math.abs(m.width - m.left - m.clientWidth) <= 1,
+    };
+    if (before) {
+      result.scrolledY = Math.round(m.top - before.top);
+      result.scrolledX = Math.round(m.left - before.left);
+    }
+    return result;
+  }
+
+  // The element's scrollable ancestors, innermost first, ending with the page
+  function scrollParents(el) {
+    const parents = [];
+    for (let node = el.parentElement; node && node !== page; node = node.parentElement) {
+      if (isScrollable(node)) parents.push(node);
+    }
+    parents.push(page);
+    return parents;
+  }
+
+  function scrollBy(el, dx, dy) {
+    if (el === page) {
+      window.scrollBy(dx, dy);
+    } else {
+      el.scrollBy(dx, dy);
+    }
+  }
+
+  function find(selector) {
+    try {
+      return document.querySelector(selector);
+    } catch (e) {
+      throw new Error("Invalid selector '" + selector + "': " + e.message);
+    }
+  }
+
+  // First element inside the container that is rendered and matches the until condition
+  function findUntil(container) {
+    const root = container === page ? document.body : container;
+    if (config.until_selector) {
+      for (const el of root.querySelectorAll(config.until_selector)) {
+        if (el.getClientRects().length > 0) return el;
+      }
+      return null;
+    }
+    const needle = config.until_text.toLowerCase();
+    const walker = document.createTreeWalker(root, NodeFilter.SHOW_TEXT);
+    for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+      const el = node.parentElement;
+      if (el && node.textContent.toLowerCase().includes(needle) && el.getClientRects().length > 0) {
+        return el;
+      }
+    }
+    return null;
+  }
+
+  function reveal(el) {
+    const parents = scrollParents(el);
+    const before = parents.map(metrics);
+    el.scrollIntoView({ block: config.align, inline: config.align, behavior: "instant" });
+    const rect = el.getBoundingClientRect();
+    return {
+      containers: parents.map((p, i) => position(p, before[i])),
+      rect: {
+        x: Math.round(rect.x),
+        y: Math.round(rect.y),
+        width: Math.round(rect.width),
+        height: Math.round(rect.height),
+      },
+    };
+  }
+
+  try {
+    // Scroll an element into view, moving every scrollable ancestor as needed
+    if (config.selector) {
+      const el = find(config.selector);
+      if (!el) {
+        return JSON.stringify({ success: false, error: "Element not found: " + config.selector });
+      }
+      const revealed = reveal(el);
+      await sleep(100);
+      const pagePosition = revealed.containers[revealed.containers.length - 1];
+      return JSON.stringify({
+        success: true,
+        actualScroll: pagePosition.scrolledY,
+        actualScrollX: pagePosition.scrolledX,
+        isAtBottom: pagePosition.atBottom,
+        containers: revealed.containers,
+        rect: revealed.rect,
+      });
+    }
+
+    let container = page;
+    if (config.container) {
+      container = find(config.container);
+      if (!container) {
+        return JSON.stringify({ success: false, error: "Container not found: " + config.container });
+      }
+      if (!isScrollable(container)) {
+        return JSON.stringify({
+          success: false,
+          error: "Container is not scrollable: " + config.container,
+        });
+      }
+    }
+
+    const start = metrics(container);
+    const hasAmount = typeof config.amount === "number";
+    const hasHorizontal = typeof config.horizontal === "number";
+    const dx = hasHorizontal ? config.horizontal : 0;
+    const dy = hasAmount ? config.amount : hasHorizontal ? 0 : start.clientHeight;
+
+    // Scroll step by step until the target shows up or the container stops growing
+    if (config.until_selector || config.until_text) {
+      let scrolls = 0;
+      let target = findUntil(container);
+      let hitEnd = false;
+      while (!target && scrolls < config.max_scrolls) {
+        const before = metrics(container);
+        // Without explicit amounts, step down by most of a screen so no content is skipped
+        const stepY = hasAmount || hasHorizontal ? dy : start.clientHeight * 0.8;
+        scrollBy(container, dx, stepY);
+        scrolls += 1;
+        await sleep(config.wait_ms);
+        target = findUntil(container);
+        const after = metrics(container);
+        if (!target && after.top === before.top && after.left === before.left && after.height === before.height) {
+          hitEnd = true;
+          break;
+        }
+      }
+      let containers = [position(container, start)];
+      let rect = null;
+      if (target) {
+        const revealed = reveal(target);
+        containers = revealed.containers;
+        rect = revealed.rect;
+        await sleep(100);
+      }
+      const end = metrics(container);
+      return JSON.stringify({
+        success: true,
+        found: !!target,
+        scrolls,
+        hitEnd,
+        actualScroll: Math.round(end.top - start.top),
+        actualScrollX: Math.round(end.left - start.left),
+        isAtBottom: position(container).atBottom,
+        containers,
+        rect,
+      });
+    }
+
+    if (config.steps) {
+      // Each step is [fraction of the amount, pause in ms]
+      for (const [fraction, pause] of config.steps) {
+        scrollBy(container, dx * fraction, dy * fraction);
+        await sleep(pause);
+      }
+    } else {
+      scrollBy(container, dx, dy);
+    }
+
+    await sleep(100);
+
+    const result = position(container, start);
+    return JSON.stringify({
+      success: true,
+      actualScroll: result.scrolledY,
+      actualScrollX: result.scrolledX,
+      isAtBottom: result.atBottom,
+      containers: [result],
+    });
+  } catch (e) {
+    return JSON.stringify({ success: false, error: e.message });
+  }
 })();
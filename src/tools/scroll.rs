@@ -3,16 +3,118 @@ use crate::{error::{BrowserError, Result},
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Where a scrolled-to element ends up within its scroll container
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrollAlign {
+    Start,
+    #[default]
+    Center,
+    End,
+    /// Scroll as little as possible (not at all if already visible)
+    Nearest,
+}
+
 /// Parameters for the scroll tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScrollParams {
     /// Amount to scroll in pixels (positive for down, negative for up).
-    /// If not provided, scrolls to the bottom of the page.
+    /// If neither this nor horizontal is provided, scrolls by one viewport (or container) height.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<i32>,
+
+    /// Amount to scroll horizontally in pixels (positive for right, negative for left)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal: Option<i32>,
+
+    /// CSS selector of an element to scroll into view (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Index of an element to scroll into view (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Alignment of the element scrolled into view (default: center)
+    #[serde(default)]
+    pub align: ScrollAlign,
+
+    /// CSS selector of a scrollable container to scroll instead of the page (e.g. a chat pane or carousel)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
+    /// Index of a scrollable container to scroll instead of the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_index: Option<usize>,
+
+    /// Keep scrolling until an element matching this CSS selector is rendered, then scroll it into view
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_selector: Option<String>,
+
+    /// Keep scrolling until this text (case-insensitive) is rendered, then scroll it into view
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_text: Option<String>,
+
+    /// Maximum number of scrolls when scrolling until a selector or text appears (default: 10)
+    #[serde(default = "default_max_scrolls")]
+    pub max_scrolls: u32,
+
+    /// Time to wait after each scroll for content to load, in milliseconds (default: 400)
+    #[serde(default = "default_wait_ms")]
+    pub wait_ms: u64,
+}
+
+impl Default for ScrollParams {
+    fn default() -> Self {
+        Self {
+            amount: None,
+            horizontal: None,
+            selector: None,
+            index: None,
+            align: ScrollAlign::default(),
+            container: None,
+            container_index: None,
+            until_selector: None,
+            until_text: None,
+            max_scrolls: default_max_scrolls(),
+            wait_ms: default_wait_ms(),
+        }
+    }
+}
+
+fn default_max_scrolls() -> u32 {
+    10
+}
+
+fn default_wait_ms() -> u64 {
+    400
+}
+
+/// Resolve a selector/index pair to a CSS selector, if either was given
+fn resolve_selector(
+    selector: Option<String>,
+    index: Option<usize>,
+    names: (&str, &str),
+    context: &mut ToolContext,
+) -> Result<Option<String>> {
+    match (selector, index) {
+        (Some(_), Some(_)) => Err(BrowserError::InvalidArgument(format!(
+            "Cannot specify both '{}' and '{}'. Use one or the other.",
+            names.0, names.1
+        ))),
+        (Some(selector), None) => Ok(Some(selector)),
+        (None, Some(index)) => {
+            let dom = context.get_dom()?;
+            dom.get_selector(index)
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))
+        }
+        (None, None) => Ok(None),
+    }
 }
 
-/// Tool for scrolling the page
+/// Tool for scrolling the page, a container or an element into view
 #[derive(Default)]
 pub struct ScrollTool;
 
@@ -26,13 +128,44 @@ impl Tool for ScrollTool {
     }
 
     fn execute_typed(&self, params: ScrollParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let target = resolve_selector(params.selector, params.index, ("selector", "index"), context)?;
+        let container =
+            resolve_selector(params.container, params.container_index, ("container", "container_index"), context)?;
+        let until = params.until_selector.is_some() || params.until_text.is_some();
+        if params.until_selector.is_some() && params.until_text.is_some() {
+            return Err(BrowserError::InvalidArgument(
+                "Cannot specify both 'until_selector' and 'until_text'. Use one or the other.".to_string(),
+            ));
+        }
+        let moves = params.amount.is_some() || params.horizontal.is_some();
+        if target.is_some() && (until || moves || container.is_some()) {
+            return Err(BrowserError::InvalidArgument(
+                "An element to scroll into view cannot be combined with amounts, a container or a scroll-until target."
+                    .to_string(),
+            ));
+        }
+
+        // With human emulation a plain scroll is split into decaying steps, like a flick with momentum
+        let steps = if target.is_none() && !until {
+            context.session.with_human(|human| human.scroll_steps(1.0))?
+        } else {
+            None
+        };
         let config = serde_json::json!({
-            "amount": params.amount
+            "amount": params.amount,
+            "horizontal": params.horizontal,
+            "selector": target,
+            "align": params.align,
+            "container": container,
+            "until_selector": params.until_selector,
+            "until_text": params.until_text,
+            "max_scrolls": params.max_scrolls,
+            "wait_ms": params.wait_ms,
+            "steps": steps.map(|steps| steps.iter().map(|s| (s.delta, s.pause.as_millis() as u64)).collect::<Vec<_>>()),
         });
         let scroll_js = SCROLL_JS.replace("__SCROLL_CONFIG__", &config.to_string());
 
         let result = context
-            .session
             .tab()?
             .evaluate(&scroll_js, true)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "scroll".to_string(), reason: e.to_string() })?;
@@ -44,20 +177,58 @@ impl Tool for ScrollTool {
             result.value.unwrap_or(serde_json::json!({"actualScroll": 0, "isAtBottom": false}))
         };
 
+        if result_json["success"].as_bool() == Some(false) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "scroll".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
         let actual_scroll = result_json["actualScroll"].as_i64().unwrap_or(0);
+        let actual_scroll_x = result_json["actualScrollX"].as_i64().unwrap_or(0);
         let is_at_bottom = result_json["isAtBottom"].as_bool().unwrap_or(false);
+        let scope = if container.is_some() { "container" } else { "page" };
 
-        let message = if is_at_bottom {
-            format!("Scrolled {} pixels. Reached the bottom of the page.", actual_scroll)
+        let mut message = if target.is_some() {
+            "Scrolled the element into view.".to_string()
+        } else if actual_scroll_x != 0 {
+            format!("Scrolled {} pixels horizontally and {} pixels vertically.", actual_scroll_x, actual_scroll)
         } else {
-            format!("Scrolled {} pixels. Did not reach the bottom of the page.", actual_scroll)
+            format!("Scrolled {} pixels.", actual_scroll)
         };
+        if until {
+            let scrolls = result_json["scrolls"].as_u64().unwrap_or(0);
+            message.push_str(&match result_json["found"].as_bool() {
+                Some(true) => format!(" Found the target after {} scroll(s).", scrolls),
+                _ if result_json["hitEnd"].as_bool() == Some(true) => {
+                    format!(" Target not found; reached the end of the {} after {} scroll(s).", scope, scrolls)
+                }
+                _ => format!(" Target not found after {} scroll(s).", scrolls),
+            });
+        } else if target.is_none() {
+            message.push_str(&if is_at_bottom {
+                format!(" Reached the bottom of the {}.", scope)
+            } else {
+                format!(" Did not reach the bottom of the {}.", scope)
+            });
+        }
 
-        Ok(ToolResult::success_with(serde_json::json!({
+        let mut data = serde_json::json!({
             "scrolled": actual_scroll,
+            "scrolledX": actual_scroll_x,
             "isAtBottom": is_at_bottom,
+            "containers": result_json["containers"],
             "message": message
-        })))
+        });
+        if until {
+            data["found"] = result_json["found"].clone();
+            data["scrolls"] = result_json["scrolls"].clone();
+            data["hitEnd"] = result_json["hitEnd"].clone();
+        }
+        if !result_json["rect"].is_null() {
+            data["rect"] = result_json["rect"].clone();
+        }
+        Ok(ToolResult::success_with(data))
     }
 }
 
@@ -91,5 +262,32 @@ mod tests {
 
         let params: ScrollParams = serde_json::from_value(json).unwrap();
         assert_eq!(params.amount, None);
+        assert_eq!(params.align, ScrollAlign::Center);
+        assert_eq!(params.max_scrolls, 10);
+    }
+
+    #[test]
+    fn test_scroll_params_targets() {
+        let json = serde_json::json!({
+            "index": 4,
+            "align": "start"
+        });
+
+        let params: ScrollParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.index, Some(4));
+        assert_eq!(params.align, ScrollAlign::Start);
+
+        let json = serde_json::json!({
+            "container": "#feed",
+            "horizontal": 300,
+            "until_text": "Load more",
+            "max_scrolls": 3
+        });
+
+        let params: ScrollParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.container.as_deref(), Some("#feed"));
+        assert_eq!(params.horizontal, Some(300));
+        assert_eq!(params.until_text.as_deref(), Some("Load more"));
+        assert_eq!(params.max_scrolls, 3);
     }
 }
@@ -11,13 +11,82 @@ JSON.stringify(
       return { success: false, error: "Element is not a SELECT element" };
     }
 
-    element.value = config.value;
+    const options = Array.from(element.options);
+    const normalize = (text) => (text || "").replace(/\s+/g, " ").trim();
+
+    // Resolve the requested options
+    let wanted;
+    if (typeof config.optionIndex === "number") {
+      const option = options[config.optionIndex];
+      if (!option) {
+        return {
+          success: false,
+          error:
+            "No option at index " +
+            config.optionIndex +
+            " (the select has " +
+            options.length +
+            " options)",
+        };
+      }
+      wanted = [option];
+    } else if (typeof config.label === "string") {
+      const option = options.find(
+        (o) => normalize(o.text) === normalize(config.label),
+      );
+      if (!option) {
+        return { success: false, error: "No option with label " + config.label };
+      }
+      wanted = [option];
+    } else if (Array.isArray(config.values)) {
+      if (!element.multiple && config.values.length > 1) {
+        return {
+          success: false,
+          error: "Element does not allow multiple selections",
+        };
+      }
+      wanted = [];
+      for (const value of config.values) {
+        const option =
+          options.find((o) => o.value === value) ||
+          options.find((o) => normalize(o.text) === normalize(value));
+        if (!option) {
+          return { success: false, error: "No option with value or label " + value };
+        }
+        wanted.push(option);
+      }
+    } else {
+      const option = options.find((o) => o.value === config.value);
+      if (!option) {
+        return { success: false, error: "No option with value " + config.value };
+      }
+      wanted = [option];
+    }
+
+    if (element.multiple) {
+      options.forEach((o) => (o.selected = wanted.includes(o)));
+    } else {
+      // Use the native setter so React/Vue controlled selects pick up the change
+      const descriptor = Object.getOwnPropertyDescriptor(
+        HTMLSelectElement.prototype,
+        "selectedIndex",
+      );
+      descriptor.set.call(element, wanted[0].index);
+    }
+    element.dispatchEvent(new Event("input", { bubbles: true }));
     element.dispatchEvent(new Event("change", { bubbles: true }));
 
+    const selected = Array.from(element.selectedOptions).map((o) => ({
+      value: o.value,
+      text: normalize(o.text),
+      index: o.index,
+    }));
+
     return {
       success: true,
       selectedValue: element.value,
       selectedText: element.options[element.selectedIndex]?.text ?? null,
+      selected: selected,
     };
   })(),
 );
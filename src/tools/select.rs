@@ -1,10 +1,12 @@
 use crate::{error::{BrowserError, Result},
-            tools::{Tool, ToolContext, ToolResult}};
+            tools::{Tool, ToolContext, ToolResult, mouse::element_center}};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the select tool
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+///
+/// Choose options with exactly one of `value`, `label`, `option_index` or `values`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct SelectParams {
     /// CSS selector (use either this or index, not both)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -14,8 +16,21 @@ pub struct SelectParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index: Option<usize>,
 
-    /// Value to select in the dropdown
-    pub value: String,
+    /// Value attribute of the option to select
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Visible label of the option to select
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Zero-based position of the option to select
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_index: Option<usize>,
+
+    /// Values (or labels) to select in a `<select multiple>`; all other options are deselected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
 }
 
 /// Tool for selecting dropdown options
@@ -49,6 +64,15 @@ impl Tool for SelectTool {
             _ => {}
         }
 
+        let choices =
+            [params.value.is_some(), params.label.is_some(), params.option_index.is_some(), params.values.is_some()];
+        if choices.iter().filter(|c| **c).count() != 1 {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "select".to_string(),
+                reason: "Must specify exactly one of 'value', 'label', 'option_index' or 'values'.".to_string(),
+            });
+        }
+
         let css_selector = if let Some(selector) = params.selector {
             selector
         } else if let Some(index) = params.index {
@@ -60,16 +84,28 @@ impl Tool for SelectTool {
         } else {
             unreachable!("Validation above ensures one field is Some")
         };
-        let value = params.value;
+
+        // Native dropdowns are drawn outside the page, where the pointer cannot reach them, so with human
+        // emulation the pointer moves onto the select and rests there before the script picks the option
+        if context.session.is_human() {
+            let (x, y) = element_center("select", &css_selector, context)?;
+            let tab = context.tab()?;
+            context.session.pointer_move(&tab, x, y, 1)?;
+            if let Some(dwell) = context.session.with_human(|human| human.hover_delay())? {
+                std::thread::sleep(dwell);
+            }
+        }
 
         let select_config = serde_json::json!({
             "selector": css_selector,
-            "value": value,
+            "value": params.value,
+            "label": params.label,
+            "optionIndex": params.option_index,
+            "values": params.values,
         });
         let select_js = SELECT_JS.replace("__SELECT_CONFIG__", &select_config.to_string());
 
         let result = context
-            .session
             .tab()?
             .evaluate(&select_js, false)
             .map_err(|e| BrowserError::ToolExecutionFailed { tool: "select".to_string(), reason: e.to_string() })?;
@@ -85,8 +121,9 @@ impl Tool for SelectTool {
         if result_json["success"].as_bool() == Some(true) {
             Ok(ToolResult::success_with(serde_json::json!({
                 "selector": css_selector,
-                "value": value,
-                "selectedText": result_json["selectedText"]
+                "value": result_json["selectedValue"],
+                "selectedText": result_json["selectedText"],
+                "selected": result_json["selected"]
             })))
         } else {
             Err(BrowserError::ToolExecutionFailed {
@@ -111,7 +148,7 @@ mod tests {
         let params: SelectParams = serde_json::from_value(json).unwrap();
         assert_eq!(params.selector, Some("#country-select".to_string()));
         assert_eq!(params.index, None);
-        assert_eq!(params.value, "us");
+        assert_eq!(params.value, Some("us".to_string()));
     }
 
     #[test]
@@ -124,6 +161,27 @@ mod tests {
         let params: SelectParams = serde_json::from_value(json).unwrap();
         assert_eq!(params.selector, None);
         assert_eq!(params.index, Some(5));
-        assert_eq!(params.value, "option2");
+        assert_eq!(params.value, Some("option2".to_string()));
+    }
+
+    #[test]
+    fn test_select_params_label_and_multiple() {
+        let json = serde_json::json!({
+            "selector": "#country-select",
+            "label": "United Kingdom"
+        });
+
+        let params: SelectParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.label, Some("United Kingdom".to_string()));
+        assert_eq!(params.value, None);
+
+        let json = serde_json::json!({
+            "index": 2,
+            "values": ["red", "Blue"]
+        });
+
+        let params: SelectParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.values, Some(vec!["red".to_string(), "Blue".to_string()]));
+        assert_eq!(params.option_index, None);
     }
 }
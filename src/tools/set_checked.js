@@ -0,0 +1,69 @@
+JSON.stringify(
+  (function () {
+    const config = __SET_CHECKED_CONFIG__;
+    const element = document.querySelector(config.selector);
+
+    if (!element) {
+      return { success: false, error: "Element not found" };
+    }
+
+    const isNative =
+      element.tagName === "INPUT" &&
+      (element.type === "checkbox" || element.type === "radio");
+    const role = element.getAttribute("role");
+    const isAria = [
+      "checkbox",
+      "switch",
+      "radio",
+      "menuitemcheckbox",
+      "menuitemradio",
+    ].includes(role);
+
+    if (!isNative && !isAria) {
+      return {
+        success: false,
+        error: "Element is not a checkbox, radio or switch",
+      };
+    }
+
+    function readChecked() {
+      return isNative
+        ? element.checked
+        : element.getAttribute("aria-checked") === "true";
+    }
+
+    const isRadio = isNative
+      ? element.type === "radio"
+      : role.endsWith("radio");
+    const before = readChecked();
+
+    if (before === config.checked) {
+      return { success: true, checked: before, changed: false };
+    }
+
+    if (isRadio && !config.checked) {
+      return {
+        success: false,
+        error:
+          "A radio button cannot be unchecked directly; check another option in its group instead",
+      };
+    }
+
+    // With human emulation the caller clicks it with the real pointer
+    if (!config.click) {
+      return {
+        success: true,
+        checked: before,
+        changed: false,
+        needsClick: true,
+      };
+    }
+
+    // Click rather than assigning .checked so frameworks receive the usual click/input/change events
+    element.scrollIntoView({ behavior: "auto", block: "center" });
+    element.click();
+
+    const after = readChecked();
+    return { success: true, checked: after, changed: after !== before };
+  })(),
+);
@@ -0,0 +1,149 @@
+use crate::{browser::MouseButton,
+            dom::element::AriaChecked,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, mouse::element_center}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Parameters for the set_checked tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SetCheckedParams {
+    /// CSS selector (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Desired checked state
+    pub checked: bool,
+}
+
+/// Tool for idempotently checking or unchecking checkboxes, radios and switches
+#[derive(Default)]
+pub struct SetCheckedTool;
+
+const SET_CHECKED_JS: &str = include_str!("set_checked.js");
+
+impl Tool for SetCheckedTool {
+    type Params = SetCheckedParams;
+
+    fn name(&self) -> &str {
+        "set_checked"
+    }
+
+    fn execute_typed(&self, params: SetCheckedParams, context: &mut ToolContext) -> Result<ToolResult> {
+        // Validate that exactly one selector method is provided
+        match (&params.selector, &params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "set_checked".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (None, None) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "set_checked".to_string(),
+                    reason: "Must specify either 'selector' or 'index'.".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let css_selector = if let Some(selector) = params.selector {
+            selector
+        } else if let Some(index) = params.index {
+            let dom = context.get_dom()?;
+            let selector = dom
+                .get_selector(index)
+                .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+            selector.clone()
+        } else {
+            unreachable!("Validation above ensures one field is Some")
+        };
+
+        let config = serde_json::json!({
+            "selector": css_selector,
+            "checked": params.checked,
+            "click": !context.session.is_human(),
+        });
+        let set_checked_js = SET_CHECKED_JS.replace("__SET_CHECKED_CONFIG__", &config.to_string());
+
+        let result = context.tab()?.evaluate(&set_checked_js, false).map_err(|e| {
+            BrowserError::ToolExecutionFailed { tool: "set_checked".to_string(), reason: e.to_string() }
+        })?;
+
+        // Parse the JSON string returned by JavaScript
+        let result_json: serde_json::Value = if let Some(serde_json::Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "set_checked".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
+        let needs_click = result_json["needsClick"].as_bool() == Some(true);
+        if needs_click {
+            let (x, y) = element_center("set_checked", &css_selector, context)?;
+            let tab = context.tab()?;
+            context.session.pointer_click(&tab, x, y, MouseButton::Left, 1)?;
+        }
+
+        // Verify against a fresh snapshot: the page may have reverted the change in its click handler
+        context.dom_tree = None;
+        let dom = context.get_dom()?;
+        let index = dom.selectors.iter().position(|s| *s == css_selector);
+        let aria_checked = index.and_then(|i| dom.find_node_by_index(i)).and_then(|node| node.checked.clone());
+        let checked = match aria_checked {
+            Some(AriaChecked::Bool(checked)) => checked,
+            Some(AriaChecked::Mixed(_)) => false,
+            None => result_json["checked"].as_bool().unwrap_or(false),
+        };
+
+        if checked != params.checked {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "set_checked".to_string(),
+                reason: format!(
+                    "Element is still {} after clicking it; the page may be preventing the change",
+                    if checked { "checked" } else { "unchecked" }
+                ),
+            });
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "selector": css_selector,
+            "index": index,
+            "checked": checked,
+            "changed": if needs_click {
+                Some(checked) != result_json["checked"].as_bool()
+            } else {
+                result_json["changed"].as_bool().unwrap_or(false)
+            }
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_checked_params() {
+        let json = serde_json::json!({
+            "index": 4,
+            "checked": false
+        });
+
+        let params: SetCheckedParams = serde_json::from_value(json).unwrap();
+        assert_eq!(params.index, Some(4));
+        assert_eq!(params.selector, None);
+        assert!(!params.checked);
+    }
+}
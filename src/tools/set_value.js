@@ -0,0 +1,102 @@
+(async function () {
+  const config = __SET_VALUE_CONFIG__;
+  __HELPERS__
+  const element = document.querySelector(config.selector);
+
+  if (!element) {
+    return JSON.stringify({ success: false, error: "Element not found" });
+  }
+
+  const value = config.value;
+
+  // Fire blur like a user tabbing away (some validators only run on blur)
+  function blur() {
+    if (document.activeElement === element) {
+      element.blur();
+    } else {
+      element.dispatchEvent(new FocusEvent("blur"));
+      element.dispatchEvent(new FocusEvent("focusout", { bubbles: true }));
+    }
+  }
+
+  element.scrollIntoView({ behavior: "auto", block: "center" });
+  element.focus();
+
+  // Rich-text editors (contenteditable): go through execCommand so the editor's
+  // own beforeinput/input handlers update its internal state
+  if (element.isContentEditable) {
+    const selection = window.getSelection();
+    const range = document.createRange();
+    range.selectNodeContents(element);
+    selection.removeAllRanges();
+    selection.addRange(range);
+
+    const inserted =
+      value === ""
+        ? document.execCommand("delete", false)
+        : document.execCommand("insertText", false, value);
+    if (!inserted) {
+      element.textContent = value;
+      element.dispatchEvent(
+        new InputEvent("input", {
+          bubbles: true,
+          inputType: "insertText",
+          data: value,
+        }),
+      );
+    }
+    element.dispatchEvent(new Event("change", { bubbles: true }));
+    blur();
+
+    await new Promise((resolve) => setTimeout(resolve, 50));
+    const actual = element.innerText.replace(/\n$/, "");
+    return JSON.stringify({
+      success: true,
+      kind: "contenteditable",
+      value: actual,
+      verified: actual.trim() === value.trim(),
+    });
+  }
+
+  if (!["INPUT", "TEXTAREA", "SELECT"].includes(element.tagName)) {
+    return JSON.stringify({
+      success: false,
+      error:
+        "Element is not an input, textarea, select or contenteditable element",
+    });
+  }
+
+  const type = (element.type || "").toLowerCase();
+  if (type === "checkbox" || type === "radio" || type === "file") {
+    return JSON.stringify({
+      success: false,
+      error: "Cannot set the value of a " + type + " input",
+    });
+  }
+
+  setNativeValue(element, value);
+
+  element.dispatchEvent(new Event("input", { bubbles: true }));
+  element.dispatchEvent(new Event("change", { bubbles: true }));
+  blur();
+
+  // Re-read on the next tick so controlled components that revert the value are detected
+  await new Promise((resolve) => setTimeout(resolve, 50));
+  const actual = element.value;
+  let verified = actual === value;
+  if (!verified && type === "range") {
+    // Range inputs snap to their step, so compare numerically within one step
+    const step = parseFloat(element.step) || 1;
+    verified = Math.abs(parseFloat(actual) - parseFloat(value)) < step;
+  }
+  if (!verified && type === "color") {
+    verified = actual.toLowerCase() === value.toLowerCase();
+  }
+
+  return JSON.stringify({
+    success: true,
+    kind: type || element.tagName.toLowerCase(),
+    value: actual,
+    verified: verified,
+  });
+})();
@@ -1,4 +1,4 @@
-use crate::{dom::{AriaChild, AriaNode, yaml_escape_key_if_needed, yaml_escape_value_if_needed},
+use crate::{dom::{AriaChild, AriaNode, DomTree, yaml_escape_key_if_needed, yaml_escape_value_if_needed},
             error::Result,
             tools::{Tool, ToolContext, ToolResult}};
 use schemars::JsonSchema;
@@ -10,6 +10,11 @@ pub struct SnapshotParams {
     /// Whether to include full snapshot or incremental
     #[serde(default)]
     pub incremental: bool,
+
+    /// Include a curated set of computed styles (color, background, font, display, position, z-index, opacity)
+    /// for each indexed element
+    #[serde(default)]
+    pub include_styles: bool,
 }
 
 /// Tool for getting an ARIA snapshot of the page in YAML format
@@ -24,8 +29,14 @@ impl Tool for SnapshotTool {
     }
 
     fn execute_typed(&self, params: SnapshotParams, context: &mut ToolContext) -> Result<ToolResult> {
-        // Get or extract the DOM tree
-        let dom = context.get_dom()?;
+        // Styles are not part of the cached tree, so extract a styled tree for this call without caching it
+        let styled;
+        let dom = if params.include_styles {
+            styled = DomTree::from_tab_with_styles(&context.tab()?)?;
+            &styled
+        } else {
+            context.get_dom()?
+        };
 
         // Generate YAML snapshot
         let yaml_snapshot = render_aria_tree(&dom.root, RenderMode::Ai, None);
@@ -218,6 +229,13 @@ fn create_key(aria_node: &AriaNode, render_cursor_pointer: bool, render_active:
         }
     }
 
+    if let Some(styles) = &aria_node.styles {
+        let compact = styles.to_compact();
+        if !compact.is_empty() {
+            key.push_str(&format!(" [style={}]", compact));
+        }
+    }
+
     key
 }
 
@@ -233,6 +251,7 @@ fn get_single_inlined_text_child(aria_node: &AriaNode) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dom::ComputedStyles;
 
     #[test]
     fn test_render_simple_tree() {
@@ -315,6 +334,26 @@ mod tests {
         assert!(yaml.contains("[level=1]"));
     }
 
+    #[test]
+    fn test_render_with_styles() {
+        let styles = ComputedStyles {
+            color: "rgb(255, 255, 255)".to_string(),
+            background_color: "rgb(0, 102, 204)".to_string(),
+            font_weight: "700".to_string(),
+            ..Default::default()
+        };
+
+        let mut root = AriaNode::fragment();
+        root.children.push(AriaChild::Node(Box::new(
+            AriaNode::new("button", "Sign up").with_index(0).with_styles(styles),
+        )));
+        root.children.push(AriaChild::Node(Box::new(AriaNode::new("link", "Learn more").with_index(1))));
+
+        let yaml = render_aria_tree(&root, RenderMode::Ai, None);
+        assert!(yaml.contains("[style=color:rgb(255, 255, 255);bg:rgb(0, 102, 204);font-weight:700]"));
+        assert_eq!(yaml.matches("[style=").count(), 1);
+    }
+
     #[test]
     fn test_empty_snapshot() {
         let root = AriaNode::fragment();
@@ -0,0 +1,85 @@
+JSON.stringify(
+  (function () {
+    const config = __STRUCTURED_CONFIG__;
+
+    function normalize(text) {
+      return (text || "").replace(/\s+/g, " ").trim();
+    }
+
+    // Read the requested attribute; href/src go through the DOM property so
+    // they come back as absolute URLs
+    function readAttr(element, attr) {
+      switch (attr) {
+        case "text":
+          return normalize(element.innerText || element.textContent);
+        case "html":
+          return element.innerHTML;
+        case "href":
+        case "src":
+          return element[attr] || element.getAttribute(attr);
+        case "value":
+          return element.value !== undefined
+            ? String(element.value)
+            : element.getAttribute("value");
+        default:
+          return element.getAttribute(attr);
+      }
+    }
+
+    function toUrl(value) {
+      try {
+        return new URL(value, document.baseURI).href;
+      } catch (e) {
+        return null;
+      }
+    }
+
+    function toDate(element, value) {
+      // Prefer machine-readable <time datetime="...">
+      const datetime = element.getAttribute("datetime");
+      const parsed = Date.parse(datetime || value);
+      return isNaN(parsed) ? null : new Date(parsed).toISOString();
+    }
+
+    function readField(container, spec) {
+      const element = spec.selector
+        ? container.querySelector(spec.selector)
+        : container;
+      if (!element) return null;
+
+      const value = readAttr(element, spec.attr || "text");
+      if (value === null || value === undefined || value === "") return null;
+
+      switch (spec.type) {
+        case "url":
+          return toUrl(value);
+        case "date":
+          return toDate(element, value);
+        default:
+          // Strings and numbers are trimmed and coerced on the Rust side
+          return value;
+      }
+    }
+
+    let containers;
+    try {
+      containers = Array.from(document.querySelectorAll(config.container));
+    } catch (e) {
+      return { success: false, error: "Invalid container selector: " + e };
+    }
+
+    const items = containers.map((container) => {
+      const item = {};
+      for (const [name, spec] of config.fields) {
+        try {
+          item[name] = readField(container, spec);
+        } catch (e) {
+          item[name] = null;
+        }
+      }
+      return item;
+    });
+
+    return { success: true, items: items };
+  })(),
+);
@@ -0,0 +1,309 @@
+use crate::{error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{thread, time::Duration};
+
+const STRUCTURED_JS: &str = include_str!("structured.js");
+
+/// Type a field value is converted to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    /// Trimmed text
+    #[default]
+    String,
+    /// Number parsed from text such as "$1,299.00" or "4.5 stars"
+    Number,
+    /// Absolute URL resolved against the page
+    Url,
+    /// ISO 8601 timestamp (uses `datetime` on `<time>` elements when present)
+    Date,
+}
+
+/// How to read one field from each item
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FieldSpec {
+    /// CSS selector relative to the item container (omit to read the container itself)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// What to read: "text" (default), "html", "href", "src", "value" or any attribute name
+    #[serde(default = "default_attr")]
+    pub attr: String,
+
+    /// Value type: "string" (default), "number", "url" or "date"
+    #[serde(default, rename = "type")]
+    pub field_type: FieldType,
+}
+
+fn default_attr() -> String {
+    "text".to_string()
+}
+
+/// Parameters for the extract_structured tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExtractStructuredParams {
+    /// CSS selector matching each repeated item (product card, search result, row, ...)
+    pub container: String,
+
+    /// Map of output field name to how it is read from the item
+    pub fields: IndexMap<String, FieldSpec>,
+
+    /// CSS selector of a "next page" control to click between pages (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_selector: Option<String>,
+
+    /// Scroll to the bottom between pages to load more items, for infinite scroll (default: false)
+    #[serde(default)]
+    pub scroll: bool,
+
+    /// Maximum number of pages to read (default: 1)
+    #[serde(default = "default_max_pages")]
+    pub max_pages: usize,
+
+    /// Stop once this many items have been collected (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+
+    /// Delay in milliseconds after paging before reading again (default: 1000)
+    #[serde(default = "default_delay_ms")]
+    pub delay_ms: u64,
+}
+
+fn default_max_pages() -> usize {
+    1
+}
+
+fn default_delay_ms() -> u64 {
+    1000
+}
+
+/// Parse a number out of display text, ignoring currency symbols, units and thousands separators
+pub fn parse_number(text: &str) -> Option<f64> {
+    // A sign or decimal point only counts when it directly precedes the first digit
+    let mut start = text.find(|c: char| c.is_ascii_digit())?;
+    if text[..start].ends_with('.') {
+        start -= 1;
+    }
+    let negative = text[..start].ends_with('-');
+    let digits: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | ' ' | '\u{a0}'))
+        .filter(|c| !matches!(c, ' ' | '\u{a0}'))
+        .collect();
+
+    // "1.234,5" and "1,234.5": whichever separator comes last is the decimal point
+    let normalized = match (digits.rfind(','), digits.rfind('.')) {
+        (Some(comma), Some(dot)) if comma > dot => digits.replace('.', "").replace(',', "."),
+        (Some(comma), None) if digits.len() - comma - 1 != 3 => digits.replace(',', "."),
+        _ => digits.replace(',', ""),
+    };
+
+    normalized.parse::<f64>().ok().map(|number| if negative { -number } else { number })
+}
+
+/// Convert the raw value read in the page to the field's declared type
+fn coerce(value: &Value, field_type: FieldType) -> Value {
+    match (value, field_type) {
+        (Value::String(text), FieldType::Number) => {
+            parse_number(text).and_then(serde_json::Number::from_f64).map(Value::Number).unwrap_or(Value::Null)
+        }
+        (Value::String(text), FieldType::String) => {
+            let trimmed = text.trim();
+            if trimmed.is_empty() { Value::Null } else { Value::String(trimmed.to_string()) }
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Tool for extracting typed records from repeated page elements
+#[derive(Default)]
+pub struct ExtractStructuredTool;
+
+impl ExtractStructuredTool {
+    fn read_items(&self, params: &ExtractStructuredParams, context: &ToolContext) -> Result<Vec<Value>> {
+        // Pass fields as [name, spec] pairs so their order is kept
+        let fields: Vec<(&String, &FieldSpec)> = params.fields.iter().collect();
+        let config = serde_json::json!({ "container": params.container, "fields": fields });
+        let structured_js = STRUCTURED_JS.replace("__STRUCTURED_CONFIG__", &config.to_string());
+
+        let result = context.tab()?.evaluate(&structured_js, false).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "extract_structured".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let result_json: Value = if let Some(Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "extract_structured".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
+        let items = result_json["items"].as_array().cloned().unwrap_or_default();
+        Ok(items
+            .iter()
+            .map(|item| {
+                let record: serde_json::Map<String, Value> = params
+                    .fields
+                    .iter()
+                    .map(|(name, spec)| (name.clone(), coerce(&item[name], spec.field_type)))
+                    .collect();
+                Value::Object(record)
+            })
+            .collect())
+    }
+
+    /// Move to the next page of results; returns false when there is nothing more to load
+    fn next_page(&self, params: &ExtractStructuredParams, context: &ToolContext) -> Result<bool> {
+        let tab = context.tab()?;
+
+        if let Some(next_selector) = &params.next_selector {
+            let click_js = format!(
+                "(function() {{ const el = document.querySelector({}); \
+                 if (!el || el.disabled || el.getAttribute('aria-disabled') === 'true') return false; \
+                 el.click(); return true; }})()",
+                serde_json::to_string(next_selector).unwrap_or_default()
+            );
+            let clicked = tab.evaluate(&click_js, false).map_err(|e| BrowserError::ToolExecutionFailed {
+                tool: "extract_structured".to_string(),
+                reason: e.to_string(),
+            })?;
+            if clicked.value.and_then(|v| v.as_bool()) != Some(true) {
+                return Ok(false);
+            }
+        } else if params.scroll {
+            tab.evaluate("window.scrollTo(0, document.documentElement.scrollHeight); true", false).map_err(|e| {
+                BrowserError::ToolExecutionFailed { tool: "extract_structured".to_string(), reason: e.to_string() }
+            })?;
+        } else {
+            return Ok(false);
+        }
+
+        thread::sleep(Duration::from_millis(params.delay_ms));
+        Ok(true)
+    }
+}
+
+impl Tool for ExtractStructuredTool {
+    type Params = ExtractStructuredParams;
+
+    fn name(&self) -> &str {
+        "extract_structured"
+    }
+
+    fn execute_typed(&self, params: ExtractStructuredParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if params.fields.is_empty() {
+            return Err(BrowserError::InvalidArgument("'fields' must contain at least one field".to_string()));
+        }
+        if params.next_selector.is_some() && params.scroll {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "extract_structured".to_string(),
+                reason: "Cannot specify both 'next_selector' and 'scroll'. Use one or the other.".to_string(),
+            });
+        }
+
+        let max_items = params.max_items.unwrap_or(usize::MAX);
+        let mut records: Vec<Value> = Vec::new();
+        let mut pages = 0;
+
+        while pages < params.max_pages.max(1) {
+            pages += 1;
+
+            // Infinite scroll re-reads items seen before, so skip duplicates; separate pages
+            // may legitimately repeat a row, so keep everything read there
+            let before = records.len();
+            for record in self.read_items(&params, context)? {
+                if !params.scroll || !records.contains(&record) {
+                    records.push(record);
+                }
+            }
+
+            if records.len() >= max_items {
+                records.truncate(max_items);
+                break;
+            }
+            if pages > 1 && records.len() == before {
+                break;
+            }
+            if pages < params.max_pages && !self.next_page(&params, context)? {
+                break;
+            }
+        }
+
+        if pages > 1 {
+            // Paging changed the page, so any cached DOM tree is stale
+            context.dom_tree = None;
+        }
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "fields": params.fields.keys().collect::<Vec<_>>(),
+            "items": records,
+            "count": records.len(),
+            "pages": pages
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("$1,299.00"), Some(1299.0));
+        assert_eq!(parse_number("1.299,50 €"), Some(1299.5));
+        assert_eq!(parse_number("4,5 stars"), Some(4.5));
+        assert_eq!(parse_number("Rated 4.5 out of 5"), Some(4.5));
+        assert_eq!(parse_number("1 234 567"), Some(1234567.0));
+        assert_eq!(parse_number("-12"), Some(-12.0));
+        assert_eq!(parse_number("-.5"), Some(-0.5));
+        assert_eq!(parse_number("Sold out"), None);
+
+        // Separated signs and periods belong to the surrounding words
+        assert_eq!(parse_number("Model No. 1234"), Some(1234.0));
+        assert_eq!(parse_number("In stock - 12 left"), Some(12.0));
+        assert_eq!(parse_number("Save - now $5"), Some(5.0));
+    }
+
+    #[test]
+    fn test_coerce() {
+        assert_eq!(coerce(&serde_json::json!("  Widget  "), FieldType::String), serde_json::json!("Widget"));
+        assert_eq!(coerce(&serde_json::json!("   "), FieldType::String), Value::Null);
+        assert_eq!(coerce(&serde_json::json!("$19.99"), FieldType::Number), serde_json::json!(19.99));
+        assert_eq!(coerce(&serde_json::json!("n/a"), FieldType::Number), Value::Null);
+        assert_eq!(coerce(&Value::Null, FieldType::Url), Value::Null);
+    }
+
+    #[test]
+    fn test_params_deserialize() {
+        let json = r#"{
+            "container": ".product",
+            "fields": {
+                "title": { "selector": "h2" },
+                "price": { "selector": ".price", "type": "number" },
+                "link": { "selector": "a", "attr": "href", "type": "url" }
+            },
+            "next_selector": "a.next",
+            "max_pages": 3
+        }"#;
+
+        let params: ExtractStructuredParams = serde_json::from_str(json).unwrap();
+        let names: Vec<&String> = params.fields.keys().collect();
+        assert_eq!(names, vec!["title", "price", "link"]);
+        assert_eq!(params.fields["title"].attr, "text");
+        assert_eq!(params.fields["price"].field_type, FieldType::Number);
+        assert_eq!(params.fields["link"].attr, "href");
+        assert!(!params.scroll);
+        assert_eq!(params.delay_ms, 1000);
+    }
+}
@@ -34,7 +34,7 @@ impl Tool for TabListTool {
     fn execute_typed(&self, _params: TabListParams, context: &mut ToolContext) -> Result<ToolResult> {
         // Get all tabs
         let tabs = context.session.get_tabs()?;
-        let active_tab = context.session.tab()?;
+        let active_tab = context.tab()?;
 
         // Build tab info list
         let mut tab_list = Vec::new();
@@ -0,0 +1,130 @@
+JSON.stringify(
+  (function () {
+    const config = __TABLE_CONFIG__;
+
+    function normalize(text) {
+      return (text || "").replace(/\s+/g, " ").trim();
+    }
+
+    // Expand colspan/rowspan into a rectangular grid of cells
+    function toGrid(rows) {
+      const grid = [];
+      // column -> { text, isHeader, remaining } for rowspans still in progress
+      const pending = [];
+
+      // Rowspans stop at the end of their section (thead, tbody or tfoot)
+      const sectionEnd = [];
+      for (let r = rows.length - 1; r >= 0; r--) {
+        const sameSection =
+          r + 1 < rows.length &&
+          rows[r + 1].parentElement === rows[r].parentElement;
+        sectionEnd[r] = sameSection ? sectionEnd[r + 1] : r + 1;
+      }
+
+      rows.forEach((row, r) => {
+        grid[r] = [];
+        let col = 0;
+        const cells = Array.from(row.cells);
+        let cellIdx = 0;
+
+        const spanned = () => pending[col] && pending[col].remaining > 0;
+
+        while (cellIdx < cells.length || spanned()) {
+          if (spanned()) {
+            const { text, isHeader } = pending[col];
+            grid[r][col] = { text, isHeader };
+            pending[col].remaining--;
+            col++;
+            continue;
+          }
+          const cell = cells[cellIdx++];
+          const text = normalize(cell.innerText);
+          const isHeader = cell.tagName === "TH";
+          const colspan = Math.max(
+            1,
+            parseInt(cell.getAttribute("colspan"), 10) || 1,
+          );
+          const rowspanAttr = parseInt(cell.getAttribute("rowspan"), 10);
+          // rowspan="0" spans to the end of the section
+          const sectionRows = sectionEnd[r] - r;
+          const rowspan =
+            rowspanAttr === 0
+              ? sectionRows
+              : Math.min(sectionRows, Math.max(1, rowspanAttr || 1));
+
+          for (let c = 0; c < colspan; c++) {
+            grid[r][col] = { text, isHeader };
+            if (rowspan > 1) {
+              pending[col] = { text, isHeader, remaining: rowspan - 1 };
+            }
+            col++;
+          }
+        }
+      });
+
+      const width = Math.max(0, ...grid.map((row) => row.length));
+      return grid.map((row) => {
+        const filled = [];
+        for (let c = 0; c < width; c++) {
+          filled.push(row[c] || { text: "", isHeader: false });
+        }
+        return filled;
+      });
+    }
+
+    function extractTable(table) {
+      // table.rows already excludes rows of nested tables
+      const rows = Array.from(table.rows);
+      const grid = toGrid(rows);
+
+      // Header rows: everything in <thead>, else leading rows of only <th> cells
+      let headerCount = rows.filter(
+        (row) => row.parentElement.tagName === "THEAD",
+      ).length;
+      if (headerCount === 0) {
+        while (
+          headerCount < grid.length &&
+          grid[headerCount].length > 0 &&
+          grid[headerCount].every((cell) => cell.isHeader)
+        ) {
+          headerCount++;
+        }
+      }
+
+      const caption = table.caption ? normalize(table.caption.innerText) : "";
+      return {
+        selector: buildSelector(table),
+        caption: caption,
+        headers: grid
+          .slice(0, headerCount)
+          .map((row) => row.map((cell) => cell.text)),
+        rows: grid.slice(headerCount).map((row) => row.map((cell) => cell.text)),
+      };
+    }
+
+    __HELPERS__
+
+    let tables;
+    if (config.selector) {
+      const element = document.querySelector(config.selector);
+      if (!element) {
+        return { success: false, error: "Element not found" };
+      }
+      const table =
+        element.tagName === "TABLE"
+          ? element
+          : element.closest("table") || element.querySelector("table");
+      if (!table) {
+        return {
+          success: false,
+          error: "Element is not inside or around a TABLE element",
+        };
+      }
+      tables = [table];
+    } else {
+      tables = Array.from(document.querySelectorAll("table"));
+    }
+
+    return { success: true, tables: tables.map(extractTable) };
+  })(),
+);
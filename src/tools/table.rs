@@ -0,0 +1,277 @@
+use crate::{dom::with_helpers,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const TABLE_JS: &str = include_str!("table.js");
+
+/// Output format for extracted tables
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TableFormat {
+    /// One JSON object per body row, keyed by column name
+    #[default]
+    Json,
+    /// CSV text with a single header line
+    Csv,
+}
+
+/// Parameters for the extract_table tool
+///
+/// Without `selector` or `index`, every table on the page is extracted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ExtractTableParams {
+    /// CSS selector of the table, or of an element inside or around it (use either this or index, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+
+    /// Element index from DOM tree (use either this or selector, not both)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<usize>,
+
+    /// Output format: "json" records (default) or "csv"
+    #[serde(default)]
+    pub format: TableFormat,
+}
+
+/// A table as read from the page, with colspan/rowspan already expanded
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RawTable {
+    pub selector: String,
+    #[serde(default)]
+    pub caption: String,
+    /// Header rows (from `<thead>`, or leading rows made only of `<th>` cells)
+    pub headers: Vec<Vec<String>>,
+    /// Body rows
+    pub rows: Vec<Vec<String>>,
+}
+
+impl RawTable {
+    /// Number of columns in the expanded grid
+    pub fn width(&self) -> usize {
+        self.headers.iter().chain(self.rows.iter()).map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Column names built from the header rows.
+    ///
+    /// Stacked header cells are joined with " / " (a cell repeated by a rowspan is used
+    /// once), empty names become `column_N`, and duplicates get a `_2`, `_3`... suffix.
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns: Vec<String> = Vec::with_capacity(self.width());
+
+        for col in 0..self.width() {
+            let mut parts: Vec<&str> = Vec::new();
+            for row in &self.headers {
+                if let Some(text) = row.get(col).map(String::as_str)
+                    && !text.is_empty()
+                    && parts.last() != Some(&text)
+                {
+                    parts.push(text);
+                }
+            }
+
+            let base = if parts.is_empty() { format!("column_{}", col + 1) } else { parts.join(" / ") };
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while columns.contains(&name) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            columns.push(name);
+        }
+
+        columns
+    }
+
+    /// Body rows as JSON objects keyed by column name
+    pub fn records(&self) -> Vec<Value> {
+        let columns = self.columns();
+        self.rows
+            .iter()
+            .map(|row| {
+                let record: serde_json::Map<String, Value> = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, column)| (column.clone(), Value::String(row.get(i).cloned().unwrap_or_default())))
+                    .collect();
+                Value::Object(record)
+            })
+            .collect()
+    }
+
+    /// The table as CSV (RFC 4180 quoting), with the column names as the header line
+    pub fn to_csv(&self) -> String {
+        let width = self.width();
+        let mut csv = String::new();
+
+        let mut write_row = |cells: &[String]| {
+            let line: Vec<String> =
+                (0..width).map(|i| csv_field(cells.get(i).map(String::as_str).unwrap_or(""))).collect();
+            csv.push_str(&line.join(","));
+            csv.push_str("\r\n");
+        };
+
+        if !self.headers.is_empty() {
+            write_row(&self.columns());
+        }
+        for row in &self.rows {
+            write_row(row);
+        }
+
+        csv
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Tool for extracting HTML tables as JSON records or CSV
+#[derive(Default)]
+pub struct ExtractTableTool;
+
+impl Tool for ExtractTableTool {
+    type Params = ExtractTableParams;
+
+    fn name(&self) -> &str {
+        "extract_table"
+    }
+
+    fn execute_typed(&self, params: ExtractTableParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let css_selector = match (params.selector, params.index) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::ToolExecutionFailed {
+                    tool: "extract_table".to_string(),
+                    reason: "Cannot specify both 'selector' and 'index'. Use one or the other.".to_string(),
+                });
+            }
+            (Some(selector), None) => Some(selector),
+            (None, Some(index)) => {
+                let dom = context.get_dom()?;
+                let selector = dom
+                    .get_selector(index)
+                    .ok_or_else(|| BrowserError::ElementNotFound(format!("No element with index {}", index)))?;
+                Some(selector.clone())
+            }
+            (None, None) => None,
+        };
+
+        let table_config = serde_json::json!({ "selector": css_selector });
+        let table_js = with_helpers(TABLE_JS).replace("__TABLE_CONFIG__", &table_config.to_string());
+
+        let result = context.tab()?.evaluate(&table_js, false).map_err(|e| BrowserError::ToolExecutionFailed {
+            tool: "extract_table".to_string(),
+            reason: e.to_string(),
+        })?;
+
+        let result_json: Value = if let Some(Value::String(json_str)) = result.value {
+            serde_json::from_str(&json_str)
+                .unwrap_or(serde_json::json!({"success": false, "error": "Failed to parse result"}))
+        } else {
+            result.value.unwrap_or(serde_json::json!({"success": false, "error": "No result returned"}))
+        };
+
+        if result_json["success"].as_bool() != Some(true) {
+            return Err(BrowserError::ToolExecutionFailed {
+                tool: "extract_table".to_string(),
+                reason: result_json["error"].as_str().unwrap_or("Unknown error").to_string(),
+            });
+        }
+
+        let tables: Vec<RawTable> =
+            serde_json::from_value(result_json["tables"].clone()).map_err(|e| BrowserError::ToolExecutionFailed {
+                tool: "extract_table".to_string(),
+                reason: format!("Invalid tables: {}", e),
+            })?;
+
+        let output: Vec<Value> = tables
+            .iter()
+            .map(|table| {
+                let mut entry = serde_json::json!({
+                    "selector": table.selector,
+                    "caption": table.caption,
+                    "columns": table.columns(),
+                    "headers": table.headers,
+                    "row_count": table.rows.len(),
+                });
+                match params.format {
+                    TableFormat::Json => entry["records"] = Value::Array(table.records()),
+                    TableFormat::Csv => entry["csv"] = Value::String(table.to_csv()),
+                }
+                entry
+            })
+            .collect();
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "tables": output,
+            "count": output.len()
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_columns_from_stacked_headers() {
+        // <tr><th rowspan=2>Name</th><th colspan=2>Score</th></tr><tr><th>Q1</th><th>Q2</th></tr>
+        let table = RawTable {
+            selector: "table".to_string(),
+            caption: String::new(),
+            headers: vec![strings(&["Name", "Score", "Score"]), strings(&["Name", "Q1", "Q2"])],
+            rows: vec![strings(&["Ada", "9", "10"])],
+        };
+
+        assert_eq!(table.columns(), vec!["Name", "Score / Q1", "Score / Q2"]);
+        assert_eq!(table.records()[0]["Score / Q2"], "10");
+    }
+
+    #[test]
+    fn test_columns_fill_missing_and_duplicate_names() {
+        let table = RawTable {
+            selector: "table".to_string(),
+            caption: String::new(),
+            headers: vec![strings(&["Item", "", "Item"])],
+            rows: vec![strings(&["a", "b"])],
+        };
+
+        assert_eq!(table.columns(), vec!["Item", "column_2", "Item_2"]);
+        // Short rows are padded with empty cells
+        assert_eq!(table.records()[0]["Item_2"], "");
+    }
+
+    #[test]
+    fn test_to_csv_quotes_fields() {
+        let table = RawTable {
+            selector: "table".to_string(),
+            caption: String::new(),
+            headers: vec![strings(&["City", "Note"])],
+            rows: vec![strings(&["Paris, FR", "say \"hi\""]), strings(&["Oslo", "line\nbreak"])],
+        };
+
+        assert_eq!(table.to_csv(), "City,Note\r\n\"Paris, FR\",\"say \"\"hi\"\"\"\r\nOslo,\"line\nbreak\"\r\n");
+    }
+
+    #[test]
+    fn test_extract_table_params_default_format() {
+        let params: ExtractTableParams = serde_json::from_value(serde_json::json!({ "index": 3 })).unwrap();
+        assert_eq!(params.index, Some(3));
+        assert_eq!(params.format, TableFormat::Json);
+
+        let params: ExtractTableParams = serde_json::from_value(serde_json::json!({ "format": "csv" })).unwrap();
+        assert_eq!(params.format, TableFormat::Csv);
+        assert!(params.selector.is_none());
+    }
+}
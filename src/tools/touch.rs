@@ -0,0 +1,317 @@
+use crate::{browser::touch,
+            error::{BrowserError, Result},
+            tools::{Tool, ToolContext, ToolResult, mouse::PointerTarget}};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Longest a single gesture may take
+const MAX_GESTURE: Duration = Duration::from_secs(60);
+
+/// Direction a finger moves in a swipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SwipeDirection {
+    /// Finger moves up: scrolls the content down
+    Up,
+    /// Finger moves down: scrolls the content up, or pulls to refresh at the top
+    Down,
+    /// Finger moves left: shows the next carousel slide
+    Left,
+    /// Finger moves right: shows the previous carousel slide
+    Right,
+}
+
+/// Parameters for the tap tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TapParams {
+    #[serde(flatten)]
+    pub target: PointerTarget,
+
+    /// Number of taps, e.g. 2 for a double tap (default: 1)
+    #[serde(default = "default_tap_count")]
+    pub tap_count: u32,
+}
+
+/// Parameters for the long_press tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LongPressParams {
+    #[serde(flatten)]
+    pub target: PointerTarget,
+
+    /// How long to hold the finger down, in milliseconds (default: 800)
+    #[serde(default = "default_long_press_ms")]
+    pub duration_ms: u64,
+}
+
+/// Parameters for the swipe tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SwipeParams {
+    /// Start point (default: the viewport center)
+    #[serde(flatten)]
+    pub from: PointerTarget,
+
+    /// Direction the finger moves (use either this or to)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direction: Option<SwipeDirection>,
+
+    /// Distance to swipe in the direction, in CSS pixels (default: half the viewport)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+
+    /// End point: {"x", "y"} or an element {"selector"} / {"index"} (use either this or direction)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<PointerTarget>,
+
+    /// Finger speed in CSS pixels per second; fast swipes fling (default: 1000)
+    #[serde(default = "default_velocity")]
+    pub velocity: f64,
+}
+
+/// Parameters for the pinch tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PinchParams {
+    /// Center of the pinch (default: the viewport center)
+    #[serde(flatten)]
+    pub center: PointerTarget,
+
+    /// Zoom factor: above 1 spreads the fingers (zoom in), below 1 brings them together (zoom out)
+    pub scale: f64,
+
+    /// Duration of the gesture, in milliseconds (default: 300)
+    #[serde(default = "default_pinch_ms")]
+    pub duration_ms: u64,
+}
+
+fn default_tap_count() -> u32 {
+    1
+}
+
+fn default_long_press_ms() -> u64 {
+    800
+}
+
+fn default_velocity() -> f64 {
+    1000.0
+}
+
+fn default_pinch_ms() -> u64 {
+    300
+}
+
+/// Viewport size in CSS pixels
+fn viewport_size(tool: &str, context: &mut ToolContext) -> Result<(f64, f64)> {
+    let result = context
+        .tab()?
+        .evaluate("JSON.stringify([innerWidth, innerHeight])", false)
+        .map_err(|e| BrowserError::ToolExecutionFailed { tool: tool.to_string(), reason: e.to_string() })?;
+    result
+        .value
+        .and_then(|v| v.as_str().and_then(|s| serde_json::from_str::<(f64, f64)>(s).ok()))
+        .ok_or_else(|| BrowserError::ToolExecutionFailed {
+            tool: tool.to_string(),
+            reason: "Failed to read the viewport size".to_string(),
+        })
+}
+
+/// The target point, or the viewport center when no target was given
+fn point_or_center(target: &PointerTarget, tool: &str, context: &mut ToolContext) -> Result<(f64, f64)> {
+    match target.resolve(tool, context)? {
+        Some(point) => Ok(point),
+        None => {
+            let (width, height) = viewport_size(tool, context)?;
+            Ok((width / 2.0, height / 2.0))
+        }
+    }
+}
+
+/// How long a swipe from `from` to `to` takes at `velocity`, capped at a minute
+fn swipe_duration(from: (f64, f64), to: (f64, f64), velocity: f64) -> Result<Duration> {
+    let length = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+    Duration::try_from_secs_f64(length / velocity)
+        .map(|duration| duration.min(MAX_GESTURE))
+        .map_err(|_| BrowserError::InvalidArgument("Swipe end point must be a finite coordinate".to_string()))
+}
+
+/// A requested gesture duration in milliseconds, capped at a minute
+fn gesture_duration(duration_ms: u64) -> Duration {
+    Duration::from_millis(duration_ms).min(MAX_GESTURE)
+}
+
+/// Tool for tapping with a finger
+#[derive(Default)]
+pub struct TapTool;
+
+impl Tool for TapTool {
+    type Params = TapParams;
+
+    fn name(&self) -> &str {
+        "tap"
+    }
+
+    fn execute_typed(&self, params: TapParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let (x, y) = params.target.require("tap", context)?;
+        let tab = context.tab()?;
+        touch::tap(&tab, x, y, params.tap_count)?;
+        Ok(ToolResult::success_with(serde_json::json!({ "x": x, "y": y, "tap_count": params.tap_count.max(1) })))
+    }
+}
+
+/// Tool for pressing and holding with a finger
+#[derive(Default)]
+pub struct LongPressTool;
+
+impl Tool for LongPressTool {
+    type Params = LongPressParams;
+
+    fn name(&self) -> &str {
+        "long_press"
+    }
+
+    fn execute_typed(&self, params: LongPressParams, context: &mut ToolContext) -> Result<ToolResult> {
+        let (x, y) = params.target.require("long_press", context)?;
+        let tab = context.tab()?;
+        let duration = gesture_duration(params.duration_ms);
+        touch::press(&tab, &[(x, y)], duration)?;
+        Ok(ToolResult::success_with(serde_json::json!({ "x": x, "y": y, "duration_ms": duration.as_millis() as u64 })))
+    }
+}
+
+/// Tool for swiping with a finger
+#[derive(Default)]
+pub struct SwipeTool;
+
+impl Tool for SwipeTool {
+    type Params = SwipeParams;
+
+    fn name(&self) -> &str {
+        "swipe"
+    }
+
+    fn execute_typed(&self, params: SwipeParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if !params.velocity.is_finite() || params.velocity < 1.0 {
+            return Err(BrowserError::InvalidArgument("'velocity' must be at least 1 pixel per second".to_string()));
+        }
+        let from = point_or_center(&params.from, "swipe", context)?;
+        let tab = context.tab()?;
+        let to = match (params.direction, &params.to) {
+            (Some(_), Some(_)) => {
+                return Err(BrowserError::InvalidArgument(
+                    "Cannot specify both 'direction' and 'to'. Use one or the other.".to_string(),
+                ));
+            }
+            (None, None) => {
+                return Err(BrowserError::InvalidArgument("Must specify either 'direction' or 'to'.".to_string()));
+            }
+            (None, Some(to)) => {
+                // Put the finger down before resolving an element target: scrolling it into view moves `from`
+                touch::touch_down(&tab, &[from])?;
+                let resolved = to
+                    .require("swipe", context)
+                    .and_then(|to| Ok((to, swipe_duration(from, to, params.velocity)?)));
+                let (to, duration) = match resolved {
+                    Ok(resolved) => resolved,
+                    Err(e) => {
+                        touch::lift(&tab)?;
+                        return Err(e);
+                    }
+                };
+                touch::glide(&tab, &[from], &[to], duration)?;
+                return Ok(swipe_result(from, to, duration));
+            }
+            (Some(direction), None) => {
+                let (width, height) = viewport_size("swipe", context)?;
+                let distance = |size: f64| params.distance.unwrap_or(size / 2.0);
+                match direction {
+                    SwipeDirection::Up => (from.0, from.1 - distance(height)),
+                    SwipeDirection::Down => (from.0, from.1 + distance(height)),
+                    SwipeDirection::Left => (from.0 - distance(width), from.1),
+                    SwipeDirection::Right => (from.0 + distance(width), from.1),
+                }
+            }
+        };
+
+        let duration = swipe_duration(from, to, params.velocity)?;
+        touch::gesture(&tab, &[from], &[to], duration)?;
+        Ok(swipe_result(from, to, duration))
+    }
+}
+
+fn swipe_result(from: (f64, f64), to: (f64, f64), duration: Duration) -> ToolResult {
+    ToolResult::success_with(serde_json::json!({
+        "from": { "x": from.0, "y": from.1 },
+        "to": { "x": to.0, "y": to.1 },
+        "duration_ms": duration.as_millis() as u64,
+    }))
+}
+
+/// Tool for pinching with two fingers
+#[derive(Default)]
+pub struct PinchTool;
+
+impl Tool for PinchTool {
+    type Params = PinchParams;
+
+    fn name(&self) -> &str {
+        "pinch"
+    }
+
+    fn execute_typed(&self, params: PinchParams, context: &mut ToolContext) -> Result<ToolResult> {
+        if params.scale <= 0.0 || !params.scale.is_finite() {
+            return Err(BrowserError::InvalidArgument("'scale' must be a positive number".to_string()));
+        }
+        let center = point_or_center(&params.center, "pinch", context)?;
+        let tab = context.tab()?;
+        touch::pinch(&tab, center, params.scale, gesture_duration(params.duration_ms))?;
+
+        Ok(ToolResult::success_with(serde_json::json!({
+            "x": center.0,
+            "y": center.1,
+            "scale": params.scale,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_touch_params() {
+        let params: TapParams = serde_json::from_value(serde_json::json!({ "index": 3 })).unwrap();
+        assert_eq!(params.target.index, Some(3));
+        assert_eq!(params.tap_count, 1);
+
+        let params: SwipeParams =
+            serde_json::from_value(serde_json::json!({ "selector": "#carousel", "direction": "left" })).unwrap();
+        assert_eq!(params.from.selector.as_deref(), Some("#carousel"));
+        assert_eq!(params.direction, Some(SwipeDirection::Left));
+        assert_eq!(params.velocity, 1000.0);
+
+        let params: SwipeParams = serde_json::from_value(serde_json::json!({
+            "x": 10, "y": 20, "to": { "x": 10, "y": 400 }, "velocity": 300
+        }))
+        .unwrap();
+        assert_eq!(params.to.unwrap().y, Some(400.0));
+
+        let params: PinchParams = serde_json::from_value(serde_json::json!({ "scale": 0.5 })).unwrap();
+        assert_eq!(params.scale, 0.5);
+        assert_eq!(params.duration_ms, 300);
+        assert!(params.center.x.is_none());
+    }
+
+    #[test]
+    fn test_swipe_duration() {
+        assert_eq!(swipe_duration((0.0, 0.0), (0.0, 500.0), 1000.0).unwrap(), Duration::from_millis(500));
+        assert_eq!(swipe_duration((0.0, 0.0), (3.0, 4.0), 1.0).unwrap(), Duration::from_secs(5));
+        assert_eq!(swipe_duration((0.0, 0.0), (0.0, 1e9), 1.0).unwrap(), MAX_GESTURE);
+        assert!(swipe_duration((0.0, 0.0), (f64::INFINITY, 0.0), 1000.0).is_err());
+    }
+
+    #[test]
+    fn test_gesture_duration() {
+        assert_eq!(gesture_duration(800), Duration::from_millis(800));
+        assert_eq!(gesture_duration(16 << 32), MAX_GESTURE);
+        assert_eq!(gesture_duration(u64::MAX), MAX_GESTURE);
+    }
+}
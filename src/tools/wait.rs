@@ -32,7 +32,6 @@ impl Tool for WaitTool {
         let start = std::time::Instant::now();
 
         context
-            .session
             .tab()?
             .wait_for_element_with_custom_timeout(&params.selector, Duration::from_millis(params.timeout_ms))
             .map_err(|e| {
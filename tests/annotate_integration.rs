@@ -0,0 +1,89 @@
+use browser_use::{BrowserSession, LaunchOptions};
+
+/// Test annotating the full page with role filters and the returned element list
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_annotate_full_page_and_filters() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <button id="top">Top</button>
+        <a href="/about">About</a>
+        <div style="height: 3000px"></div>
+        <button id="bottom">Bottom</button>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    // The viewport only shows the top of the page
+    let result = session.execute_tool("annotate", serde_json::json!({ "return_base64": true })).unwrap();
+    let data = result.data.unwrap();
+    let names: Vec<&str> = data["elements"].as_array().unwrap().iter().map(|e| e["name"].as_str().unwrap()).collect();
+    assert!(names.contains(&"Top"));
+    assert!(names.contains(&"About"));
+    assert!(!names.contains(&"Bottom"));
+    assert!(!data["image_base64"].as_str().unwrap().is_empty());
+
+    // Full page with a role filter labels both buttons and nothing else
+    let result =
+        session.execute_tool("annotate", serde_json::json!({ "full_page": true, "roles": ["button"] })).unwrap();
+    let data = result.data.unwrap();
+    let elements = data["elements"].as_array().unwrap();
+    assert_eq!(elements.len(), 2, "{:?}", elements);
+    assert!(elements.iter().all(|e| e["role"] == "button"));
+    let bottom = elements.iter().find(|e| e["name"] == "Bottom").unwrap();
+    assert!(bottom["rect"]["y"].as_f64().unwrap() > 3000.0);
+    assert!(bottom["selector"].is_string());
+
+    // Index filter
+    let index = bottom["index"].as_u64().unwrap();
+    let path = std::env::temp_dir().join("browser_use_annotate_test.png");
+    let result = session
+        .execute_tool("annotate", serde_json::json!({ "full_page": true, "indices": [index], "path": path }))
+        .unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data["count"], 1);
+    assert!(data["map"][index.to_string()].is_string());
+    assert!(std::fs::read(&path).unwrap().starts_with(b"\x89PNG"));
+    let _ = std::fs::remove_file(&path);
+}
+
+/// Test the grid overlay and mapping points and grid cells back to snapshot indices
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_grid_and_element_at_point() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <style>body { margin: 0 } button { position: absolute; width: 80px; height: 40px }</style>
+        <button id="a" style="left: 210px; top: 310px" onclick="this.textContent = 'Hit'">Alpha</button>
+        <button id="b" style="left: 10px; top: 10px">Beta</button>
+        <button id="c" style="left: 10px; top: 1510px">Gamma</button>
+        <div style="height: 2000px"></div>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    let result = session.execute_tool("annotate", serde_json::json!({ "grid": true, "return_base64": true })).unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data["grid_size"], 100);
+
+    // Cell C4 is centered on (250, 350), inside the Alpha button
+    let result = session.execute_tool("element_at_point", serde_json::json!({ "cell": "C4" })).unwrap();
+    let hit = result.data.unwrap();
+    assert_eq!(hit["name"], "Alpha");
+    assert_eq!(hit["role"], "button");
+    assert!(hit["index"].is_u64());
+
+    let result = session.execute_tool("element_at_point", serde_json::json!({ "x": 30, "y": 20 })).unwrap();
+    assert_eq!(result.data.unwrap()["name"], "Beta");
+
+    // Empty space has no indexed element
+    let result = session.execute_tool("element_at_point", serde_json::json!({ "x": 600, "y": 500 })).unwrap();
+    assert!(result.data.unwrap()["index"].is_null());
+
+    // Page coordinates below the fold are scrolled into view
+    let result =
+        session.execute_tool("element_at_point", serde_json::json!({ "x": 50, "y": 1530, "full_page": true })).unwrap();
+    assert_eq!(result.data.unwrap()["name"], "Gamma");
+
+    session.execute_tool("click_at_mark", serde_json::json!({ "cell": "C4", "full_page": true })).unwrap();
+    let text = session.tab().unwrap().evaluate("document.getElementById('a').textContent", false).unwrap();
+    assert_eq!(text.value.unwrap(), "Hit");
+}
@@ -40,7 +40,11 @@ fn test_select_tool() {
     // Execute the tool to select an option
     let result = tool
         .execute_typed(
-            SelectParams { selector: Some("#country".to_string()), index: None, value: "uk".to_string() },
+            SelectParams {
+                selector: Some("#country".to_string()),
+                value: Some("uk".to_string()),
+                ..Default::default()
+            },
             &mut context,
         )
         .expect("Failed to execute select tool");
@@ -128,8 +132,9 @@ fn test_scroll_tool_with_amount() {
     let mut context = ToolContext::new(&session);
 
     // Execute the tool to scroll down 500 pixels
-    let result =
-        tool.execute_typed(ScrollParams { amount: Some(500) }, &mut context).expect("Failed to execute scroll tool");
+    let result = tool
+        .execute_typed(ScrollParams { amount: Some(500), ..Default::default() }, &mut context)
+        .expect("Failed to execute scroll tool");
 
     // Verify the result
     assert!(result.success, "Tool execution should succeed");
@@ -169,8 +174,9 @@ fn test_scroll_tool_to_bottom() {
 
     // Execute the tool multiple times to reach bottom
     for _ in 0..10 {
-        let result =
-            tool.execute_typed(ScrollParams { amount: None }, &mut context).expect("Failed to execute scroll tool");
+        let result = tool
+            .execute_typed(ScrollParams { amount: None, ..Default::default() }, &mut context)
+            .expect("Failed to execute scroll tool");
 
         assert!(result.success);
 
@@ -220,8 +226,10 @@ fn test_select_with_index() {
     let mut context = ToolContext::new(&session);
 
     // Try to select using index (the select element should have index 0 since it's the first interactive element)
-    let result =
-        tool.execute_typed(SelectParams { selector: None, index: Some(0), value: "green".to_string() }, &mut context);
+    let result = tool.execute_typed(
+        SelectParams { index: Some(0), value: Some("green".to_string()), ..Default::default() },
+        &mut context,
+    );
 
     // This might fail if DOM indexing doesn't include select elements, which is acceptable
     // The test is mainly to verify the API works
@@ -231,3 +239,187 @@ fn test_select_with_index() {
         info!("Select with index failed (may be expected if select not indexed)");
     }
 }
+
+#[test]
+#[ignore]
+fn test_input_set_value_mode() {
+    use browser_use::tools::{InputMode, InputParams, input::InputTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    // The "controlled" input shadows `value` on the instance like framework value trackers do,
+    // so only the native prototype setter reaches the DOM
+    let html = r#"
+        <!DOCTYPE html>
+        <html>
+        <body>
+            <input id="controlled" type="text">
+            <input id="volume" type="range" min="0" max="100" step="5">
+            <input id="due" type="date">
+            <div id="editor" contenteditable="true">Old text</div>
+            <div id="log"></div>
+            <script>
+                const controlled = document.getElementById('controlled');
+                Object.defineProperty(controlled, 'value', { get() { return ''; }, set(v) {} });
+                controlled.addEventListener('input', function(e) {
+                    const native = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value');
+                    document.getElementById('log').textContent = native.get.call(e.target);
+                });
+            </script>
+        </body>
+        </html>
+    "#;
+
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let tool = InputTool;
+    let mut context = ToolContext::new(&session);
+
+    let set = |context: &mut ToolContext, selector: &str, text: &str| {
+        tool.execute_typed(
+            InputParams {
+                selector: Some(selector.to_string()),
+                index: None,
+                text: text.to_string(),
+                clear: false,
+                mode: InputMode::SetValue,
+            },
+            context,
+        )
+        .expect("Failed to set value")
+        .data
+        .unwrap()
+    };
+
+    set(&mut context, "#controlled", "hello");
+    let log = session.tab().unwrap().evaluate("document.getElementById('log').textContent", false).unwrap();
+    assert_eq!(log.value.unwrap().as_str(), Some("hello"));
+
+    let range = set(&mut context, "#volume", "42");
+    info!("Range result: {}", range);
+    assert_eq!(range["value"].as_str(), Some("40"));
+    assert_eq!(range["verified"].as_bool(), Some(true));
+
+    let date = set(&mut context, "#due", "2024-02-29");
+    assert_eq!(date["verified"].as_bool(), Some(true));
+
+    let editor = set(&mut context, "#editor", "New text");
+    assert_eq!(editor["kind"].as_str(), Some("contenteditable"));
+    assert_eq!(editor["value"].as_str(), Some("New text"));
+}
+
+#[test]
+#[ignore]
+fn test_select_by_label_and_multiple() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <!DOCTYPE html>
+        <html>
+        <body>
+            <select id="size">
+                <option value="s">Small</option>
+                <option value="m">Medium</option>
+                <option value="l">Large</option>
+            </select>
+            <select id="toppings" multiple>
+                <option value="cheese">Cheese</option>
+                <option value="ham">Ham</option>
+                <option value="olives">Olives</option>
+            </select>
+        </body>
+        </html>
+    "#;
+
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let tool = SelectTool;
+    let mut context = ToolContext::new(&session);
+
+    let by_label = tool
+        .execute_typed(
+            SelectParams {
+                selector: Some("#size".to_string()),
+                label: Some("Large".to_string()),
+                ..Default::default()
+            },
+            &mut context,
+        )
+        .expect("Failed to select by label");
+    assert_eq!(by_label.data.unwrap()["value"].as_str(), Some("l"));
+
+    let by_position = tool
+        .execute_typed(
+            SelectParams { selector: Some("#size".to_string()), option_index: Some(1), ..Default::default() },
+            &mut context,
+        )
+        .expect("Failed to select by option index");
+    assert_eq!(by_position.data.unwrap()["value"].as_str(), Some("m"));
+
+    let multiple = tool
+        .execute_typed(
+            SelectParams {
+                selector: Some("#toppings".to_string()),
+                values: Some(vec!["cheese".to_string(), "Olives".to_string()]),
+                ..Default::default()
+            },
+            &mut context,
+        )
+        .expect("Failed to select multiple values");
+    let data = multiple.data.unwrap();
+    info!("Multi-select result: {}", data);
+    let selected: Vec<&str> =
+        data["selected"].as_array().unwrap().iter().map(|o| o["value"].as_str().unwrap()).collect();
+    assert_eq!(selected, vec!["cheese", "olives"]);
+}
+
+#[test]
+#[ignore]
+fn test_set_checked_tool() {
+    use browser_use::tools::{SetCheckedParams, set_checked::SetCheckedTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <!DOCTYPE html>
+        <html>
+        <body>
+            <label><input id="terms" type="checkbox"> Accept terms</label>
+            <div id="dark" role="switch" aria-checked="false" tabindex="0">Dark mode</div>
+            <script>
+                const dark = document.getElementById('dark');
+                dark.addEventListener('click', function() {
+                    dark.setAttribute('aria-checked', dark.getAttribute('aria-checked') === 'true' ? 'false' : 'true');
+                });
+            </script>
+        </body>
+        </html>
+    "#;
+
+    session.navigate(&format!("data:text/html,{}", html)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let tool = SetCheckedTool;
+    let mut context = ToolContext::new(&session);
+
+    let check = |context: &mut ToolContext, selector: &str, checked: bool| {
+        tool.execute_typed(SetCheckedParams { selector: Some(selector.to_string()), index: None, checked }, context)
+            .expect("Failed to set checked state")
+            .data
+            .unwrap()
+    };
+
+    let first = check(&mut context, "#terms", true);
+    assert_eq!(first["checked"].as_bool(), Some(true));
+    assert_eq!(first["changed"].as_bool(), Some(true));
+
+    // Idempotent: asking again must not toggle it back
+    let second = check(&mut context, "#terms", true);
+    assert_eq!(second["checked"].as_bool(), Some(true));
+    assert_eq!(second["changed"].as_bool(), Some(false));
+
+    let switch = check(&mut context, "#dark", true);
+    assert_eq!(switch["checked"].as_bool(), Some(true));
+}
@@ -0,0 +1,82 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{CompareScreenshotParams, Tool, ToolContext, compare_screenshot::CompareScreenshotTool}};
+use log::info;
+
+fn open_page(session: &BrowserSession, banner: &str) {
+    let html = format!(
+        r#"<html><body style="margin:0">
+            <h1 id="heading" style="background:#036;color:#fff;padding:20px">Dashboard</h1>
+            <p id="clock">Updated {}</p>
+        </body></html>"#,
+        banner
+    );
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(&html))).expect("Failed to navigate");
+}
+
+fn params(baseline: &std::path::Path) -> CompareScreenshotParams {
+    serde_json::from_value(serde_json::json!({ "baseline": baseline.to_string_lossy() })).unwrap()
+}
+
+/// Test baseline creation, a clean match and a masked change
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_compare_screenshot_baseline_and_mask() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let dir = std::env::temp_dir().join("browser_use_visual_test");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let baseline = dir.join("dashboard.png");
+
+    open_page(&session, "10:00");
+    let mut context = ToolContext::new(&session);
+
+    // First run creates the baseline
+    let result = CompareScreenshotTool.execute_typed(params(&baseline), &mut context).unwrap();
+    assert_eq!(result.data.unwrap()["baseline_created"], true);
+    assert!(baseline.exists());
+
+    // Same page matches
+    let data = CompareScreenshotTool.execute_typed(params(&baseline), &mut context).unwrap().data.unwrap();
+    assert_eq!(data["matches"], true);
+    assert!(dir.join("dashboard.diff.png").exists());
+
+    // A changed clock is a mismatch unless masked
+    open_page(&session, "11:45");
+    let mut context = ToolContext::new(&session);
+    let data = CompareScreenshotTool.execute_typed(params(&baseline), &mut context).unwrap().data.unwrap();
+    info!("Unmasked comparison: {}", data);
+    assert_eq!(data["matches"], false);
+    assert!(data["mismatch_percent"].as_f64().unwrap() > 0.0);
+
+    let masked = CompareScreenshotParams { mask: vec!["#clock".to_string()], ..params(&baseline) };
+    let data = CompareScreenshotTool.execute_typed(masked, &mut context).unwrap().data.unwrap();
+    assert_eq!(data["matches"], true);
+    assert!(data["masked_pixels"].as_u64().unwrap() > 0);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+/// Test comparing a single element
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_compare_screenshot_element() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let baseline = std::env::temp_dir().join("browser_use_visual_heading.png");
+    let _ = std::fs::remove_file(&baseline);
+
+    open_page(&session, "10:00");
+    let mut context = ToolContext::new(&session);
+    let heading = CompareScreenshotParams { selector: Some("#heading".to_string()), ..params(&baseline) };
+    let data = CompareScreenshotTool.execute_typed(heading.clone(), &mut context).unwrap().data.unwrap();
+    assert_eq!(data["baseline_created"], true);
+
+    // The heading is unaffected by the clock changing
+    open_page(&session, "11:45");
+    let mut context = ToolContext::new(&session);
+    let data = CompareScreenshotTool.execute_typed(heading, &mut context).unwrap().data.unwrap();
+    assert_eq!(data["matches"], true);
+    assert_eq!(data["size_mismatch"], false);
+
+    let _ = std::fs::remove_file(&baseline);
+    let _ = std::fs::remove_file(baseline.with_extension("diff.png"));
+}
@@ -0,0 +1,153 @@
+use browser_use::{BrowserError, BrowserSession, CrawlOptions, CrawledPage, Crawler, LaunchOptions};
+use std::{io::{BufRead, BufReader, Write},
+          net::TcpListener,
+          thread,
+          time::Duration};
+
+/// Serve a tiny site on localhost: / links to /a and /private/b, /a links back and to /c
+fn serve_site() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap_or_default();
+            let path = request_line.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+            let (status, content_type, body) = match path.as_str() {
+                "/robots.txt" => ("200 OK", "text/plain", "User-agent: *\nDisallow: /private/\n".to_string()),
+                "/" => ("200 OK", "text/html", page("Home", &["/a", "/private/b", "mailto:x@example.com"])),
+                "/a" => ("200 OK", "text/html", page("Page A", &["/", "/c#section"])),
+                "/c" => ("200 OK", "text/html", page("Page C", &["/d"])),
+                _ => ("404 Not Found", "text/html", page("Not found", &[])),
+            };
+
+            let mut stream = stream;
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                content_type,
+                body.len(),
+                body
+            );
+        }
+    });
+
+    address
+}
+
+fn page(title: &str, links: &[&str]) -> String {
+    let anchors: String = links.iter().map(|href| format!("<p><a href=\"{}\">{}</a></p>", href, href)).collect();
+    format!(
+        "<html><head><title>{}</title></head><body><article><h1>{}</h1>\
+         <p>Some text about {} that is long enough for Readability to keep.</p>{}</article></body></html>",
+        title, title, title, anchors
+    )
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_crawler_depth_and_robots() {
+    let base = serve_site();
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let options = CrawlOptions::new([format!("{}/", base)]).max_depth(2).delay(Duration::from_millis(10));
+    let crawler = Crawler::new(&session, options).expect("Failed to create crawler");
+
+    let mut pages: Vec<CrawledPage> = Vec::new();
+    let summary = crawler
+        .run(|page| {
+            pages.push(page.clone());
+            Ok(())
+        })
+        .expect("Crawl failed");
+
+    let mut urls: Vec<String> = pages.iter().map(|p| p.url.trim_start_matches(&base).to_string()).collect();
+    urls.sort();
+    // /private/b is disallowed by robots.txt and /d is beyond max_depth
+    assert_eq!(urls, vec!["/", "/a", "/c"]);
+    assert_eq!(summary.pages, 3);
+
+    let home = pages.iter().find(|p| p.url == format!("{}/", base)).unwrap();
+    assert_eq!(home.title, "Home");
+    assert_eq!(home.status, Some(200));
+    assert!(home.markdown.contains("Some text about Home"));
+    assert!(!home.links.iter().any(|l| l.starts_with("mailto:")));
+}
+
+#[test]
+#[ignore]
+fn test_crawler_resumes_from_state_file() {
+    let base = serve_site();
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let state_file = std::env::temp_dir().join(format!("crawl-state-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&state_file);
+
+    let options = CrawlOptions::new([format!("{}/", base)])
+        .max_depth(2)
+        .delay(Duration::from_millis(10))
+        .respect_robots(false)
+        .state_file(state_file.clone());
+
+    // First run stops after one page
+    let first = Crawler::new(&session, options.clone().max_pages(1)).unwrap().run(|_| Ok(())).unwrap();
+    assert_eq!(first.pages, 1);
+    assert!(first.remaining > 0);
+
+    // Second run continues from the saved queue instead of starting over
+    let mut urls = Vec::new();
+    let second = Crawler::new(&session, options.max_pages(10))
+        .unwrap()
+        .run(|page| {
+            urls.push(page.url.clone());
+            Ok(())
+        })
+        .unwrap();
+    assert!(!urls.contains(&format!("{}/", base)));
+    assert_eq!(second.total_pages, first.pages + second.pages);
+
+    let _ = std::fs::remove_file(&state_file);
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_crawler_resume_after_interrupt_keeps_in_flight_pages() {
+    let base = serve_site();
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let state_file = std::env::temp_dir().join(format!("crawl-interrupt-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&state_file);
+
+    let options = CrawlOptions::new([format!("{}/", base)])
+        .max_depth(2)
+        .delay(Duration::from_millis(10))
+        .respect_robots(false)
+        .concurrency(2)
+        .state_file(state_file.clone());
+
+    // Interrupt on the second page, while the other tab is still crawling /a or /private/b
+    let mut urls: Vec<String> = Vec::new();
+    let result = Crawler::new(&session, options.clone()).unwrap().run(|page| {
+        if !urls.is_empty() {
+            return Err(BrowserError::ToolExecutionFailed { tool: "test".into(), reason: "interrupted".into() });
+        }
+        urls.push(page.url.trim_start_matches(&base).to_string());
+        Ok(())
+    });
+    assert!(result.is_err());
+
+    // The resumed crawl delivers every page the first run did not, exactly once
+    Crawler::new(&session, options)
+        .unwrap()
+        .run(|page| {
+            urls.push(page.url.trim_start_matches(&base).to_string());
+            Ok(())
+        })
+        .unwrap();
+    urls.sort();
+    assert_eq!(urls, vec!["/", "/a", "/c", "/private/b"]);
+
+    let _ = std::fs::remove_file(&state_file);
+}
@@ -168,3 +168,47 @@ fn test_press_key_enter() {
     // Note: Due to limitations with data: URLs and event handling,
     // we mainly verify that the tool executes without error
 }
+
+#[test]
+#[ignore]
+fn test_snapshot_include_styles() {
+    use browser_use::tools::{SnapshotParams, Tool, ToolContext, snapshot::SnapshotTool};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    session
+        .navigate(concat!(
+            "data:text/html,<html><body>",
+            "<button style='background:rgb(0, 102, 204);color:white;font-weight:700'>Sign up</button>",
+            "<a href='#' style='opacity:0.5'>Maybe later</a>",
+            "</body></html>"
+        ))
+        .expect("Failed to navigate");
+
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let dom = session.extract_dom_with_styles().expect("Failed to extract DOM with styles");
+    let button =
+        dom.interactive_indices().into_iter().filter_map(|i| dom.find_node_by_index(i)).find(|n| n.role == "button");
+    let styles = button.and_then(|b| b.styles.clone()).expect("Button should carry computed styles");
+    assert_eq!(styles.background_color, "rgb(0, 102, 204)");
+    assert_eq!(styles.font_weight, "700");
+
+    let tool = SnapshotTool;
+    let mut context = ToolContext::new(&session);
+    let result = tool
+        .execute_typed(SnapshotParams { incremental: false, include_styles: true }, &mut context)
+        .expect("Failed to execute snapshot tool");
+
+    let snapshot = result.data.unwrap()["snapshot"].as_str().unwrap().to_string();
+    info!("Styled snapshot:\n{}", snapshot);
+    assert!(snapshot.contains("bg:rgb(0, 102, 204)"));
+    assert!(snapshot.contains("opacity:0.5"));
+
+    // The styled tree is not cached, so a later plain snapshot has no styles
+    let result = tool
+        .execute_typed(SnapshotParams { incremental: false, include_styles: false }, &mut context)
+        .expect("Failed to execute snapshot tool");
+    let snapshot = result.data.unwrap()["snapshot"].as_str().unwrap().to_string();
+    assert!(!snapshot.contains("opacity:0.5"));
+}
@@ -0,0 +1,95 @@
+use browser_use::{BrowserSession, Device, Emulation, LaunchOptions, browser::NetworkConditions};
+
+fn eval(session: &BrowserSession, js: &str) -> serde_json::Value {
+    session.tab().unwrap().evaluate(js, false).unwrap().value.unwrap()
+}
+
+/// Test device presets from launch options and the set_viewport tool
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_device_and_viewport() {
+    let options = LaunchOptions::new().headless(true).device(Device::preset("iphone_15").unwrap());
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    session.navigate("data:text/html,<meta name=viewport content='width=device-width'><p>hi</p>").unwrap();
+
+    assert_eq!(eval(&session, "innerWidth"), 393);
+    assert_eq!(eval(&session, "devicePixelRatio"), 3);
+    assert_eq!(eval(&session, "navigator.maxTouchPoints > 0"), true);
+    assert!(eval(&session, "navigator.userAgent").as_str().unwrap().contains("iPhone"));
+
+    let result = session.execute_tool("set_viewport", serde_json::json!({ "width": 1024, "height": 600 })).unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data["page"]["innerWidth"], 1024);
+    assert_eq!(data["page"]["touch"], false);
+    assert!(!data["page"]["userAgent"].as_str().unwrap().contains("iPhone"));
+
+    let result = session.execute_tool("set_viewport", serde_json::json!({ "device": "nokia_3310" }));
+    assert!(result.is_err() || !result.unwrap().success);
+}
+
+/// Test media features, timezone, locale and geolocation overrides
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_emulate_media_locale_and_geolocation() {
+    let options = LaunchOptions::new().headless(true).emulation(Emulation::new().timezone("Asia/Tokyo"));
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    session.navigate("data:text/html,<p>hi</p>").unwrap();
+    assert_eq!(eval(&session, "Intl.DateTimeFormat().resolvedOptions().timeZone"), "Asia/Tokyo");
+
+    let params = serde_json::json!({
+        "color_scheme": "dark",
+        "reduced_motion": true,
+        "media": "print",
+        "locale": "de-DE",
+        "geolocation": { "latitude": 52.52, "longitude": 13.405 }
+    });
+    session.execute_tool("emulate", params).unwrap();
+
+    assert_eq!(eval(&session, "matchMedia('(prefers-color-scheme: dark)').matches"), true);
+    assert_eq!(eval(&session, "matchMedia('(prefers-reduced-motion: reduce)').matches"), true);
+    assert_eq!(eval(&session, "matchMedia('print').matches"), true);
+    assert_eq!(eval(&session, "navigator.language"), "de-DE");
+    assert_eq!(eval(&session, "Intl.DateTimeFormat().resolvedOptions().timeZone"), "Asia/Tokyo");
+
+    let position = session
+        .tab()
+        .unwrap()
+        .evaluate(
+            "new Promise(r => navigator.geolocation.getCurrentPosition(p => r(p.coords.latitude), e => r(e.message)))",
+            true,
+        )
+        .unwrap()
+        .value
+        .unwrap();
+    assert_eq!(position, 52.52);
+
+    session.execute_tool("emulate", serde_json::json!({ "reset": true })).unwrap();
+    assert_eq!(eval(&session, "matchMedia('print').matches"), false);
+}
+
+/// Test network and CPU throttling from launch options and the emulate_network/emulate_cpu tools
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_network_and_cpu_throttling() {
+    let options = LaunchOptions::new().headless(true).network(NetworkConditions::offline());
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    session.navigate("data:text/html,<p>hi</p>").unwrap();
+    assert_eq!(eval(&session, "navigator.onLine"), false);
+
+    session.execute_tool("emulate_network", serde_json::json!({ "preset": "slow-3g" })).unwrap();
+    assert_eq!(eval(&session, "navigator.onLine"), true);
+    assert_eq!(eval(&session, "navigator.connection ? navigator.connection.rtt >= 2000 : true"), true);
+
+    let busy = "(() => { const t = performance.now(); let x = 0; for (let i = 0; i < 3e7; i++) x += i; \
+                return performance.now() - t; })()";
+    let baseline = eval(&session, busy).as_f64().unwrap();
+    session.execute_tool("emulate_cpu", serde_json::json!({ "rate": 6 })).unwrap();
+    let throttled = eval(&session, busy).as_f64().unwrap();
+    assert!(throttled > baseline * 2.0, "throttled {} vs baseline {}", throttled, baseline);
+
+    let result = session.execute_tool("emulate_cpu", serde_json::json!({ "rate": 0.5 }));
+    assert!(result.is_err() || !result.unwrap().success);
+
+    session.execute_tool("emulate_network", serde_json::json!({ "preset": "none" })).unwrap();
+    session.execute_tool("emulate_cpu", serde_json::json!({ "rate": 1 })).unwrap();
+}
@@ -0,0 +1,117 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{FillFormParams, GetFormsParams, Tool, ToolContext,
+                          forms::{FillFormTool, GetFormsTool}}};
+use log::info;
+
+const SIGNUP_FORM: &str = r#"
+    <!DOCTYPE html>
+    <html>
+    <body>
+        <form id="signup" action="/signup" method="post">
+            <label for="email">Email</label>
+            <input id="email" name="email" type="email" required>
+            <label for="bio">Bio</label>
+            <textarea id="bio" name="bio"></textarea>
+            <label for="country">Country</label>
+            <select id="country" name="country">
+                <option value="us">United States</option>
+                <option value="ca">Canada</option>
+            </select>
+            <label><input type="checkbox" name="newsletter"> Send me news</label>
+            <fieldset>
+                <legend>Plan</legend>
+                <label><input type="radio" name="plan" value="free"> Free</label>
+                <label><input type="radio" name="plan" value="pro"> Pro</label>
+            </fieldset>
+            <label for="birthday">Birthday</label>
+            <input id="birthday" name="birthday" type="date">
+            <button type="submit">Sign up</button>
+        </form>
+    </body>
+    </html>
+"#;
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_get_forms() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", SIGNUP_FORM)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let result = GetFormsTool.execute_typed(GetFormsParams {}, &mut context).expect("Failed to get forms");
+
+    let data = result.data.unwrap();
+    info!("Forms: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    assert_eq!(data["count"].as_u64(), Some(1));
+    let fields = data["forms"][0]["fields"].as_array().unwrap();
+    // email, bio, country, newsletter, plan (radio group), birthday
+    assert_eq!(fields.len(), 6);
+    assert_eq!(fields[0]["label"].as_str(), Some("Email"));
+    assert_eq!(fields[0]["required"].as_bool(), Some(true));
+    assert!(fields[0]["index"].is_u64());
+    assert_eq!(fields[2]["options"].as_array().unwrap().len(), 2);
+    assert_eq!(fields[4]["label"].as_str(), Some("Plan"));
+    assert_eq!(fields[4]["type"].as_str(), Some("radio"));
+}
+
+#[test]
+#[ignore]
+fn test_fill_form() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", SIGNUP_FORM)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let params: FillFormParams = serde_json::from_value(serde_json::json!({
+        "values": {
+            "Email": "jane@example.com",
+            "bio": "Hello",
+            "Country": "Canada",
+            "Send me news": true,
+            "Plan": "Pro",
+            "birthday": "1990-04-01",
+            "Missing field": "x"
+        }
+    }))
+    .unwrap();
+
+    let mut context = ToolContext::new(&session);
+    let result = FillFormTool.execute_typed(params, &mut context).expect("Failed to fill form");
+
+    let data = result.data.unwrap();
+    info!("Fill result: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    assert_eq!(data["filled"].as_u64(), Some(6));
+    assert_eq!(data["failed"].as_u64(), Some(1));
+
+    let tab = session.tab().unwrap();
+    let values = tab
+        .evaluate(
+            "[email.value, country.value, document.querySelector('[name=newsletter]').checked, \
+             document.querySelector('[name=plan]:checked').value, birthday.value].join('|')",
+            false,
+        )
+        .unwrap();
+    assert_eq!(values.value.unwrap().as_str(), Some("jane@example.com|ca|true|pro|1990-04-01"));
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_fill_form_submit_without_form() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"<input id="search" name="q" placeholder="Search">"#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).expect("Failed to navigate");
+
+    let params: FillFormParams =
+        serde_json::from_value(serde_json::json!({ "values": { "q": "rust" }, "submit": true })).unwrap();
+    let mut context = ToolContext::new(&session);
+    let result = FillFormTool.execute_typed(params, &mut context).expect("Failed to fill form");
+
+    // The field is still filled and reported, with a warning instead of a submit
+    let data = result.data.unwrap();
+    assert_eq!(data["filled"].as_u64(), Some(1));
+    assert_eq!(data["fields"][0]["selector"], "#search");
+    assert_eq!(data["submitted"], false);
+    assert!(data["warning"].as_str().is_some());
+}
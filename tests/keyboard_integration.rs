@@ -0,0 +1,42 @@
+use browser_use::{BrowserSession, LaunchOptions};
+
+fn eval(session: &BrowserSession, js: &str) -> serde_json::Value {
+    session.tab().unwrap().evaluate(js, false).unwrap().value.unwrap()
+}
+
+/// Test key chords, typing into the focused element and inserting text
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_keyboard_chords_and_typing() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <input id="a" value="old text"><input id="b">
+        <script>
+            window.chords = [];
+            document.addEventListener('keydown', e => {
+                if (e.ctrlKey && e.shiftKey) chords.push('ctrl+shift+' + e.key);
+            });
+            document.getElementById('a').focus();
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    // Select all and replace the focused field's content
+    session.execute_tool("keyboard", serde_json::json!({ "keys": ["Control+A", "Backspace"] })).unwrap();
+    session.execute_tool("keyboard", serde_json::json!({ "text": "Hi there!", "delay_ms": 5 })).unwrap();
+    assert_eq!(eval(&session, "document.getElementById('a').value"), "Hi there!");
+
+    session.execute_tool("keyboard", serde_json::json!({ "keys": ["Ctrl+Shift+K"] })).unwrap();
+    assert_eq!(eval(&session, "chords.join(' ')"), "ctrl+shift+K");
+
+    // Tab moves focus forward, Shift+Tab moves it back
+    session.execute_tool("keyboard", serde_json::json!({ "keys": ["Tab"] })).unwrap();
+    assert_eq!(eval(&session, "document.activeElement.id"), "b");
+    session.execute_tool("keyboard", serde_json::json!({ "text": "日本 👋", "insert_text": true })).unwrap();
+    assert_eq!(eval(&session, "document.getElementById('b').value"), "日本 👋");
+    session.execute_tool("keyboard", serde_json::json!({ "keys": ["Shift+Tab"] })).unwrap();
+    assert_eq!(eval(&session, "document.activeElement.id"), "a");
+
+    let result = session.execute_tool("keyboard", serde_json::json!({ "keys": ["Hyper+K"] }));
+    assert!(result.is_err() || !result.unwrap().success);
+}
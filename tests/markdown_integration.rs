@@ -172,6 +172,7 @@ fn test_markdown_pagination() {
             GetMarkdownParams {
                 page: 1,
                 page_size: 5000, // Small page size to force pagination
+                ..Default::default()
             },
             &mut context,
         )
@@ -200,6 +201,17 @@ fn test_markdown_pagination() {
     assert!(markdown.contains("Page 1 of"), "Should have pagination info");
     assert!(markdown.contains("more page"), "Should indicate more pages");
 
+    // Every page is listed in the table of contents
+    let toc = data["toc"].as_array().expect("Should have toc");
+    assert_eq!(toc.len() as u64, total_pages);
+    assert!(toc.iter().all(|entry| entry["size"].as_u64().unwrap() <= 5000));
+
+    // single_page returns the whole document without chunking
+    let params = GetMarkdownParams { page: 2, page_size: 5000, single_page: true, ..Default::default() };
+    let data = tool.execute_typed(params, &mut context).expect("Failed to execute markdown tool").data.unwrap();
+    assert_eq!(data["totalPages"].as_u64(), Some(1));
+    assert!(!data["markdown"].as_str().unwrap().contains("Page 1 of"));
+
     // Note: Testing second page in the same session sometimes fails due to
     // Readability caching. In production this works fine as each call is independent.
     // Uncomment below to test second page with a new session:
@@ -211,6 +223,7 @@ fn test_markdown_pagination() {
             GetMarkdownParams {
                 page: 2,
                 page_size: 5000,
+                ..Default::default()
             },
             &mut context,
         )
@@ -435,7 +448,7 @@ fn test_page_clamping() {
 
     // Request page 999 (way beyond available content)
     let result = tool
-        .execute_typed(GetMarkdownParams { page: 999, page_size: 100_000 }, &mut context)
+        .execute_typed(GetMarkdownParams { page: 999, page_size: 100_000, ..Default::default() }, &mut context)
         .expect("Failed to execute markdown tool");
 
     assert!(result.success);
@@ -446,3 +459,207 @@ fn test_page_clamping() {
     assert_eq!(data["totalPages"].as_u64(), Some(1));
     assert_eq!(data["hasMorePages"].as_bool(), Some(false));
 }
+
+/// Test that small pages over multi-byte text split on character boundaries without panicking
+#[test]
+#[ignore]
+fn test_markdown_pagination_multibyte() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let sections: String = (1..=4)
+        .map(|i| {
+            let text = "吾輩は猫である。名前はまだ無い。".repeat(20);
+            format!("<h2>第{}章</h2><p>{}</p><p>{}</p>", i, text, "🐱".repeat(50))
+        })
+        .collect();
+    let html = format!("<html><head><title>猫</title></head><body><article>{}</article></body></html>", sections);
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(&html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = GetMarkdownParams { page: 2, page_size: 200, ..Default::default() };
+    let result = GetMarkdownTool.execute_typed(params, &mut context).expect("Failed to execute markdown tool");
+
+    let data = result.data.unwrap();
+    let toc = data["toc"].as_array().expect("Should have toc");
+    assert!(toc.len() > 4);
+    assert!(toc.iter().any(|entry| entry["title"].as_str() == Some("第3章")));
+    assert_eq!(data["currentPage"].as_u64(), Some(2));
+}
+
+/// Test that links, buttons and inputs are annotated with their snapshot indices
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_with_indices() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <html><head><title>Plans</title></head>
+        <body>
+            <article>
+                <h1>Plans</h1>
+                <p>Compare our plans on the <a id="pricing" href="/pricing">Pricing</a> page before signing up.
+                   Every plan includes support, backups and a generous free tier for small teams.</p>
+                <p>Subscribe to the newsletter: <input type="email" placeholder="Email">
+                   <button>Subscribe</button></p>
+                <p>More text so that Readability keeps this article as the main content of the page.</p>
+            </article>
+        </body></html>
+    "#;
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = GetMarkdownParams { with_indices: true, ..Default::default() };
+    let result = GetMarkdownTool.execute_typed(params, &mut context).expect("Failed to execute markdown tool");
+
+    let data = result.data.unwrap();
+    let markdown = data["markdown"].as_str().expect("Should have markdown");
+    info!("Indexed markdown:\n{}", markdown);
+
+    assert!(data["indexed"].as_u64().unwrap_or(0) >= 1);
+
+    // The index next to the link must resolve to the link in the stored DomTree
+    let marker = markdown.split("(/pricing){#").nth(1).expect("Pricing link should be annotated");
+    let index: usize = marker.split('}').next().unwrap().parse().expect("Index should be a number");
+    let selector = context.get_dom().unwrap().get_selector(index).cloned().expect("Index should resolve");
+    let js = format!("document.querySelector({}).id", serde_json::to_string(&selector).unwrap());
+    let id = session.tab().unwrap().evaluate(&js, false).unwrap().value;
+    assert_eq!(id, Some(serde_json::json!("pricing")));
+
+    // Without the option the markdown has no markers
+    let mut context = ToolContext::new(&session);
+    let result = GetMarkdownTool.execute_typed(GetMarkdownParams::default(), &mut context).unwrap();
+    assert!(!result.data.unwrap()["markdown"].as_str().unwrap().contains("{#"));
+}
+
+/// Test fallback to <main> on app-like pages where Readability finds no article
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_fallback_to_main() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <html><head><title>Dashboard</title></head>
+        <body>
+            <nav><a href="/home">Home</a> <a href="/settings">Settings</a></nav>
+            <main>
+                <div class="card"><span>Revenue</span> <b>$12,400</b></div>
+                <div class="card"><span>Users</span> <b>318</b></div>
+            </main>
+            <footer>Copyright Example Inc.</footer>
+        </body></html>
+    "#;
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let result = GetMarkdownTool
+        .execute_typed(GetMarkdownParams::default(), &mut context)
+        .expect("Fallback extraction should succeed");
+
+    let data = result.data.unwrap();
+    let markdown = data["markdown"].as_str().expect("Should have markdown");
+    info!("Fallback result ({}):\n{}", data["strategy"], markdown);
+
+    assert!(markdown.contains("Revenue"));
+    assert!(markdown.contains("318"));
+    if data["strategy"] != "readability" {
+        assert_eq!(data["strategy"], "main");
+        assert!(!markdown.contains("Settings"));
+        assert!(!markdown.contains("Copyright"));
+    }
+}
+
+/// Test converting only a selected region of the page
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_region() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = r#"
+        <html><head><title>Results</title></head>
+        <body>
+            <ol id="results">
+                <li><a href="/a">First result</a></li>
+                <li><a href="/b">Second result</a></li>
+            </ol>
+            <aside id="ads"><p>Buy now</p></aside>
+        </body></html>
+    "#;
+
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = GetMarkdownParams { selector: Some("#results".to_string()), ..Default::default() };
+    let result = GetMarkdownTool.execute_typed(params, &mut context).expect("Failed to execute markdown tool");
+
+    let data = result.data.unwrap();
+    let markdown = data["markdown"].as_str().expect("Should have markdown");
+    assert_eq!(data["strategy"], "region");
+    assert!(markdown.contains("[First result](/a)"));
+    assert!(!markdown.contains("Buy now"));
+
+    // Unknown selectors and conflicting parameters are errors
+    let params = GetMarkdownParams { selector: Some("#missing".to_string()), ..Default::default() };
+    assert!(GetMarkdownTool.execute_typed(params, &mut context).is_err());
+    let params = GetMarkdownParams { selector: Some("#results".to_string()), index: Some(0), ..Default::default() };
+    assert!(GetMarkdownTool.execute_typed(params, &mut context).is_err());
+}
+
+/// Test reading a PDF document with page-based pagination
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_markdown_pdf_document() {
+    use browser_use::tools::{SavePdfParams, pdf::SavePdfTool};
+    use std::{io::{BufRead, BufReader, Write},
+              net::TcpListener};
+
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    // Print a two-page document to serve as the PDF
+    let html = "<h1>Invoice 42</h1><p style=\"page-break-after: always\">Total due: 100 EUR</p><p>Thank you</p>";
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).expect("Failed to navigate");
+    let mut context = ToolContext::new(&session);
+    let result = SavePdfTool.execute_typed(SavePdfParams::default(), &mut context).expect("Failed to print PDF");
+    let encoded = result.data.unwrap()["pdf_base64"].as_str().unwrap().to_string();
+    let pdf = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, encoded).unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/invoice.pdf", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut request_line = String::new();
+            BufReader::new(stream.try_clone().unwrap()).read_line(&mut request_line).unwrap_or_default();
+            let header = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/pdf\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                pdf.len()
+            );
+            let _ = stream.write_all(header.as_bytes()).and_then(|_| stream.write_all(&pdf));
+        }
+    });
+
+    session.navigate(&url).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let result = GetMarkdownTool.execute_typed(GetMarkdownParams::default(), &mut context).expect("PDF should be read");
+    let data = result.data.unwrap();
+    info!("PDF result: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    assert_eq!(data["strategy"], "pdf");
+    assert_eq!(data["totalPages"].as_u64(), Some(2));
+    assert!(data["markdown"].as_str().unwrap().contains("Total due: 100 EUR"));
+
+    let params = GetMarkdownParams { page: 2, ..Default::default() };
+    let result = GetMarkdownTool.execute_typed(params, &mut context).unwrap();
+    assert!(result.data.unwrap()["markdown"].as_str().unwrap().contains("Thank you"));
+}
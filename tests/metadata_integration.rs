@@ -0,0 +1,92 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{GetMetadataParams, Tool, ToolContext, metadata::GetMetadataTool}};
+use log::info;
+
+const PRODUCT_PAGE: &str = r#"
+    <!DOCTYPE html>
+    <html lang="en">
+    <head>
+        <base href="https://shop.example/">
+        <title>Widget</title>
+        <meta name="description" content="The best widget">
+        <meta name="robots" content="index, follow">
+        <link rel="canonical" href="/widget">
+        <link rel="alternate" hreflang="de" href="/de/widget">
+        <link rel="alternate" type="application/rss+xml" title="News" href="/feed.xml">
+        <meta property="og:title" content="Widget">
+        <meta property="og:image" content="https://shop.example/a.png">
+        <meta property="og:image" content="https://shop.example/b.png">
+        <meta name="twitter:card" content="summary">
+        <script type="application/ld+json">
+            {"@context": "https://schema.org", "@graph": [
+                {"@type": "Product", "name": "Widget", "offers": {"@type": "Offer", "price": "19.99"}},
+                {"@type": "BreadcrumbList", "itemListElement": []}
+            ]}
+        </script>
+        <script type="application/ld+json">{ broken </script>
+    </head>
+    <body>
+        <div itemscope itemtype="https://schema.org/Review">
+            <span itemprop="author">Ada</span>
+            <div itemprop="reviewRating" itemscope itemtype="https://schema.org/Rating">
+                <meta itemprop="ratingValue" content="5">
+            </div>
+        </div>
+        <div vocab="https://schema.org/" typeof="Person">
+            <span property="name">Grace</span>
+            <a property="url" href="/grace">home</a>
+        </div>
+    </body>
+    </html>
+"#;
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_get_metadata() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PRODUCT_PAGE))).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let result =
+        GetMetadataTool.execute_typed(GetMetadataParams::default(), &mut context).expect("Failed to get metadata");
+
+    let data = result.data.unwrap();
+    info!("Metadata: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    assert_eq!(data["canonical"].as_str(), Some("https://shop.example/widget"));
+    assert_eq!(data["robots"].as_str(), Some("index, follow"));
+    assert_eq!(data["language"].as_str(), Some("en"));
+    assert_eq!(data["hreflang"][0]["hreflang"].as_str(), Some("de"));
+    assert_eq!(data["feeds"][0]["href"].as_str(), Some("https://shop.example/feed.xml"));
+    assert_eq!(data["open_graph"]["og:image"].as_array().unwrap().len(), 2);
+    assert_eq!(data["twitter"]["twitter:card"].as_str(), Some("summary"));
+
+    // @graph is flattened and the broken block is reported, not fatal
+    assert_eq!(data["json_ld"].as_array().unwrap().len(), 2);
+    assert_eq!(data["json_ld_errors"].as_array().unwrap().len(), 1);
+
+    let review = &data["microdata"][0];
+    assert_eq!(review["properties"]["author"].as_str(), Some("Ada"));
+    assert_eq!(review["properties"]["reviewRating"]["properties"]["ratingValue"].as_str(), Some("5"));
+
+    assert_eq!(data["rdfa"][0]["properties"]["name"].as_str(), Some("Grace"));
+    assert_eq!(data["rdfa"][0]["properties"]["url"].as_str(), Some("https://shop.example/grace"));
+}
+
+#[test]
+#[ignore]
+fn test_get_metadata_filters_json_ld_type() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PRODUCT_PAGE))).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = GetMetadataParams { json_ld_type: Some("Product".to_string()) };
+    let result = GetMetadataTool.execute_typed(params, &mut context).expect("Failed to get metadata");
+
+    let data = result.data.unwrap();
+    let items = data["json_ld"].as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["offers"]["price"].as_str(), Some("19.99"));
+}
@@ -0,0 +1,186 @@
+use browser_use::{BrowserSession, HumanEmulation, LaunchOptions};
+
+fn eval(session: &BrowserSession, js: &str) -> serde_json::Value {
+    session.tab().unwrap().evaluate(js, false).unwrap().value.unwrap()
+}
+
+/// Test clicks, button presses and wheel events at coordinates
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_click_at_and_wheel() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <style>body { margin: 0 } #pad { width: 400px; height: 300px }</style>
+        <div id="pad"></div>
+        <div style="height: 3000px"></div>
+        <script>
+            window.events = [];
+            const pad = document.getElementById('pad');
+            ['mousedown', 'mouseup', 'click', 'dblclick', 'contextmenu'].forEach(type =>
+                pad.addEventListener(type, e => {
+                    e.preventDefault();
+                    events.push(type + ':' + e.button + '@' + e.clientX + ',' + e.clientY);
+                }));
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    session.execute_tool("click_at", serde_json::json!({ "x": 50, "y": 60 })).unwrap();
+    assert_eq!(eval(&session, "events.join(' ')"), "mousedown:0@50,60 mouseup:0@50,60 click:0@50,60");
+
+    eval(&session, "events = []");
+    session.execute_tool("click_at", serde_json::json!({ "x": 10, "y": 10, "click_count": 2 })).unwrap();
+    assert!(eval(&session, "events.join(' ')").as_str().unwrap().contains("dblclick:0@10,10"));
+
+    eval(&session, "events = []");
+    session.execute_tool("mouse_down", serde_json::json!({ "x": 20, "y": 30, "button": "right" })).unwrap();
+    let result = session.execute_tool("mouse_up", serde_json::json!({ "button": "right" })).unwrap();
+    assert_eq!(result.data.unwrap()["buttons"], 0);
+    assert!(eval(&session, "events.join(' ')").as_str().unwrap().starts_with("mousedown:2@20,30 mouseup:2@20,30"));
+
+    session.execute_tool("wheel", serde_json::json!({ "dy": 400 })).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    assert!(eval(&session, "window.scrollY").as_f64().unwrap() > 0.0);
+}
+
+/// Test dragging with mouse events and with HTML5 drag and drop
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_drag() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <style>body { margin: 0 } div { position: absolute; width: 100px; height: 100px }</style>
+        <div id="card" draggable="true" style="left: 0; top: 0; background: red"></div>
+        <div id="zone" style="left: 300px; top: 0; background: blue"></div>
+        <div id="slider" style="left: 0; top: 200px; width: 400px; height: 20px; background: gray"></div>
+        <script>
+            window.dropped = null;
+            card.addEventListener('dragstart', e => e.dataTransfer.setData('text/plain', 'card'));
+            zone.addEventListener('dragover', e => e.preventDefault());
+            zone.addEventListener('drop', e => { dropped = e.dataTransfer.getData('text/plain'); });
+            window.moves = 0;
+            slider.addEventListener('mousedown', () => {
+                const move = e => { if (e.buttons === 1) moves++; };
+                document.addEventListener('mousemove', move);
+                const up = () => document.removeEventListener('mousemove', move);
+                document.addEventListener('mouseup', up, { once: true });
+            });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    let result = session
+        .execute_tool("drag", serde_json::json!({ "from": { "selector": "#card" }, "to": { "selector": "#zone" } }))
+        .unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data["method"], "html5");
+    assert_eq!(data["dropped"], true);
+    assert_eq!(eval(&session, "dropped"), "card");
+
+    let params = serde_json::json!({ "from": { "x": 10, "y": 210 }, "to": { "x": 300, "y": 210 }, "steps": 5 });
+    let result = session.execute_tool("drag", params).unwrap();
+    assert_eq!(result.data.unwrap()["method"], "mouse");
+    assert_eq!(eval(&session, "moves"), 5);
+}
+
+/// Test dragging between elements a viewport apart, which scrolls between pick-up and drop
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_drag_across_viewport() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <style>body { margin: 0 } div { position: absolute; width: 100px; height: 100px }</style>
+        <div id="card" draggable="true" style="left: 0; top: 0; background: red"></div>
+        <div id="handle" style="left: 200px; top: 0; background: green"></div>
+        <div id="zone" style="left: 0; top: 2000px; background: blue"></div>
+        <div style="top: 4000px"></div>
+        <script>
+            window.dropped = null;
+            window.pressed = null;
+            window.released = null;
+            card.addEventListener('dragstart', e => e.dataTransfer.setData('text/plain', 'card'));
+            zone.addEventListener('dragover', e => e.preventDefault());
+            zone.addEventListener('drop', e => { dropped = e.dataTransfer.getData('text/plain'); });
+            document.addEventListener('mousedown', e => { pressed = e.target.id; });
+            document.addEventListener('mouseup', e => { released = e.target.id; });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    let params = serde_json::json!({ "from": { "selector": "#card" }, "to": { "selector": "#zone" } });
+    let data = session.execute_tool("drag", params).unwrap().data.unwrap();
+    assert_eq!(data["method"], "html5");
+    assert_eq!(data["dropped"], true);
+    assert_eq!(eval(&session, "dropped"), "card");
+
+    let params = serde_json::json!({ "from": { "selector": "#handle" }, "to": { "selector": "#zone" } });
+    let data = session.execute_tool("drag", params).unwrap().data.unwrap();
+    assert_eq!(data["method"], "mouse");
+    assert_eq!(eval(&session, "pressed"), "handle");
+    assert_eq!(eval(&session, "released"), "zone");
+}
+
+/// Test that interaction tools still work with human emulation on
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_human_emulation() {
+    let options = LaunchOptions::new().headless(true).human_emulation(HumanEmulation::new().seed(42).typo_rate(0.3));
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    let html = r#"
+        <input id="name">
+        <button onclick="window.clicked = true">Go</button>
+        <div style="height: 3000px"></div>
+        <script>
+            window.moves = 0;
+            document.addEventListener('mousemove', () => moves++);
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    session.execute_tool("input", serde_json::json!({ "selector": "#name", "text": "hello world" })).unwrap();
+    assert_eq!(eval(&session, "document.getElementById('name').value"), "hello world");
+
+    session.execute_tool("click", serde_json::json!({ "selector": "button" })).unwrap();
+    assert_eq!(eval(&session, "window.clicked"), true);
+    // The pointer travelled along a path instead of jumping
+    assert!(eval(&session, "moves").as_u64().unwrap() > 5);
+
+    let result = session.execute_tool("scroll", serde_json::json!({ "amount": 500 })).unwrap();
+    assert!(result.data.unwrap()["scrolled"].as_i64().unwrap() > 400);
+}
+
+/// Test that checkbox, select and form tools use the real pointer with human emulation on
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_human_emulation_forms() {
+    let options = LaunchOptions::new().headless(true).human_emulation(HumanEmulation::new().seed(7));
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    let html = r#"
+        <form>
+            <label>Name <input name="name"></label>
+            <label><input type="checkbox" name="terms"> Terms</label>
+            <select name="size"><option>S</option><option>M</option></select>
+        </form>
+        <input type="checkbox" id="solo">
+        <script>
+            window.trusted = [];
+            document.addEventListener('click', e => e.isTrusted && trusted.push(e.target.name || e.target.id));
+            window.keys = 0;
+            document.addEventListener('keydown', () => keys++);
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    let params = serde_json::json!({ "values": { "name": "Ada", "terms": true, "size": "M" } });
+    let data = session.execute_tool("fill_form", params).unwrap().data.unwrap();
+    assert_eq!(data["filled"], 3);
+    assert_eq!(eval(&session, "document.querySelector('[name=name]').value"), "Ada");
+    assert_eq!(eval(&session, "document.querySelector('[name=size]').value"), "M");
+    assert!(eval(&session, "keys").as_u64().unwrap() >= 3);
+
+    session.execute_tool("set_checked", serde_json::json!({ "selector": "#solo", "checked": true })).unwrap();
+    assert_eq!(eval(&session, "document.getElementById('solo').checked"), true);
+
+    // Checkboxes were clicked with real (trusted) pointer events, not from script
+    assert_eq!(eval(&session, "trusted.includes('terms') && trusted.includes('solo')"), true);
+}
@@ -0,0 +1,50 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{PaperSize, SavePdfParams, Tool, ToolContext, pdf::SavePdfTool}};
+use log::info;
+
+/// Test printing a page to a PDF file
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_save_pdf_to_file() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+
+    let html = "<html><head><title>Report</title></head><body><h1>Quarterly report</h1><p>All good.</p></body></html>";
+    let data_url = format!("data:text/html,{}", urlencoding::encode(html));
+    session.navigate(&data_url).expect("Failed to navigate");
+
+    let path = std::env::temp_dir().join("browser_use_test_report.pdf");
+    let params = SavePdfParams {
+        path: Some(path.to_string_lossy().to_string()),
+        paper_size: PaperSize::A4,
+        margin: Some("1cm".to_string()),
+        landscape: true,
+        footer_template: Some("<span class=\"pageNumber\"></span>".to_string()),
+        print_background: true,
+        ..Default::default()
+    };
+
+    let mut context = ToolContext::new(&session);
+    let result = SavePdfTool.execute_typed(params, &mut context).expect("Failed to save PDF");
+    info!("save_pdf result: {:?}", result);
+
+    let bytes = std::fs::read(&path).expect("PDF should be written");
+    assert!(bytes.starts_with(b"%PDF"));
+    assert_eq!(result.data.unwrap()["size_bytes"].as_u64(), Some(bytes.len() as u64));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// Test returning the PDF as base64 when no path is given
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_save_pdf_base64() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate("data:text/html,<p>Hello</p>").expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    let result = SavePdfTool.execute_typed(SavePdfParams::default(), &mut context).expect("Failed to save PDF");
+
+    let data = result.data.unwrap();
+    assert!(data["pdf_base64"].as_str().unwrap().starts_with("JVBER")); // "%PDF" in base64
+    assert!(data.get("path").is_none());
+}
@@ -0,0 +1,58 @@
+use browser_use::{BrowserSession, LaunchOptions};
+
+/// Test recording a few actions to a GIF and to PNG frames
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_recording_gif_and_png() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"<h1 id="t">Start</h1><button onclick="t.textContent='Clicked'">Go</button>"#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    for format in ["gif", "png"] {
+        let result = session.execute_tool("start_recording", serde_json::json!({ "max_width": 640 })).unwrap();
+        assert!(result.success, "{:?}", result.error);
+
+        // A second recording cannot be started while one is running
+        assert!(session.execute_tool("start_recording", serde_json::json!({})).is_err());
+
+        session.execute_tool("click", serde_json::json!({ "selector": "button" })).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        session.tab().unwrap().evaluate("t.textContent = 'Again'", false).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(500));
+
+        let path = std::env::temp_dir().join(format!("browser_use_recording_test.{}", format));
+        let result = session
+            .execute_tool("stop_recording", serde_json::json!({ "path": path, "format": format }))
+            .expect("Failed to stop recording");
+        let data = result.data.unwrap();
+        assert!(data["frames"].as_u64().unwrap() >= 1);
+
+        if format == "gif" {
+            assert!(std::fs::read(&path).unwrap().starts_with(b"GIF89a"));
+            let _ = std::fs::remove_file(&path);
+        } else {
+            assert!(path.join("frame_00001.png").exists());
+            let _ = std::fs::remove_dir_all(&path);
+        }
+    }
+
+    // Stopping without a recording is an error
+    assert!(session.execute_tool("stop_recording", serde_json::json!({ "path": "unused.gif" })).is_err());
+}
+
+/// Test that a recording stops buffering frames at max_frames
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_recording_max_frames() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"<h1 id="t">0</h1><script>setInterval(() => t.textContent++, 20)</script>"#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    session.execute_tool("start_recording", serde_json::json!({ "max_frames": 2 })).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(1000));
+
+    let path = std::env::temp_dir().join("browser_use_recording_max_frames.gif");
+    let result = session.execute_tool("stop_recording", serde_json::json!({ "path": path })).unwrap();
+    assert!(result.data.unwrap()["frames"].as_u64().unwrap() <= 2);
+    let _ = std::fs::remove_file(&path);
+}
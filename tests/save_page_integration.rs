@@ -0,0 +1,71 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{PageFormat, SavePageParams, Tool, ToolContext, save_page::SavePageTool}};
+
+const FORM_PAGE: &str = r#"
+    <html><head><title>Order</title><style>h1 { color: rgb(200, 0, 0); }</style></head>
+    <body>
+        <h1>Order form</h1>
+        <img src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" alt="pixel">
+        <input id="name" type="text">
+        <input id="agree" type="checkbox">
+        <select id="size"><option>S</option><option>M</option><option>L</option></select>
+        <textarea id="notes"></textarea>
+        <script>document.title = "Order";</script>
+    </body></html>
+"#;
+
+fn open_form(session: &BrowserSession) {
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(FORM_PAGE))).expect("Failed to navigate");
+    let js = r#"
+        document.getElementById("name").value = "Ada";
+        document.getElementById("agree").checked = true;
+        document.getElementById("size").value = "L";
+        document.getElementById("notes").value = "Leave at door";
+    "#;
+    session.tab().unwrap().evaluate(js, false).expect("Failed to fill form");
+}
+
+/// Test that the HTML archive keeps the current form state and drops scripts
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_save_page_html_keeps_form_state() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    open_form(&session);
+
+    let mut context = ToolContext::new(&session);
+    let params = SavePageParams { format: PageFormat::Html, ..Default::default() };
+    let result = SavePageTool.execute_typed(params, &mut context).expect("Failed to save page");
+
+    let data = result.data.unwrap();
+    let html = data["content"].as_str().expect("Should return content");
+    assert!(html.contains(r#"value="Ada""#));
+    assert!(html.contains("checked"));
+    assert!(html.contains("<option selected=\"\">L</option>"));
+    assert!(html.contains("Leave at door"));
+    assert!(html.contains("rgb(200, 0, 0)"));
+    assert!(!html.contains("<script"));
+    assert_eq!(data["format"], "html");
+}
+
+/// Test saving an MHTML archive to a file
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_save_page_mhtml_to_file() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    open_form(&session);
+
+    let path = std::env::temp_dir().join("browser_use_test_page.mhtml");
+    let params = SavePageParams { path: Some(path.to_string_lossy().to_string()), format: PageFormat::Mhtml };
+
+    let mut context = ToolContext::new(&session);
+    let result = SavePageTool.execute_typed(params, &mut context).expect("Failed to save page");
+    let data = result.data.unwrap();
+
+    let archive = std::fs::read_to_string(&path).expect("Archive should be written");
+    assert!(archive.contains("MIME-Version"));
+    assert!(archive.contains("Order form"));
+    assert!(data.get("content").is_none());
+    assert_eq!(data["size_bytes"].as_u64(), Some(archive.len() as u64));
+
+    let _ = std::fs::remove_file(&path);
+}
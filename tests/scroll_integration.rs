@@ -0,0 +1,91 @@
+use browser_use::{BrowserSession, LaunchOptions};
+
+fn eval(session: &BrowserSession, js: &str) -> serde_json::Value {
+    session.tab().unwrap().evaluate(js, false).unwrap().value.unwrap()
+}
+
+const PAGE: &str = r#"
+    <style>
+        body { margin: 0 }
+        #chat { height: 200px; overflow-y: auto }
+        #chat p { height: 50px; margin: 0 }
+        #carousel { width: 300px; overflow-x: auto; white-space: nowrap }
+        #carousel span { display: inline-block; width: 200px; height: 50px }
+    </style>
+    <div id="chat"></div>
+    <div id="carousel"><span>1</span><span>2</span><span>3</span><span>4</span></div>
+    <div style="height: 3000px"></div>
+    <div id="footer">Footer</div>
+    <script>
+        const chat = document.getElementById('chat');
+        for (let i = 0; i < 20; i++) chat.insertAdjacentHTML('beforeend', '<p>Message ' + i + '</p>');
+        // Infinite feed: more messages load when the bottom is reached
+        chat.addEventListener('scroll', () => {
+            if (chat.scrollTop + chat.clientHeight >= chat.scrollHeight - 1 && chat.children.length < 60) {
+                for (let i = 0; i < 20; i++) {
+                    chat.insertAdjacentHTML('beforeend', '<p>Message ' + chat.children.length + '</p>');
+                }
+            }
+        });
+    </script>
+"#;
+
+/// Test scrolling containers, horizontal scrolling and scrolling elements into view
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_scroll_containers_and_elements() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PAGE))).unwrap();
+
+    let result = session.execute_tool("scroll", serde_json::json!({ "container": "#chat", "amount": 150 })).unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data["scrolled"], 150);
+    assert_eq!(data["containers"][0]["container"], "#chat");
+    assert_eq!(eval(&session, "window.scrollY"), 0);
+
+    let params = serde_json::json!({ "container": "#carousel", "horizontal": 1000 });
+    let data = session.execute_tool("scroll", params).unwrap().data.unwrap();
+    assert!(data["scrolledX"].as_i64().unwrap() > 0);
+    assert_eq!(data["containers"][0]["atRight"], true);
+
+    let result = session.execute_tool("scroll", serde_json::json!({ "selector": "#footer", "align": "end" })).unwrap();
+    let data = result.data.unwrap();
+    assert!(data["scrolled"].as_i64().unwrap() > 2000);
+    assert_eq!(data["isAtBottom"], true);
+
+    let result = session.execute_tool("scroll", serde_json::json!({ "container": "#nope" }));
+    assert!(result.is_err() || !result.unwrap().success);
+}
+
+/// Test scrolling a lazy-loading feed until text appears, and giving up at the end
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_scroll_until() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PAGE))).unwrap();
+
+    let params = serde_json::json!({ "container": "#chat", "until_text": "Message 45", "max_scrolls": 20 });
+    let data = session.execute_tool("scroll", params).unwrap().data.unwrap();
+    assert_eq!(data["found"], true);
+    assert!(data["scrolls"].as_u64().unwrap() > 1);
+
+    let params = serde_json::json!({ "container": "#chat", "until_text": "Message 99", "max_scrolls": 30 });
+    let data = session.execute_tool("scroll", params).unwrap().data.unwrap();
+    assert_eq!(data["found"], false);
+    assert_eq!(data["hitEnd"], true);
+}
+
+/// Test that a horizontal scroll-until search moves only the carousel, not the page
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_scroll_until_horizontal() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PAGE))).unwrap();
+
+    let params = serde_json::json!({ "container": "#carousel", "horizontal": 150, "until_text": "nowhere" });
+    let data = session.execute_tool("scroll", params).unwrap().data.unwrap();
+    assert_eq!(data["found"], false);
+    assert!(data["scrolledX"].as_i64().unwrap() > 0);
+    assert_eq!(data["scrolled"], 0);
+    assert_eq!(eval(&session, "window.scrollY"), 0);
+}
@@ -0,0 +1,70 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{ExtractStructuredParams, Tool, ToolContext, structured::ExtractStructuredTool}};
+use log::info;
+
+const PRODUCTS_PAGE: &str = r#"
+    <!DOCTYPE html>
+    <html>
+    <head><base href="https://shop.example/"></head>
+    <body>
+        <div id="list"></div>
+        <button id="next">Next</button>
+        <script>
+            const pages = [
+                [["Widget", "$1,299.00", "/p/1", "2024-03-01"], ["Gadget", "$15", "/p/2", "2024-03-02"]],
+                [["Doohickey", "Sold out", "/p/3", "not a date"], ["Gadget", "$15", "/p/2", "2024-03-02"]]
+            ];
+            let page = 0;
+            function render() {
+                document.getElementById('list').innerHTML = pages[page].map(([name, price, href, date]) =>
+                    '<div class="product"><h2>' + name + '</h2><span class="price">' + price + '</span>' +
+                    '<a href="' + href + '">View</a><time datetime="' + date + '">' + date + '</time></div>'
+                ).join('');
+                document.getElementById('next').disabled = page === pages.length - 1;
+            }
+            document.getElementById('next').onclick = () => { page++; render(); };
+            render();
+        </script>
+    </body>
+    </html>
+"#;
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_extract_structured_with_next_page() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(PRODUCTS_PAGE))).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let params: ExtractStructuredParams = serde_json::from_value(serde_json::json!({
+        "container": ".product",
+        "fields": {
+            "name": { "selector": "h2" },
+            "price": { "selector": ".price", "type": "number" },
+            "link": { "selector": "a", "attr": "href", "type": "url" },
+            "added": { "selector": "time", "type": "date" }
+        },
+        "next_selector": "button#next",
+        "max_pages": 5,
+        "delay_ms": 200
+    }))
+    .unwrap();
+
+    let mut context = ToolContext::new(&session);
+    let result = ExtractStructuredTool.execute_typed(params, &mut context).expect("Failed to extract");
+
+    let data = result.data.unwrap();
+    info!("Items: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    // Two pages read; the disabled next button ends paging. The row repeated on page two is kept.
+    assert_eq!(data["pages"].as_u64(), Some(2));
+    assert_eq!(data["count"].as_u64(), Some(4));
+
+    let items = data["items"].as_array().unwrap();
+    assert_eq!(items[0]["name"].as_str(), Some("Widget"));
+    assert_eq!(items[0]["price"].as_f64(), Some(1299.0));
+    assert_eq!(items[0]["link"].as_str(), Some("https://shop.example/p/1"));
+    assert_eq!(items[0]["added"].as_str(), Some("2024-03-01T00:00:00.000Z"));
+    assert!(items[2]["price"].is_null());
+    assert!(items[2]["added"].is_null());
+}
@@ -0,0 +1,106 @@
+use browser_use::{BrowserSession, LaunchOptions,
+                  tools::{ExtractTableParams, TableFormat, Tool, ToolContext, table::ExtractTableTool}};
+use log::info;
+
+const SCORES_TABLE: &str = r#"
+    <!DOCTYPE html>
+    <html>
+    <body>
+        <table id="scores">
+            <caption>Quarterly scores</caption>
+            <thead>
+                <tr><th rowspan="2">Name</th><th colspan="2">Score</th></tr>
+                <tr><th>Q1</th><th>Q2</th></tr>
+            </thead>
+            <tbody>
+                <tr><td>Ada</td><td>9</td><td>10</td></tr>
+                <tr><td rowspan="2">Grace</td><td>8</td><td>7</td></tr>
+                <tr><td colspan="2">absent, sick</td></tr>
+            </tbody>
+        </table>
+        <table id="plain">
+            <tr><td>a</td><td>b</td></tr>
+        </table>
+    </body>
+    </html>
+"#;
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_extract_table_records() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", SCORES_TABLE)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = ExtractTableParams { selector: Some("#scores td".to_string()), ..Default::default() };
+    let result = ExtractTableTool.execute_typed(params, &mut context).expect("Failed to extract table");
+
+    let data = result.data.unwrap();
+    info!("Table: {}", serde_json::to_string_pretty(&data).unwrap());
+
+    assert_eq!(data["count"].as_u64(), Some(1));
+    let table = &data["tables"][0];
+    assert_eq!(table["caption"].as_str(), Some("Quarterly scores"));
+    assert_eq!(table["columns"], serde_json::json!(["Name", "Score / Q1", "Score / Q2"]));
+
+    let records = table["records"].as_array().unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0]["Score / Q2"].as_str(), Some("10"));
+    // Rowspan and colspan cells are repeated into every cell they cover
+    assert_eq!(records[2]["Name"].as_str(), Some("Grace"));
+    assert_eq!(records[2]["Score / Q1"].as_str(), Some("absent, sick"));
+    assert_eq!(records[2]["Score / Q2"].as_str(), Some("absent, sick"));
+}
+
+#[test]
+#[ignore]
+fn test_extract_all_tables_as_csv() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    session.navigate(&format!("data:text/html,{}", SCORES_TABLE)).expect("Failed to navigate");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let mut context = ToolContext::new(&session);
+    let params = ExtractTableParams { format: TableFormat::Csv, ..Default::default() };
+    let result = ExtractTableTool.execute_typed(params, &mut context).expect("Failed to extract tables");
+
+    let data = result.data.unwrap();
+    assert_eq!(data["count"].as_u64(), Some(2));
+    assert_eq!(
+        data["tables"][0]["csv"].as_str(),
+        Some("Name,Score / Q1,Score / Q2\r\nAda,9,10\r\nGrace,8,7\r\nGrace,\"absent, sick\",\"absent, sick\"\r\n")
+    );
+    // A table without header rows has no header line
+    assert_eq!(data["tables"][1]["csv"].as_str(), Some("a,b\r\n"));
+    assert!(data["tables"][1]["records"].is_null());
+}
+
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_extract_table_rowspan_zero() {
+    let session = BrowserSession::launch(LaunchOptions::new().headless(true)).expect("Failed to launch browser");
+    let html = r#"
+        <table id="groups">
+            <thead>
+                <tr><th rowspan="0">Group</th><th>Member</th></tr>
+            </thead>
+            <tbody>
+                <tr><td rowspan="0">Admins</td><td>Ada</td></tr>
+                <tr><td>Grace</td></tr>
+            </tbody>
+        </table>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).expect("Failed to navigate");
+
+    let mut context = ToolContext::new(&session);
+    let params = ExtractTableParams { selector: Some("#groups".to_string()), ..Default::default() };
+    let result = ExtractTableTool.execute_typed(params, &mut context).expect("Failed to extract table");
+
+    // rowspan="0" spans to the end of its own section, so the header cell stays out of the body
+    let table = &result.data.unwrap()["tables"][0];
+    assert_eq!(table["columns"], serde_json::json!(["Group", "Member"]));
+    assert_eq!(
+        table["records"],
+        serde_json::json!([{ "Group": "Admins", "Member": "Ada" }, { "Group": "Admins", "Member": "Grace" }])
+    );
+}
@@ -0,0 +1,52 @@
+use browser_use::{BrowserSession, Device, LaunchOptions};
+
+fn eval(session: &BrowserSession, js: &str) -> serde_json::Value {
+    session.tab().unwrap().evaluate(js, false).unwrap().value.unwrap()
+}
+
+/// Test taps, long presses, swipes and pinches on an emulated phone
+#[test]
+#[ignore] // Requires Chrome to be installed
+fn test_touch_gestures() {
+    let options = LaunchOptions::new().headless(true).device(Device::preset("pixel_7").unwrap());
+    let session = BrowserSession::launch(options).expect("Failed to launch browser");
+    let html = r#"
+        <meta name="viewport" content="width=device-width">
+        <style>body { margin: 0 } #pad { width: 100%; height: 400px; background: #ccc }</style>
+        <div id="pad"></div>
+        <button id="btn" onclick="clicks++">Tap me</button>
+        <script>
+            window.clicks = 0;
+            window.log = [];
+            const pad = document.getElementById('pad');
+            let start = null;
+            pad.addEventListener('touchstart', e => {
+                start = { x: e.touches[0].clientX, y: e.touches[0].clientY, t: Date.now(), n: e.touches.length };
+            });
+            pad.addEventListener('touchmove', e => {
+                if (e.touches.length === 2) start.spread = Math.abs(e.touches[0].clientX - e.touches[1].clientX);
+            });
+            pad.addEventListener('touchend', e => {
+                const t = e.changedTouches[0];
+                const dx = t.clientX - start.x;
+                if (start.n === 2) log.push(start.spread > 150 ? 'pinch-out' : 'pinch-in');
+                else if (Math.abs(dx) > 50) log.push(dx < 0 ? 'swipe-left' : 'swipe-right');
+                else log.push(Date.now() - start.t > 500 ? 'long-press' : 'tap');
+            });
+        </script>
+    "#;
+    session.navigate(&format!("data:text/html,{}", urlencoding::encode(html))).unwrap();
+
+    session.execute_tool("tap", serde_json::json!({ "selector": "#btn" })).unwrap();
+    assert_eq!(eval(&session, "clicks"), 1);
+
+    session.execute_tool("tap", serde_json::json!({ "x": 100, "y": 100 })).unwrap();
+    session.execute_tool("long_press", serde_json::json!({ "x": 100, "y": 100, "duration_ms": 700 })).unwrap();
+    let params = serde_json::json!({ "x": 300, "y": 200, "direction": "left", "distance": 200 });
+    session.execute_tool("swipe", params).unwrap();
+    session.execute_tool("pinch", serde_json::json!({ "x": 200, "y": 200, "scale": 2.0 })).unwrap();
+    assert_eq!(eval(&session, "log.join(' ')"), "tap long-press swipe-left pinch-out");
+
+    let result = session.execute_tool("swipe", serde_json::json!({ "x": 10, "y": 10 }));
+    assert!(result.is_err() || !result.unwrap().success);
+}